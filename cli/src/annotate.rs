@@ -0,0 +1,326 @@
+// Derived from Seaography (github.com/SeaQL/seaography)
+// Modifications Copyright (c) 2025 Stephen J. Li
+
+use std::error::Error;
+
+use heck::ToUpperCamelCase;
+use quote::ToTokens;
+
+const FILTER: &str = "async_graphql_template::macros::Filter";
+const MUTANT: &str = "async_graphql_template::macros::Mutant";
+const RELATIONS_COMPACT: &str = "async_graphql_template::macros::RelationsCompact";
+const ENUM_FILTER: &str = "async_graphql_template::macros::EnumFilter";
+
+/// 實體中可作為查詢鍵的欄位
+#[derive(Debug)]
+pub struct KeyField {
+    pub name: String,
+    pub rust_type: String,
+}
+
+/// 產生 `QueryRoot` 所需的實體資訊
+#[derive(Debug)]
+pub struct EntityInfo {
+    pub module: String,
+    pub primary_keys: Vec<KeyField>,
+    pub unique_keys: Vec<KeyField>,
+    /// 欄位使用的類型（去除 `Option`），用於決定依賴的 feature
+    pub types: Vec<String>,
+}
+
+/// 在 compact 格式的實體檔案加上 GraphQL 與 async-graphql-template 的 derive
+///
+/// 已加上 `Filter` 的檔案維持不變；自關聯的 `"Entity"` 改寫為模塊路徑，供 `RelationsCompact` 解析。
+pub fn annotate(module: &str, source: &str) -> Result<String, Box<dyn Error>> {
+    let mut source = source.to_owned();
+
+    if let Some(position) = source.find("pub struct Model") {
+        let table_name = table_name(&source)?
+            .ok_or_else(|| format!("`{}`: Model without `#[sea_orm(table_name)]`", module))?;
+
+        if !derives_before(&source, position)
+            .iter()
+            .any(|derive| derive == "DeriveEntityModel")
+        {
+            return Err(
+                format!("`{}`: only the compact entity format is supported", module).into(),
+            );
+        }
+
+        if !derives_before(&source, position)
+            .iter()
+            .any(|derive| derive == FILTER)
+        {
+            source = add_derives(
+                &source,
+                position,
+                &["async_graphql::SimpleObject", FILTER, MUTANT],
+            );
+
+            let position = source.find("pub struct Model").unwrap();
+
+            source.insert_str(
+                position,
+                &format!(
+                    "#[graphql(complex)]\n#[graphql(name = \"{}\")]\n",
+                    table_name.to_upper_camel_case()
+                ),
+            );
+        }
+    }
+
+    if let Some(position) = source.find("pub enum Relation ") {
+        source = add_derives(&source, position, &[RELATIONS_COMPACT]);
+
+        let self_path = format!("\"super::{}::Entity\"", module);
+
+        for kind in ["belongs_to", "has_many", "has_one"] {
+            source = source.replace(
+                &format!("{} = \"Entity\"", kind),
+                &format!("{} = {}", kind, self_path),
+            );
+        }
+    }
+
+    let mut offset = 0;
+
+    while let Some(found) = source[offset..].find("pub enum ") {
+        let position = offset + found;
+
+        if derives_before(&source, position)
+            .iter()
+            .any(|derive| derive == "DeriveActiveEnum")
+        {
+            // `async_graphql::Enum` 需要 `Copy` 與 `Eq`；`Hash` 讓枚舉可作為查詢鍵
+            source = add_derives(
+                &source,
+                position,
+                &["Copy", "Eq", "Hash", "async_graphql::Enum", ENUM_FILTER],
+            );
+        }
+
+        offset = source[offset..].find("pub enum ").unwrap() + offset + "pub enum ".len();
+    }
+
+    Ok(source)
+}
+
+/// 讀取實體檔案中 `Model` 的主鍵、唯一欄位與欄位類型
+pub fn entity_info(module: &str, source: &str) -> Result<Option<EntityInfo>, Box<dyn Error>> {
+    let file = syn::parse_file(source)?;
+
+    let model = file.items.iter().find_map(|item| match item {
+        syn::Item::Struct(item) if item.ident == "Model" => Some(item),
+        _ => None,
+    });
+
+    let model = match model {
+        Some(model) => model,
+        None => return Ok(None),
+    };
+
+    let mut info = EntityInfo {
+        module: module.to_owned(),
+        primary_keys: Vec::new(),
+        unique_keys: Vec::new(),
+        types: Vec::new(),
+    };
+
+    for field in model.fields.iter() {
+        let name = match &field.ident {
+            Some(ident) => ident.to_string(),
+            None => continue,
+        };
+
+        let rust_type = remove_optional(&field.ty)
+            .to_token_stream()
+            .to_string()
+            .replace(' ', "");
+        let flags = sea_orm_flags(&field.attrs)?;
+
+        // 與 derive 相同，浮點數無法作為 DataLoader 的鍵
+        let hashable = !["f32", "f64"].contains(&rust_type.as_str());
+
+        if flags.contains(&"primary_key".to_owned()) {
+            info.primary_keys.push(KeyField {
+                name,
+                rust_type: rust_type.clone(),
+            });
+        } else if flags.contains(&"unique".to_owned()) && hashable {
+            info.unique_keys.push(KeyField {
+                name,
+                rust_type: rust_type.clone(),
+            });
+        }
+
+        info.types.push(rust_type);
+    }
+
+    if info
+        .primary_keys
+        .iter()
+        .any(|key| ["f32", "f64"].contains(&key.rust_type.as_str()))
+    {
+        info.primary_keys.clear();
+    }
+
+    Ok(Some(info))
+}
+
+/// 檔案中以 `DeriveActiveEnum` 定義的枚舉名稱
+pub fn active_enums(source: &str) -> Result<Vec<String>, Box<dyn Error>> {
+    let file = syn::parse_file(source)?;
+
+    Ok(file
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            syn::Item::Enum(item)
+                if item.attrs.iter().any(|attr| {
+                    attr.to_token_stream()
+                        .to_string()
+                        .contains("DeriveActiveEnum")
+                }) =>
+            {
+                Some(item.ident.to_string())
+            }
+            _ => None,
+        })
+        .collect())
+}
+
+/// 讀取 `#[sea_orm(table_name = "...")]`
+fn table_name(source: &str) -> Result<Option<String>, Box<dyn Error>> {
+    let file = syn::parse_file(source)?;
+    let mut table_name = None;
+
+    for item in file.items.iter() {
+        if let syn::Item::Struct(item) = item {
+            if item.ident != "Model" {
+                continue;
+            }
+
+            for attr in item
+                .attrs
+                .iter()
+                .filter(|attr| attr.path().is_ident("sea_orm"))
+            {
+                attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("table_name") {
+                        table_name = Some(meta.value()?.parse::<syn::LitStr>()?.value());
+                    } else if meta.input.peek(syn::Token![=]) {
+                        meta.value()?.parse::<syn::Expr>()?;
+                    }
+
+                    Ok(())
+                })?;
+            }
+        }
+    }
+
+    Ok(table_name)
+}
+
+/// 欄位上 `#[sea_orm(...)]` 中不帶值的旗標，如 `primary_key`、`unique`
+fn sea_orm_flags(attrs: &[syn::Attribute]) -> Result<Vec<String>, syn::Error> {
+    let mut flags = Vec::new();
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("sea_orm")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.input.peek(syn::Token![=]) {
+                meta.value()?.parse::<syn::Expr>()?;
+            } else if let Some(ident) = meta.path.get_ident() {
+                flags.push(ident.to_string());
+            }
+
+            Ok(())
+        })?;
+    }
+
+    Ok(flags)
+}
+
+fn remove_optional(ty: &syn::Type) -> &syn::Type {
+    if let syn::Type::Path(path) = ty {
+        if let Some(segment) = path.path.segments.last() {
+            if segment.ident == "Option" {
+                if let syn::PathArguments::AngleBracketed(args) = &segment.arguments {
+                    if let Some(syn::GenericArgument::Type(ty)) = args.args.first() {
+                        return ty;
+                    }
+                }
+            }
+        }
+    }
+
+    ty
+}
+
+/// 找出 `position` 之前最近的 `#[derive(...)]`，回傳其起點與右括號的位置
+fn derive_span(source: &str, position: usize) -> Option<(usize, usize)> {
+    let start = source[..position].rfind("#[derive(")?;
+    let mut depth = 0;
+
+    for (index, char) in source[start..position].char_indices() {
+        match char {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+
+                // derive 與項目之間只能有其他屬性，否則屬於前一個項目
+                if depth == 0 {
+                    let end = start + index;
+
+                    return (!source[end..position].contains(['{', '}', ';']))
+                        .then_some((start, end));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+fn derives_before(source: &str, position: usize) -> Vec<String> {
+    match derive_span(source, position) {
+        Some((start, end)) => source[start + "#[derive(".len()..end]
+            .split(',')
+            .map(|derive| derive.trim().to_owned())
+            .filter(|derive| !derive.is_empty())
+            .collect(),
+        None => Vec::new(),
+    }
+}
+
+/// 在 `position` 的項目前的 derive 中加上尚未存在的 `derives`
+fn add_derives(source: &str, position: usize, derives: &[&str]) -> String {
+    let (start, end) = match derive_span(source, position) {
+        Some(span) => span,
+        None => {
+            let line_start = source[..position].rfind('\n').map_or(0, |index| index + 1);
+
+            return format!(
+                "{}#[derive({})]\n{}",
+                &source[..line_start],
+                derives.join(", "),
+                &source[line_start..]
+            );
+        }
+    };
+
+    let mut list = derives_before(source, position);
+
+    for derive in derives {
+        if !list.iter().any(|existing| existing == derive) {
+            list.push((*derive).to_owned());
+        }
+    }
+
+    format!(
+        "{}#[derive({}){}",
+        &source[..start],
+        list.join(", "),
+        &source[end + 1..]
+    )
+}
@@ -0,0 +1,80 @@
+// Derived from Seaography (github.com/SeaQL/seaography)
+// Modifications Copyright (c) 2025 Stephen J. Li
+
+use std::error::Error;
+
+use sea_schema::sea_query::TableCreateStatement;
+
+/// 連線到資料庫並讀取所有資料表的結構
+///
+/// 以 `_` 開頭的資料表（如 `_sqlx_migrations`）與 `ignore_tables` 中的資料表會被略過。
+pub async fn discover(
+    database_url: &str,
+    database_schema: &str,
+    ignore_tables: &[String],
+) -> Result<Vec<TableCreateStatement>, Box<dyn Error>> {
+    let url = url::Url::parse(database_url)?;
+
+    let is_included = |table: &str| {
+        !table.starts_with('_') && !ignore_tables.iter().any(|ignore| ignore == table)
+    };
+
+    let tables = match url.scheme() {
+        "mysql" => {
+            use sea_schema::mysql::discovery::SchemaDiscovery;
+
+            let database_name = url
+                .path_segments()
+                .and_then(|mut segments| segments.next())
+                .filter(|name| !name.is_empty())
+                .ok_or("There is no database name as part of the url path")?
+                .to_owned();
+
+            let connection = sqlx::MySqlPool::connect(database_url).await?;
+            let schema = SchemaDiscovery::new(connection, &database_name)
+                .discover()
+                .await?;
+
+            schema
+                .tables
+                .into_iter()
+                .filter(|table| is_included(&table.info.name))
+                .map(|table| table.write())
+                .collect()
+        }
+        "postgres" | "postgresql" => {
+            use sea_schema::postgres::discovery::SchemaDiscovery;
+
+            let connection = sqlx::PgPool::connect(database_url).await?;
+            let schema = SchemaDiscovery::new(connection, database_schema)
+                .discover()
+                .await?;
+
+            schema
+                .tables
+                .into_iter()
+                .filter(|table| is_included(&table.info.name))
+                .map(|table| table.write())
+                .collect()
+        }
+        "sqlite" => {
+            use sea_schema::sqlite::discovery::SchemaDiscovery;
+
+            let connection = sqlx::SqlitePool::connect(database_url).await?;
+            let schema = SchemaDiscovery::new(connection)
+                .discover()
+                .await?
+                .merge_indexes_into_table();
+
+            schema
+                .tables
+                .into_iter()
+                .filter(|table| is_included(&table.name))
+                .map(|table| table.write())
+                .collect()
+        }
+        scheme => return Err(format!("Database `{}` is not supported", scheme).into()),
+    };
+
+    Ok(tables)
+}
@@ -0,0 +1,542 @@
+// Derived from Seaography (github.com/SeaQL/seaography)
+// Modifications Copyright (c) 2025 Stephen J. Li
+
+use std::collections::BTreeMap;
+
+use heck::{ToSnakeCase, ToUpperCamelCase};
+use sea_schema::sea_query::{
+    ColumnSpec, ColumnType, ForeignKeyAction, TableCreateStatement, TableRef,
+};
+
+pub const HEADER: &str = "//! Generated by async-graphql-template-cli";
+
+#[derive(Debug)]
+pub struct OutputFile {
+    pub name: String,
+    pub content: String,
+}
+
+#[derive(Debug)]
+struct Column {
+    name: String,
+    rust_type: String,
+    nullable: bool,
+    primary_key: bool,
+    unique: bool,
+}
+
+impl Column {
+    fn field_name(&self) -> String {
+        self.name.to_snake_case()
+    }
+}
+
+#[derive(Debug)]
+struct ForeignKey {
+    column: String,
+    ref_table: String,
+    ref_column: String,
+    on_update: Option<ForeignKeyAction>,
+    on_delete: Option<ForeignKeyAction>,
+}
+
+#[derive(Debug)]
+struct Table {
+    name: String,
+    columns: Vec<Column>,
+    foreign_keys: Vec<ForeignKey>,
+}
+
+impl Table {
+    fn module(&self) -> String {
+        self.name.to_snake_case()
+    }
+
+    fn is_unique(&self, column: &str) -> bool {
+        self.columns.iter().any(|col| {
+            col.name == column && (col.unique || (col.primary_key && self.primary_key_len() == 1))
+        })
+    }
+
+    fn primary_key_len(&self) -> usize {
+        self.columns
+            .iter()
+            .filter(|column| column.primary_key)
+            .count()
+    }
+}
+
+#[derive(Debug)]
+enum RelationKind {
+    BelongsTo {
+        from: String,
+        to: String,
+        on_update: Option<ForeignKeyAction>,
+        on_delete: Option<ForeignKeyAction>,
+    },
+    HasMany,
+    HasOne,
+}
+
+#[derive(Debug)]
+struct Relation {
+    variant: String,
+    target: String,
+    kind: RelationKind,
+}
+
+/// 依資料表結構產生 sea-orm-codegen 格式（compact）的實體檔案
+///
+/// 只支持單欄位外鍵；同一目標有多個關聯時以外鍵欄位命名，且不產生 `Related` 實作。
+pub fn write_entities(stmts: &[TableCreateStatement]) -> Vec<OutputFile> {
+    let mut enums: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    let tables: Vec<Table> = stmts
+        .iter()
+        .map(|stmt| table_from_stmt(stmt, &mut enums))
+        .collect();
+
+    let mut relations: BTreeMap<String, Vec<Relation>> = BTreeMap::new();
+
+    for table in tables.iter() {
+        let mut belongs_to = Vec::new();
+
+        for foreign_key in table.foreign_keys.iter() {
+            let same_target = table
+                .foreign_keys
+                .iter()
+                .filter(|other| other.ref_table == foreign_key.ref_table)
+                .count();
+
+            // 自關聯或同一目標有多個外鍵時，以外鍵欄位命名，如 `original_language_id` 為 `OriginalLanguage`
+            let variant = if same_target > 1 || foreign_key.ref_table == table.name {
+                let column = foreign_key.column.to_snake_case();
+                column
+                    .strip_suffix("_id")
+                    .unwrap_or(&column)
+                    .to_upper_camel_case()
+            } else {
+                foreign_key.ref_table.to_upper_camel_case()
+            };
+
+            belongs_to.push(Relation {
+                variant,
+                target: foreign_key.ref_table.clone(),
+                kind: RelationKind::BelongsTo {
+                    from: foreign_key.column.clone(),
+                    to: foreign_key.ref_column.clone(),
+                    on_update: foreign_key.on_update,
+                    on_delete: foreign_key.on_delete,
+                },
+            });
+        }
+
+        relations
+            .entry(table.name.clone())
+            .or_default()
+            .extend(belongs_to);
+    }
+
+    // 反向關聯需要子實體對父實體有唯一的 `Related` 實作，即只有一個外鍵指向父實體
+    for table in tables.iter() {
+        for foreign_key in table.foreign_keys.iter() {
+            let same_target = table
+                .foreign_keys
+                .iter()
+                .filter(|other| other.ref_table == foreign_key.ref_table)
+                .count();
+
+            if same_target > 1 || foreign_key.ref_table == table.name {
+                continue;
+            }
+
+            let Some(parent) = relations.get_mut(&foreign_key.ref_table) else {
+                continue;
+            };
+
+            let mut variant = table.name.to_upper_camel_case();
+
+            if parent.iter().any(|relation| relation.variant == variant) {
+                variant = format!("{}Reverse", variant);
+            }
+
+            parent.push(Relation {
+                variant,
+                target: table.name.clone(),
+                kind: if table.is_unique(&foreign_key.column) {
+                    RelationKind::HasOne
+                } else {
+                    RelationKind::HasMany
+                },
+            });
+        }
+    }
+
+    let mut files: Vec<OutputFile> = tables
+        .iter()
+        .map(|table| OutputFile {
+            name: format!("{}.rs", table.module()),
+            content: write_entity(
+                table,
+                relations
+                    .get(&table.name)
+                    .map(Vec::as_slice)
+                    .unwrap_or_default(),
+                &enums,
+            ),
+        })
+        .collect();
+
+    let mut modules: Vec<String> = tables.iter().map(Table::module).collect();
+
+    if !enums.is_empty() {
+        files.push(OutputFile {
+            name: "sea_orm_active_enums.rs".into(),
+            content: write_enums(&enums),
+        });
+        modules.push("sea_orm_active_enums".into());
+    }
+
+    modules.sort();
+
+    files.push(OutputFile {
+        name: "prelude.rs".into(),
+        content: format!(
+            "{}\n\n{}",
+            HEADER,
+            tables
+                .iter()
+                .map(|table| format!(
+                    "pub use super::{}::Entity as {};\n",
+                    table.module(),
+                    table.name.to_upper_camel_case()
+                ))
+                .collect::<String>()
+        ),
+    });
+
+    files.push(OutputFile {
+        name: "mod.rs".into(),
+        content: format!(
+            "{}\n\npub mod prelude;\n\n{}",
+            HEADER,
+            modules
+                .iter()
+                .map(|module| format!("pub mod {};\n", module))
+                .collect::<String>()
+        ),
+    });
+
+    files
+}
+
+fn table_ref_name(table_ref: &TableRef) -> String {
+    match table_ref {
+        TableRef::Table(table)
+        | TableRef::SchemaTable(_, table)
+        | TableRef::DatabaseSchemaTable(_, _, table)
+        | TableRef::TableAlias(table, _)
+        | TableRef::SchemaTableAlias(_, table, _)
+        | TableRef::DatabaseSchemaTableAlias(_, _, table, _) => table.to_string(),
+        TableRef::SubQuery(_, alias)
+        | TableRef::ValuesList(_, alias)
+        | TableRef::FunctionCall(_, alias) => alias.to_string(),
+    }
+}
+
+fn table_from_stmt(
+    stmt: &TableCreateStatement,
+    enums: &mut BTreeMap<String, Vec<String>>,
+) -> Table {
+    let name = stmt
+        .get_table_name()
+        .map(table_ref_name)
+        .unwrap_or_default();
+
+    let primary_keys: Vec<String> = stmt
+        .get_indexes()
+        .iter()
+        .filter(|index| index.is_primary_key())
+        .flat_map(|index| index.get_index_spec().get_column_names())
+        .collect();
+
+    let unique_keys: Vec<String> = stmt
+        .get_indexes()
+        .iter()
+        .filter(|index| index.is_unique_key() && !index.is_primary_key())
+        .map(|index| index.get_index_spec().get_column_names())
+        .filter(|columns| columns.len() == 1)
+        .flatten()
+        .collect();
+
+    let columns = stmt
+        .get_columns()
+        .iter()
+        .map(|column| {
+            let name = column.get_column_name();
+            let spec = column.get_column_spec();
+            let primary_key = primary_keys.contains(&name)
+                || spec
+                    .iter()
+                    .any(|spec| matches!(spec, ColumnSpec::PrimaryKey));
+            let unique = unique_keys.contains(&name)
+                || spec
+                    .iter()
+                    .any(|spec| matches!(spec, ColumnSpec::UniqueKey));
+            let nullable =
+                !primary_key && !spec.iter().any(|spec| matches!(spec, ColumnSpec::NotNull));
+
+            Column {
+                rust_type: column
+                    .get_column_type()
+                    .map(|column_type| rust_type(column_type, enums))
+                    .unwrap_or_else(|| "String".into()),
+                name,
+                nullable,
+                primary_key,
+                unique,
+            }
+        })
+        .collect();
+
+    let foreign_keys = stmt
+        .get_foreign_key_create_stmts()
+        .iter()
+        .map(|stmt| stmt.get_foreign_key())
+        .filter(|foreign_key| foreign_key.get_columns().len() == 1)
+        .filter_map(|foreign_key| {
+            Some(ForeignKey {
+                column: foreign_key.get_columns().remove(0),
+                ref_table: table_ref_name(foreign_key.get_ref_table()?),
+                ref_column: foreign_key.get_ref_columns().first()?.clone(),
+                on_update: foreign_key.get_on_update(),
+                on_delete: foreign_key.get_on_delete(),
+            })
+        })
+        .collect();
+
+    Table {
+        name,
+        columns,
+        foreign_keys,
+    }
+}
+
+/// 欄位類型對應的 Rust 類型，與 sea-orm-codegen（chrono）的對應相同
+fn rust_type(column_type: &ColumnType, enums: &mut BTreeMap<String, Vec<String>>) -> String {
+    match column_type {
+        ColumnType::Char(_) | ColumnType::String(_) | ColumnType::Text => "String".into(),
+        ColumnType::TinyInteger => "i8".into(),
+        ColumnType::SmallInteger => "i16".into(),
+        ColumnType::Integer => "i32".into(),
+        ColumnType::BigInteger => "i64".into(),
+        ColumnType::TinyUnsigned => "u8".into(),
+        ColumnType::SmallUnsigned => "u16".into(),
+        ColumnType::Unsigned => "u32".into(),
+        ColumnType::BigUnsigned => "u64".into(),
+        ColumnType::Float => "f32".into(),
+        ColumnType::Double => "f64".into(),
+        ColumnType::Decimal(_) | ColumnType::Money(_) => "Decimal".into(),
+        ColumnType::DateTime | ColumnType::Timestamp => "DateTime".into(),
+        ColumnType::TimestampWithTimeZone => "DateTimeWithTimeZone".into(),
+        ColumnType::Time => "Time".into(),
+        ColumnType::Date => "Date".into(),
+        ColumnType::Year => "i32".into(),
+        ColumnType::Blob
+        | ColumnType::Binary(_)
+        | ColumnType::VarBinary(_)
+        | ColumnType::Bit(_)
+        | ColumnType::VarBit(_) => "Vec<u8>".into(),
+        ColumnType::Boolean => "bool".into(),
+        ColumnType::Json | ColumnType::JsonBinary => "Json".into(),
+        ColumnType::Uuid => "Uuid".into(),
+        ColumnType::Enum { name, variants } => {
+            let name = name.to_string();
+
+            enums
+                .entry(name.clone())
+                .or_insert_with(|| variants.iter().map(|variant| variant.to_string()).collect());
+
+            name.to_upper_camel_case()
+        }
+        ColumnType::Array(column_type) => format!("Vec<{}>", rust_type(column_type, enums)),
+        _ => "String".into(),
+    }
+}
+
+fn foreign_key_action(action: ForeignKeyAction) -> &'static str {
+    match action {
+        ForeignKeyAction::Restrict => "Restrict",
+        ForeignKeyAction::Cascade => "Cascade",
+        ForeignKeyAction::SetNull => "SetNull",
+        ForeignKeyAction::NoAction => "NoAction",
+        ForeignKeyAction::SetDefault => "SetDefault",
+    }
+}
+
+fn write_entity(
+    table: &Table,
+    relations: &[Relation],
+    enums: &BTreeMap<String, Vec<String>>,
+) -> String {
+    let mut out = format!("{}\n\n", HEADER);
+
+    let used_enums: Vec<String> = enums
+        .keys()
+        .map(|name| name.to_upper_camel_case())
+        .filter(|name| {
+            table.columns.iter().any(|column| {
+                column.rust_type == *name || column.rust_type == format!("Vec<{}>", name)
+            })
+        })
+        .collect();
+
+    for name in used_enums {
+        out.push_str(&format!("use super::sea_orm_active_enums::{};\n", name));
+    }
+
+    out.push_str("use sea_orm::entity::prelude::*;\n\n");
+    out.push_str("#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]\n");
+    out.push_str(&format!("#[sea_orm(table_name = \"{}\")]\n", table.name));
+    out.push_str("pub struct Model {\n");
+
+    let composite_key = table.primary_key_len() > 1;
+
+    for column in table.columns.iter() {
+        let mut attrs: Vec<String> = Vec::new();
+
+        if column.primary_key {
+            attrs.push("primary_key".into());
+
+            // 非整數或複合主鍵不是自動遞增
+            if composite_key || !column.rust_type.starts_with('i') {
+                attrs.push("auto_increment = false".into());
+            }
+        } else if column.unique {
+            attrs.push("unique".into());
+        }
+
+        if column.field_name() != column.name {
+            attrs.push(format!("column_name = \"{}\"", column.name));
+        }
+
+        if !attrs.is_empty() {
+            out.push_str(&format!("    #[sea_orm({})]\n", attrs.join(", ")));
+        }
+
+        let rust_type = if column.nullable {
+            format!("Option<{}>", column.rust_type)
+        } else {
+            column.rust_type.clone()
+        };
+
+        out.push_str(&format!(
+            "    pub {}: {},\n",
+            column.field_name(),
+            rust_type
+        ));
+    }
+
+    out.push_str("}\n\n");
+    out.push_str("#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]\n");
+    out.push_str("pub enum Relation {\n");
+
+    for relation in relations.iter() {
+        let target = format!("super::{}::Entity", relation.target.to_snake_case());
+
+        match &relation.kind {
+            RelationKind::BelongsTo {
+                from,
+                to,
+                on_update,
+                on_delete,
+            } => {
+                out.push_str("    #[sea_orm(\n");
+                out.push_str(&format!("        belongs_to = \"{}\",\n", target));
+                out.push_str(&format!(
+                    "        from = \"Column::{}\",\n",
+                    from.to_snake_case().to_upper_camel_case()
+                ));
+                out.push_str(&format!(
+                    "        to = \"super::{}::Column::{}\",\n",
+                    relation.target.to_snake_case(),
+                    to.to_snake_case().to_upper_camel_case()
+                ));
+
+                if let Some(action) = on_update {
+                    out.push_str(&format!(
+                        "        on_update = \"{}\",\n",
+                        foreign_key_action(*action)
+                    ));
+                }
+
+                if let Some(action) = on_delete {
+                    out.push_str(&format!(
+                        "        on_delete = \"{}\",\n",
+                        foreign_key_action(*action)
+                    ));
+                }
+
+                out.push_str("    )]\n");
+            }
+            RelationKind::HasMany => {
+                out.push_str(&format!("    #[sea_orm(has_many = \"{}\")]\n", target));
+            }
+            RelationKind::HasOne => {
+                out.push_str(&format!("    #[sea_orm(has_one = \"{}\")]\n", target));
+            }
+        }
+
+        out.push_str(&format!("    {},\n", relation.variant));
+    }
+
+    out.push_str("}\n\n");
+
+    for relation in relations.iter() {
+        let same_target = relations
+            .iter()
+            .filter(|other| other.target == relation.target)
+            .count();
+
+        if same_target > 1 || relation.target == table.name {
+            continue;
+        }
+
+        out.push_str(&format!(
+            "impl Related<super::{}::Entity> for Entity {{\n    fn to() -> RelationDef {{\n        Relation::{}.def()\n    }}\n}}\n\n",
+            relation.target.to_snake_case(),
+            relation.variant
+        ));
+    }
+
+    out.push_str("impl ActiveModelBehavior for ActiveModel {}\n");
+
+    out
+}
+
+fn write_enums(enums: &BTreeMap<String, Vec<String>>) -> String {
+    let mut out = format!("{}\n\nuse sea_orm::entity::prelude::*;\n", HEADER);
+
+    for (name, variants) in enums.iter() {
+        out.push_str("\n#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]\n");
+        out.push_str(&format!(
+            "#[sea_orm(rs_type = \"String\", db_type = \"Enum\", enum_name = \"{}\")]\n",
+            name
+        ));
+        out.push_str(&format!("pub enum {} {{\n", name.to_upper_camel_case()));
+
+        for variant in variants.iter() {
+            let mut ident = variant.to_upper_camel_case();
+
+            if !ident.starts_with(|c: char| c.is_ascii_alphabetic()) {
+                ident = format!("Variant{}", ident);
+            }
+
+            out.push_str(&format!("    #[sea_orm(string_value = \"{}\")]\n", variant));
+            out.push_str(&format!("    {},\n", ident));
+        }
+
+        out.push_str("}\n");
+    }
+
+    out
+}
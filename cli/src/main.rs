@@ -0,0 +1,183 @@
+// Derived from Seaography (github.com/SeaQL/seaography)
+// Modifications Copyright (c) 2025 Stephen J. Li
+
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use clap::Parser;
+
+mod annotate;
+mod discover;
+mod entity;
+mod project;
+
+use entity::OutputFile;
+
+/// 由資料庫或 sea-orm-codegen 產生的實體目錄，產生可直接執行的 GraphQL 伺服器
+#[derive(Debug, Parser)]
+#[command(version)]
+struct Args {
+    /// 資料庫連線網址（如 `sqlite://sakila.db`），或 sea-orm-codegen 產生的實體目錄
+    source: String,
+
+    /// 產生的 crate 名稱
+    crate_name: String,
+
+    /// 輸出目錄
+    destination: PathBuf,
+
+    /// 伺服器預設連線的資料庫，`source` 為資料庫時預設相同
+    #[arg(long)]
+    database_url: Option<String>,
+
+    /// 讀取的 Postgres schema
+    #[arg(long, default_value = "public")]
+    database_schema: String,
+
+    /// 略過的資料表，以逗號分隔
+    #[arg(long, value_delimiter = ',')]
+    ignore_tables: Vec<String>,
+
+    /// 以本地路徑依賴 async-graphql-template，而非 crates.io 版本
+    #[arg(long)]
+    template_path: Option<PathBuf>,
+
+    /// 查詢的深度上限
+    #[arg(long)]
+    depth_limit: Option<usize>,
+
+    /// 查詢的複雜度上限
+    #[arg(long)]
+    complexity_limit: Option<usize>,
+
+    /// 以 Apollo Federation v2 子圖的形式提供 `_entities` 與 `_service`
+    #[arg(long)]
+    federation: bool,
+}
+
+/// 讀取實體目錄中的所有 `.rs` 檔案
+fn read_entity_dir(dir: &Path) -> Result<Vec<OutputFile>, Box<dyn Error>> {
+    let mut files = Vec::new();
+
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+
+        if path.extension().map_or(true, |extension| extension != "rs") {
+            continue;
+        }
+
+        files.push(OutputFile {
+            name: path.file_name().unwrap().to_string_lossy().into_owned(),
+            content: fs::read_to_string(&path)?,
+        });
+    }
+
+    files.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(files)
+}
+
+fn write_files(dir: &Path, files: &[OutputFile]) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    let mut paths = Vec::new();
+
+    for file in files.iter() {
+        let path = dir.join(&file.name);
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        println!("Writing {}", path.display());
+        fs::write(&path, &file.content)?;
+
+        paths.push(path);
+    }
+
+    Ok(paths)
+}
+
+/// 與 sea-orm-cli 相同以 rustfmt 格式化，未安裝 rustfmt 時保留原始輸出
+fn format_files(paths: &[PathBuf]) {
+    for path in paths
+        .iter()
+        .filter(|path| path.extension().is_some_and(|extension| extension == "rs"))
+    {
+        let _ = Command::new("rustfmt")
+            .arg("--edition")
+            .arg("2021")
+            .arg(path)
+            .status();
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    let args = Args::parse();
+
+    let source_dir = Path::new(&args.source);
+
+    let (files, database_url) = if source_dir.is_dir() {
+        (read_entity_dir(source_dir)?, args.database_url.clone())
+    } else {
+        println!("Discovering schema ...");
+
+        let tables =
+            discover::discover(&args.source, &args.database_schema, &args.ignore_tables).await?;
+
+        (
+            entity::write_entities(&tables),
+            Some(
+                args.database_url
+                    .clone()
+                    .unwrap_or_else(|| args.source.clone()),
+            ),
+        )
+    };
+
+    let mut entities = Vec::new();
+    let mut enums = Vec::new();
+    let mut annotated = Vec::new();
+
+    for file in files.into_iter() {
+        let module = file.name.trim_end_matches(".rs").to_owned();
+
+        let content = if ["mod", "prelude", "lib"].contains(&module.as_str()) {
+            file.content
+        } else {
+            annotate::annotate(&module, &file.content)?
+        };
+
+        entities.extend(annotate::entity_info(&module, &content)?);
+        enums.extend(annotate::active_enums(&content)?);
+
+        annotated.push(OutputFile {
+            name: file.name,
+            content,
+        });
+    }
+
+    entities.sort_by(|a, b| a.module.cmp(&b.module));
+
+    let options = project::ProjectOptions {
+        crate_name: &args.crate_name,
+        database_url: database_url.as_deref(),
+        template_path: args.template_path.as_deref(),
+        depth_limit: args.depth_limit,
+        complexity_limit: args.complexity_limit,
+        federation: args.federation,
+    };
+
+    let mut paths = write_files(&args.destination.join("src").join("entities"), &annotated)?;
+    paths.extend(write_files(
+        &args.destination,
+        &project::write_project(&options, &entities, &enums),
+    )?);
+
+    format_files(&paths);
+
+    println!("... Done.");
+
+    Ok(())
+}
@@ -0,0 +1,450 @@
+// Derived from Seaography (github.com/SeaQL/seaography)
+// Modifications Copyright (c) 2025 Stephen J. Li
+
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use heck::ToSnakeCase;
+
+use crate::annotate::{EntityInfo, KeyField};
+use crate::entity::{OutputFile, HEADER};
+
+/// 產生伺服器骨架的選項
+pub struct ProjectOptions<'a> {
+    pub crate_name: &'a str,
+    /// 伺服器預設連線的資料庫，可由環境變數 `DATABASE_URL` 覆蓋
+    pub database_url: Option<&'a str>,
+    /// async-graphql-template 的本地路徑，未提供時使用 crates.io 版本
+    pub template_path: Option<&'a Path>,
+    pub depth_limit: Option<usize>,
+    pub complexity_limit: Option<usize>,
+    /// 合併各實體的 `EntityQuery` 並開啟 federation
+    pub federation: bool,
+}
+
+/// 產生 `Cargo.toml`、`main.rs`、`lib.rs` 與 `query_root.rs`
+pub fn write_project(
+    options: &ProjectOptions<'_>,
+    entities: &[EntityInfo],
+    enums: &[String],
+) -> Vec<OutputFile> {
+    vec![
+        OutputFile {
+            name: "Cargo.toml".into(),
+            content: write_cargo_toml(options, entities),
+        },
+        OutputFile {
+            name: "src/main.rs".into(),
+            content: write_main(options),
+        },
+        OutputFile {
+            name: "src/lib.rs".into(),
+            content: write_lib(),
+        },
+        OutputFile {
+            name: "src/query_root.rs".into(),
+            content: write_query_root(options, entities, enums),
+        },
+    ]
+}
+
+/// 依實體使用的類型決定需要開啟的 feature
+#[derive(Default)]
+struct Features {
+    chrono: bool,
+    decimal: bool,
+    json: bool,
+    uuid: bool,
+    array: bool,
+}
+
+impl Features {
+    fn from_entities(entities: &[EntityInfo]) -> Self {
+        let mut features = Self::default();
+
+        for rust_type in entities.iter().flat_map(|entity| entity.types.iter()) {
+            let element = rust_type
+                .strip_prefix("Vec<")
+                .and_then(|element| element.strip_suffix('>'));
+
+            if element.is_some_and(|element| element != "u8") {
+                features.array = true;
+            }
+
+            let rust_type = element.unwrap_or(rust_type);
+
+            match rust_type {
+                "Date"
+                | "Time"
+                | "DateTime"
+                | "DateTimeUtc"
+                | "DateTimeLocal"
+                | "DateTimeWithTimeZone" => features.chrono = true,
+                "Decimal" => features.decimal = true,
+                "Json" => features.json = true,
+                "Uuid" => features.uuid = true,
+                _ => {}
+            }
+        }
+
+        features
+    }
+}
+
+fn quoted_list(items: &[&str]) -> String {
+    items
+        .iter()
+        .map(|item| format!("\"{}\"", item))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn write_cargo_toml(options: &ProjectOptions<'_>, entities: &[EntityInfo]) -> String {
+    let features = Features::from_entities(entities);
+
+    // derive 產生的 Loader 以 `async_trait` 實作
+    let mut graphql_features = vec!["dataloader", "boxed-trait"];
+    let mut template_features = Vec::new();
+    let mut orm_features = vec!["macros", "runtime-tokio-native-tls"];
+
+    let scheme = options
+        .database_url
+        .and_then(|url| url.split(':').next())
+        .unwrap_or_default();
+
+    match scheme {
+        "mysql" => orm_features.push("sqlx-mysql"),
+        "postgres" | "postgresql" => orm_features.push("sqlx-postgres"),
+        "sqlite" => orm_features.push("sqlx-sqlite"),
+        _ => orm_features.extend(["sqlx-mysql", "sqlx-postgres", "sqlx-sqlite"]),
+    }
+
+    if features.chrono {
+        graphql_features.push("chrono");
+        template_features.push("with-chrono");
+        orm_features.push("with-chrono");
+    }
+
+    if features.decimal {
+        graphql_features.push("decimal");
+        template_features.push("with-decimal");
+        orm_features.push("with-rust_decimal");
+    }
+
+    if features.json {
+        template_features.push("with-json");
+        orm_features.push("with-json");
+    }
+
+    if features.uuid {
+        graphql_features.push("uuid");
+        template_features.push("with-uuid");
+        orm_features.push("with-uuid");
+    }
+
+    if features.array {
+        template_features.push("with-array");
+        orm_features.push("postgres-array");
+    }
+
+    let template_source = match options.template_path {
+        Some(path) => format!("path = \"{}\"", path.display()),
+        None => format!("version = \"{}\"", env!("CARGO_PKG_VERSION")),
+    };
+
+    format!(
+        r#"[package]
+name = "{crate_name}"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+async-graphql = {{ version = "7.0", features = [{graphql_features}] }}
+async-graphql-template = {{ {template_source}, features = [{template_features}] }}
+async-trait = "0.1"
+axum = "0.8"
+sea-orm = {{ version = "~1.1.5", features = [{orm_features}] }}
+tokio = {{ version = "1", features = ["macros", "rt-multi-thread"] }}
+"#,
+        crate_name = options.crate_name,
+        graphql_features = quoted_list(&graphql_features),
+        template_source = template_source,
+        template_features = quoted_list(&template_features),
+        orm_features = quoted_list(&orm_features),
+    )
+}
+
+fn write_main(options: &ProjectOptions<'_>) -> String {
+    let database_url = match options.database_url {
+        Some(url) => format!(
+            "std::env::var(\"DATABASE_URL\").unwrap_or_else(|_| \"{}\".into())",
+            url
+        ),
+        None => "std::env::var(\"DATABASE_URL\").expect(\"DATABASE_URL is not set\")".into(),
+    };
+
+    let mut limits = String::new();
+
+    if let Some(depth) = options.depth_limit {
+        limits.push_str(&format!("\n        .limit_depth({})", depth));
+    }
+
+    if let Some(complexity) = options.complexity_limit {
+        limits.push_str(&format!("\n        .limit_complexity({})", complexity));
+    }
+
+    if options.federation {
+        limits.push_str("\n        .enable_federation()");
+    }
+
+    format!(
+        r#"{header}
+
+use async_graphql::{{
+    dataloader::DataLoader, http::GraphiQLSource, EmptyMutation, EmptySubscription, Schema,
+}};
+use axum::{{
+    extract::State,
+    response::{{Html, IntoResponse}},
+    routing::get,
+    Json, Router,
+}};
+use {crate_ident}::{{OrmDataloader, QueryRoot}};
+
+type AppSchema = Schema<QueryRoot, EmptyMutation, EmptySubscription>;
+
+async fn graphql_handler(
+    State(schema): State<AppSchema>,
+    Json(request): Json<async_graphql::Request>,
+) -> Json<async_graphql::Response> {{
+    Json(schema.execute(request).await)
+}}
+
+async fn graphiql() -> impl IntoResponse {{
+    Html(GraphiQLSource::build().endpoint("/").finish())
+}}
+
+#[tokio::main]
+async fn main() {{
+    let database_url = {database_url};
+    let address = std::env::var("LISTEN_ADDRESS").unwrap_or_else(|_| "0.0.0.0:8000".into());
+
+    let db = sea_orm::Database::connect(&database_url)
+        .await
+        .expect("Fail to initialize database connection");
+
+    let schema = Schema::build(QueryRoot::default(), EmptyMutation, EmptySubscription)
+        .data(DataLoader::new(OrmDataloader {{ db }}, tokio::spawn)){limits}
+        .finish();
+
+    let app = Router::new()
+        .route("/", get(graphiql).post(graphql_handler))
+        .with_state(schema);
+
+    let listener = tokio::net::TcpListener::bind(&address)
+        .await
+        .expect("Fail to bind address");
+
+    println!("Visit GraphQL Playground at http://{{}}", address);
+
+    axum::serve(listener, app).await.expect("Fail to start server");
+}}
+"#,
+        header = HEADER,
+        crate_ident = options.crate_name.to_snake_case(),
+        database_url = database_url,
+        limits = limits,
+    )
+}
+
+fn write_lib() -> String {
+    format!(
+        r#"{header}
+
+extern crate async_graphql_template as seaography;
+
+pub mod entities;
+pub mod query_root;
+
+pub use query_root::QueryRoot;
+
+pub struct OrmDataloader {{
+    pub db: sea_orm::DatabaseConnection,
+}}
+"#,
+        header = HEADER
+    )
+}
+
+/// 查詢鍵類型中需要引入的名稱，枚舉來自 `sea_orm_active_enums`，其他來自 sea-orm 的 prelude
+fn key_type_imports(entities: &[EntityInfo], enums: &[String]) -> BTreeSet<String> {
+    const STD_TYPES: [&str; 12] = [
+        "i8", "i16", "i32", "i64", "u8", "u16", "u32", "u64", "bool", "char", "String", "Vec",
+    ];
+
+    entities
+        .iter()
+        .flat_map(|entity| entity.primary_keys.iter().chain(entity.unique_keys.iter()))
+        .flat_map(|key| {
+            key.rust_type
+                .split(|c: char| !c.is_alphanumeric() && c != '_')
+                .filter(|name| !name.is_empty() && !STD_TYPES.contains(name))
+                .map(|name| {
+                    if enums.iter().any(|item| item == name) {
+                        format!("crate::entities::sea_orm_active_enums::{}", name)
+                    } else {
+                        format!("sea_orm::prelude::{}", name)
+                    }
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+fn lookup_fn(entity: &EntityInfo, fn_name: &str, lookup: &str, keys: &[KeyField]) -> String {
+    let params: String = keys
+        .iter()
+        .map(|key| format!(", {}: {}", key.name, key.rust_type))
+        .collect();
+    let args: Vec<&str> = keys.iter().map(|key| key.name.as_str()).collect();
+
+    format!(
+        r#"
+    async fn {fn_name}(
+        &self,
+        ctx: &Context<'_>{params}
+    ) -> async_graphql::Result<Option<{module}::Model>> {{
+        {module}::{lookup}(ctx, {args}).await
+    }}
+"#,
+        fn_name = fn_name,
+        params = params,
+        module = entity.module,
+        lookup = lookup,
+        args = args.join(", "),
+    )
+}
+
+fn write_query_root(
+    options: &ProjectOptions<'_>,
+    entities: &[EntityInfo],
+    enums: &[String],
+) -> String {
+    // federation 時查詢與各實體的 `EntityQuery` 合併為 `QueryRoot`
+    let query_name = if options.federation {
+        "Query"
+    } else {
+        "QueryRoot"
+    };
+
+    let mut out = format!(
+        r#"{header}
+
+use async_graphql::{{
+    dataloader::DataLoader,
+    types::connection::{{Connection, EmptyFields}},
+    Context, Object,
+}};
+use sea_orm::{{ConnectionTrait, EntityTrait, QueryFilter}};
+"#,
+        header = HEADER
+    );
+
+    for import in key_type_imports(entities, enums) {
+        out.push_str(&format!("use {};\n", import));
+    }
+
+    out.push_str(&format!(
+        "\nuse crate::entities::*;\nuse crate::OrmDataloader;\n\n#[derive(Default)]\npub struct {0};\n\n#[Object]\nimpl {0} {{",
+        query_name
+    ));
+
+    for (index, entity) in entities.iter().enumerate() {
+        if index > 0 {
+            out.push('\n');
+        }
+
+        out.push_str(&format!(
+            r#"
+    async fn {module}(
+        &self,
+        ctx: &Context<'_>,
+        filters: Option<{module}::Filter>,
+        pagination: Option<seaography::Pagination>,
+        order_by: Option<Vec<seaography::Ordered<{module}::OrderBy>>>,
+        distinct_on: Option<Vec<{module}::QueryColumn>>,
+        #[graphql(visible = "{module}::has_soft_delete")] with_deleted: Option<bool>,
+    ) -> async_graphql::Result<
+        Connection<String, {module}::Model, {module}::ConnectionFields, EmptyFields>,
+    > {{
+        let db = &ctx.data::<DataLoader<OrmDataloader>>()?.loader().db;
+
+        let backend = db.get_database_backend();
+
+        let condition = {module}::try_filter_with_deleted(ctx, filters.clone(), with_deleted, backend)?;
+        let stmt = {module}::distinct_on_guarded(
+            ctx,
+            {module}::Entity::find().filter(condition.clone()),
+            distinct_on,
+            condition,
+            order_by.as_deref(),
+            backend,
+        )?;
+        let stmt = {module}::order_by_guarded(ctx, stmt, order_by)?;
+        let columns = {module}::selected_connection_columns(&ctx.field());
+
+        seaography::fetch_connection_with_columns::<_, {module}::PartialModel, _>(
+            db,
+            stmt,
+            Some(columns),
+            pagination,
+            |pagination| {module}::ConnectionFields {{
+                pagination,
+                filters,
+                with_deleted,
+            }},
+        )
+        .await
+    }}
+"#,
+            module = entity.module
+        ));
+
+        if !entity.primary_keys.is_empty() {
+            out.push_str(&lookup_fn(
+                entity,
+                &format!("{}_by_id", entity.module),
+                "by_id",
+                &entity.primary_keys,
+            ));
+        }
+
+        for key in entity.unique_keys.iter() {
+            let column = key.name.to_snake_case();
+
+            out.push_str(&lookup_fn(
+                entity,
+                &format!("{}_by_{}", entity.module, column),
+                &format!("by_{}", column),
+                std::slice::from_ref(key),
+            ));
+        }
+    }
+
+    out.push_str("}\n");
+
+    if options.federation {
+        let members: Vec<String> = entities
+            .iter()
+            .filter(|entity| !entity.primary_keys.is_empty())
+            .map(|entity| format!("{}::EntityQuery", entity.module))
+            .collect();
+
+        out.push_str(&format!(
+            "\n#[derive(async_graphql::MergedObject, Default)]\npub struct QueryRoot(Query, {});\n",
+            members.join(", ")
+        ));
+    }
+
+    out
+}
@@ -0,0 +1,146 @@
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command};
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+
+use sqlx::sqlite::{SqliteConnectOptions, SqlitePool};
+
+const SCHEMA: &str = r#"
+CREATE TABLE language (
+    id INTEGER PRIMARY KEY,
+    name TEXT NOT NULL
+);
+
+CREATE TABLE film (
+    id INTEGER PRIMARY KEY,
+    title TEXT NOT NULL,
+    description TEXT,
+    language_id INTEGER NOT NULL REFERENCES language (id)
+);
+
+INSERT INTO language (id, name) VALUES (1, 'English'), (2, 'French');
+
+INSERT INTO film (id, title, description, language_id) VALUES
+    (1, 'A', 'first', 1),
+    (2, 'B', 'second', 1),
+    (3, 'C', 'third', 2);
+"#;
+
+/// 結束時停止產生的伺服器
+struct Server(Child);
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        let _ = self.0.kill();
+        let _ = self.0.wait();
+    }
+}
+
+async fn create_database(path: &Path) {
+    let options = SqliteConnectOptions::new()
+        .filename(path)
+        .create_if_missing(true);
+    let pool = SqlitePool::connect_with(options).await.unwrap();
+
+    sqlx::raw_sql(SCHEMA).execute(&pool).await.unwrap();
+    pool.close().await;
+}
+
+fn free_address() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+
+    listener.local_addr().unwrap().to_string()
+}
+
+/// 以 HTTP/1.0 送出 GraphQL 請求並回傳回應內容
+fn post_query(address: &str, query: &str) -> Option<String> {
+    let body = format!(r#"{{"query":"{}"}}"#, query.replace('"', "\\\""));
+    let mut stream = TcpStream::connect(address).ok()?;
+
+    write!(
+        stream,
+        "POST / HTTP/1.0\r\nHost: {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
+        address,
+        body.len(),
+        body
+    )
+    .ok()?;
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response).ok()?;
+
+    response
+        .split_once("\r\n\r\n")
+        .map(|(_, body)| body.to_owned())
+}
+
+#[tokio::test]
+async fn generated_project_builds_and_serves_queries() {
+    let dir =
+        std::env::temp_dir().join(format!("async-graphql-template-cli-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let database = dir.join("test.db");
+    let project = dir.join("project");
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    // 產生的專案共用同一個 target 目錄，重複執行時只需增量編譯
+    let target_dir = manifest_dir.join("target").join("generated-project");
+
+    create_database(&database).await;
+
+    let status = Command::new(env!("CARGO_BIN_EXE_async-graphql-template-cli"))
+        .arg(format!("sqlite://{}", database.display()))
+        .arg("generated")
+        .arg(&project)
+        .arg("--template-path")
+        .arg(manifest_dir.parent().unwrap())
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    let query_root = std::fs::read_to_string(project.join("src").join("query_root.rs")).unwrap();
+    assert!(query_root.contains("film::selected_connection_columns"));
+    assert!(query_root.contains("film::distinct_on_guarded"));
+
+    let status = Command::new(std::env::var("CARGO").unwrap_or_else(|_| "cargo".into()))
+        .arg("build")
+        .current_dir(&project)
+        .env("CARGO_TARGET_DIR", &target_dir)
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    let address = free_address();
+    let _server = Server(
+        Command::new(target_dir.join("debug").join("generated"))
+            .env("LISTEN_ADDRESS", &address)
+            .spawn()
+            .unwrap(),
+    );
+
+    // 根查詢以 distinctOn 取每種語言排序最前的電影，只選取部分欄位並載入關聯
+    let query = "{ film(distinctOn: [LANGUAGE_ID], orderBy: [{ languageId: ASC }, { title: DESC }]) { nodes { title language { name } } } }";
+    let started = Instant::now();
+
+    let response = loop {
+        if let Some(response) = post_query(&address, query) {
+            break response;
+        }
+
+        assert!(
+            started.elapsed() < Duration::from_secs(30),
+            "server did not start"
+        );
+        sleep(Duration::from_millis(100));
+    };
+
+    assert_eq!(
+        response,
+        r#"{"data":{"film":{"nodes":[{"title":"B","language":{"name":"English"}},{"title":"C","language":{"name":"French"}}]}}}"#
+    );
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
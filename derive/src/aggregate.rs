@@ -0,0 +1,615 @@
+// Derived from Seaography (github.com/SeaQL/seaography)
+// Modifications Copyright (c) 2025 Stephen J. Li
+
+use heck::{ToSnakeCase, ToUpperCamelCase};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+
+use crate::filter::{IdentTypeTuple, SeaOrm};
+use crate::guard::{find_guard, output_guard_attr, FieldGuard};
+
+pub fn is_numeric_type(ty: &syn::Type) -> bool {
+    let numeric_types = [
+        "i8",
+        "i16",
+        "i32",
+        "i64",
+        "u8",
+        "u16",
+        "u32",
+        "u64",
+        "f32",
+        "f64",
+        #[cfg(feature = "with-decimal")]
+        "Decimal",
+    ];
+
+    numeric_types.contains(&ty.to_token_stream().to_string().as_str())
+}
+
+/// Decimal 欄位的 sum/avg 保留 Decimal，其餘數值欄位統一以 f64 表示
+fn is_decimal_type(ty: &syn::Type) -> bool {
+    ty.to_token_stream().to_string() == "Decimal"
+}
+
+pub fn aggregate_fn(
+    fields: &[IdentTypeTuple],
+    attrs: &SeaOrm,
+    shareable: bool,
+    guards: &[FieldGuard],
+) -> Result<TokenStream, crate::error::Error> {
+    let shareable = crate::federation::shareable_attr(shareable);
+
+    let entity_name = match &attrs.table_name {
+        Some(syn::Lit::Str(name)) => name.value().to_upper_camel_case(),
+        _ => return Err(crate::error::Error::Internal("Invalid entity name".into())),
+    };
+
+    let aggregate_name = format!("{}Aggregate", entity_name);
+    let sum_fields_name = format!("{}AggregateSumFields", entity_name);
+    let min_max_fields_name = format!("{}AggregateMinMaxFields", entity_name);
+    let aggregate_filter_name = format!("{}AggregateFilter", entity_name);
+    let sum_filter_name = format!("{}AggregateSumFilter", entity_name);
+    let min_max_filter_name = format!("{}AggregateMinMaxFilter", entity_name);
+    let group_by_key_name = format!("{}GroupByKey", entity_name);
+    let group_by_row_name = format!("{}GroupByRow", entity_name);
+    let connection_fields_name = format!("{}ConnectionFields", entity_name);
+
+    let numeric_fields: Vec<&IdentTypeTuple> = fields
+        .iter()
+        .filter(|(_, ty, _)| is_numeric_type(ty))
+        .collect();
+
+    let mut sum_fields: Vec<TokenStream> = Vec::new();
+    let mut min_max_fields: Vec<TokenStream> = Vec::new();
+    let mut sum_filters: Vec<TokenStream> = Vec::new();
+    let mut min_max_filters: Vec<TokenStream> = Vec::new();
+    let mut selects: Vec<TokenStream> = Vec::new();
+    let mut sum_values: Vec<TokenStream> = Vec::new();
+    let mut avg_values: Vec<TokenStream> = Vec::new();
+    let mut min_values: Vec<TokenStream> = Vec::new();
+    let mut max_values: Vec<TokenStream> = Vec::new();
+    let mut sum_conditions: Vec<TokenStream> = Vec::new();
+    let mut min_max_conditions: Vec<TokenStream> = Vec::new();
+    let mut having_guards: Vec<TokenStream> = Vec::new();
+
+    for (ident, ty, _) in numeric_fields.iter() {
+        let column = format_ident!("{}", ident.to_string().to_upper_camel_case());
+        let column_name = ident.to_string().to_snake_case();
+        let as_double = !is_decimal_type(ty);
+
+        let sum_type = if as_double {
+            quote! { f64 }
+        } else {
+            quote! { #ty }
+        };
+
+        let output_guard = output_guard_attr(guards, ident);
+
+        sum_fields.push(quote! {
+            #output_guard
+            pub #ident: Option<#sum_type>
+        });
+
+        min_max_fields.push(quote! {
+            #output_guard
+            pub #ident: Option<#ty>
+        });
+
+        if let Some(path) = find_guard(guards, ident) {
+            having_guards.push(quote! {
+                let sum = [&self.sum, &self.avg]
+                    .iter()
+                    .any(|filter| filter.as_ref().and_then(|filter| filter.#ident.as_ref()).is_some());
+                let min_max = [&self.min, &self.max]
+                    .iter()
+                    .any(|filter| filter.as_ref().and_then(|filter| filter.#ident.as_ref()).is_some());
+
+                if sum || min_max {
+                    #path(ctx)?;
+                }
+            });
+        }
+
+        sum_filters.push(quote! {
+            pub #ident: Option<async_graphql_template::TypeFilter<#sum_type>>
+        });
+
+        min_max_filters.push(quote! {
+            pub #ident: Option<async_graphql_template::TypeFilter<#ty>>
+        });
+
+        sum_conditions.push(quote! {
+            if let Some(filter) = filter.#ident {
+                condition = condition.add(filter.to_condition(async_graphql_template::aggregate_expr(
+                    func,
+                    sea_orm::IntoSimpleExpr::into_simple_expr(Column::#column),
+                    backend,
+                    #as_double,
+                )));
+            }
+        });
+
+        min_max_conditions.push(quote! {
+            if let Some(filter) = filter.#ident {
+                condition = condition.add(filter.to_condition(async_graphql_template::aggregate_expr(
+                    func,
+                    sea_orm::IntoSimpleExpr::into_simple_expr(Column::#column),
+                    backend,
+                    false,
+                )));
+            }
+        });
+
+        for (func, prefix, values) in [
+            (quote! { Sum }, "sum", &mut sum_values),
+            (quote! { Avg }, "avg", &mut avg_values),
+            (quote! { Min }, "min", &mut min_values),
+            (quote! { Max }, "max", &mut max_values),
+        ] {
+            let alias = format!("{}_{}", prefix, column_name);
+
+            selects.push(quote! {
+                let stmt = stmt.column_as(
+                    async_graphql_template::aggregate_expr(
+                        async_graphql_template::AggregateFunc::#func,
+                        sea_orm::IntoSimpleExpr::into_simple_expr(Column::#column),
+                        backend,
+                        #as_double,
+                    ),
+                    #alias,
+                );
+            });
+
+            values.push(quote! {
+                #ident: result.try_get("", #alias)?
+            });
+        }
+    }
+
+    // 沒有數值欄位時只提供 count，避免生成沒有欄位的 GraphQL 類型
+    let (numeric_structs, numeric_members, numeric_values, numeric_filter_members, numeric_having) =
+        if numeric_fields.is_empty() {
+            (quote! {}, quote! {}, quote! {}, quote! {}, quote! {})
+        } else {
+            (
+                quote! {
+                    #[derive(Debug, Clone, Default, async_graphql::SimpleObject)]
+                    #[graphql(name = #sum_fields_name)]
+                    #shareable
+                    pub struct AggregateSumFields {
+                        #(#sum_fields),*
+                    }
+
+                    #[derive(Debug, Clone, Default, async_graphql::SimpleObject)]
+                    #[graphql(name = #min_max_fields_name)]
+                    #shareable
+                    pub struct AggregateMinMaxFields {
+                        #(#min_max_fields),*
+                    }
+
+                    #[derive(Debug, Clone, async_graphql::InputObject)]
+                    #[graphql(name = #sum_filter_name)]
+                    pub struct AggregateSumFilter {
+                        #(#sum_filters),*
+                    }
+
+                    impl AggregateSumFilter {
+                        pub fn to_condition(
+                            self,
+                            func: async_graphql_template::AggregateFunc,
+                            backend: sea_orm::DbBackend,
+                        ) -> sea_orm::Condition {
+                            let filter = self;
+                            let mut condition = sea_orm::Condition::all();
+
+                            #(#sum_conditions)*
+
+                            condition
+                        }
+                    }
+
+                    #[derive(Debug, Clone, async_graphql::InputObject)]
+                    #[graphql(name = #min_max_filter_name)]
+                    pub struct AggregateMinMaxFilter {
+                        #(#min_max_filters),*
+                    }
+
+                    impl AggregateMinMaxFilter {
+                        pub fn to_condition(
+                            self,
+                            func: async_graphql_template::AggregateFunc,
+                            backend: sea_orm::DbBackend,
+                        ) -> sea_orm::Condition {
+                            let filter = self;
+                            let mut condition = sea_orm::Condition::all();
+
+                            #(#min_max_conditions)*
+
+                            condition
+                        }
+                    }
+                },
+                quote! {
+                    pub sum: AggregateSumFields,
+                    pub avg: AggregateSumFields,
+                    pub min: AggregateMinMaxFields,
+                    pub max: AggregateMinMaxFields,
+                },
+                quote! {
+                    sum: AggregateSumFields {
+                        #(#sum_values),*
+                    },
+                    avg: AggregateSumFields {
+                        #(#avg_values),*
+                    },
+                    min: AggregateMinMaxFields {
+                        #(#min_values),*
+                    },
+                    max: AggregateMinMaxFields {
+                        #(#max_values),*
+                    },
+                },
+                quote! {
+                    pub sum: Option<AggregateSumFilter>,
+                    pub avg: Option<AggregateSumFilter>,
+                    pub min: Option<AggregateMinMaxFilter>,
+                    pub max: Option<AggregateMinMaxFilter>,
+                },
+                quote! {
+                    if let Some(sum) = self.sum {
+                        condition = condition.add(sum.to_condition(async_graphql_template::AggregateFunc::Sum, backend));
+                    }
+
+                    if let Some(avg) = self.avg {
+                        condition = condition.add(avg.to_condition(async_graphql_template::AggregateFunc::Avg, backend));
+                    }
+
+                    if let Some(min) = self.min {
+                        condition = condition.add(min.to_condition(async_graphql_template::AggregateFunc::Min, backend));
+                    }
+
+                    if let Some(max) = self.max {
+                        condition = condition.add(max.to_condition(async_graphql_template::AggregateFunc::Max, backend));
+                    }
+                },
+            )
+        };
+
+    let unused_ctx = if having_guards.is_empty() {
+        quote! { let _ = ctx; }
+    } else {
+        quote! {}
+    };
+
+    let group_key_values: Vec<TokenStream> = fields
+        .iter()
+        .map(|(ident, ty, _)| {
+            let column = format_ident!("{}", ident.to_string().to_upper_camel_case());
+
+            quote! {
+                Column::#column => result.try_get::<#ty>("", "group_key")?.into()
+            }
+        })
+        .collect();
+
+    let group_by_fn = group_by_fn(
+        fields,
+        &group_by_key_name,
+        &group_by_row_name,
+        &shareable,
+        guards,
+    );
+
+    Ok(quote! {
+        #numeric_structs
+
+        #[derive(Debug, Clone, Default, async_graphql::SimpleObject)]
+        #[graphql(name = #aggregate_name)]
+        #shareable
+        pub struct Aggregate {
+            pub count: i64,
+            #numeric_members
+        }
+
+        /// 分組查詢的 HAVING 條件
+        #[derive(Debug, Clone, async_graphql::InputObject)]
+        #[graphql(name = #aggregate_filter_name)]
+        pub struct AggregateFilter {
+            pub count: Option<async_graphql_template::TypeFilter<i64>>,
+            #numeric_filter_members
+        }
+
+        impl AggregateFilter {
+            /// HAVING 條件使用受保護的欄位時需先通過其守衛
+            pub fn check_guards(&self, ctx: &async_graphql::Context<'_>) -> async_graphql::Result<()> {
+                #unused_ctx
+
+                #(#having_guards)*
+
+                Ok(())
+            }
+
+            pub fn to_condition(self, backend: sea_orm::DbBackend) -> sea_orm::Condition {
+                let mut condition = sea_orm::Condition::all();
+
+                if let Some(count) = self.count {
+                    condition = condition.add(count.to_condition(
+                        sea_orm::sea_query::Expr::col(sea_orm::sea_query::Asterisk).count(),
+                    ));
+                }
+
+                #numeric_having
+
+                condition
+            }
+        }
+
+        /// 在查詢中加入聚合欄位
+        pub fn aggregate_select(
+            stmt: sea_orm::Select<Entity>,
+            backend: sea_orm::DbBackend,
+        ) -> sea_orm::Select<Entity> {
+            use sea_orm::QuerySelect;
+
+            let stmt = stmt.column_as(sea_orm::sea_query::Expr::col(sea_orm::sea_query::Asterisk).count(), "count");
+
+            #(#selects)*
+
+            stmt
+        }
+
+        /// 讀取 `aggregate_select` 加入的聚合欄位
+        pub fn aggregate_from_result(result: &sea_orm::QueryResult) -> Result<Aggregate, sea_orm::DbErr> {
+            Ok(Aggregate {
+                count: result.try_get("", "count")?,
+                #numeric_values
+            })
+        }
+
+        /// 以 `Filter` 計算聚合結果
+        pub async fn aggregate<C>(db: &C, filters: Option<Filter>) -> Result<Aggregate, sea_orm::DbErr>
+        where
+            C: sea_orm::ConnectionTrait,
+        {
+            let condition = try_filter_recursive(filters, db.get_database_backend())?;
+
+            aggregate_condition(db, condition).await
+        }
+
+        /// 以任意條件計算聚合結果，供關聯欄位加上外鍵條件使用
+        pub async fn aggregate_condition<C>(
+            db: &C,
+            condition: sea_orm::Condition,
+        ) -> Result<Aggregate, sea_orm::DbErr>
+        where
+            C: sea_orm::ConnectionTrait,
+        {
+            use sea_orm::{EntityTrait, QueryFilter, QuerySelect, QueryTrait};
+
+            let backend = db.get_database_backend();
+
+            let stmt = aggregate_select(Entity::find().filter(condition).select_only(), backend);
+
+            let result = db
+                .query_one(stmt.build(backend))
+                .await?
+                .ok_or_else(|| sea_orm::DbErr::RecordNotFound("aggregate returned no row".into()))?;
+
+            aggregate_from_result(&result)
+        }
+
+        /// 依 `column` 分組，一次計算 `values` 中每個值的聚合結果，供 has-many 關聯的聚合欄位批次查詢
+        ///
+        /// 沒有資料列的值不在結果中，呼叫者以 `Aggregate::default()` 補上。
+        pub async fn aggregate_grouped<C>(
+            db: &C,
+            column: Column,
+            values: Vec<sea_orm::Value>,
+            condition: sea_orm::Condition,
+        ) -> Result<Vec<(sea_orm::Value, Aggregate)>, sea_orm::DbErr>
+        where
+            C: sea_orm::ConnectionTrait,
+        {
+            use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QuerySelect, QueryTrait};
+
+            let backend = db.get_database_backend();
+
+            let stmt = Entity::find()
+                .filter(column.is_in(values))
+                .filter(condition)
+                .select_only()
+                .column_as(column.select_as(sea_orm::sea_query::Expr::col((Entity, column))), "group_key")
+                .group_by(column);
+
+            let stmt = aggregate_select(stmt, backend);
+
+            db.query_all(stmt.build(backend))
+                .await?
+                .iter()
+                .map(|result| {
+                    let value: sea_orm::Value = match column {
+                        #(#group_key_values),*
+                    };
+
+                    Ok((value, aggregate_from_result(result)?))
+                })
+                .collect()
+        }
+
+        #group_by_fn
+
+        /// 分頁連線的額外欄位，`aggregate` 與 `groupBy` 在被查詢時才依同一組過濾條件計算
+        #[derive(async_graphql::SimpleObject)]
+        #[graphql(name = #connection_fields_name, complex)]
+        #shareable
+        pub struct ConnectionFields {
+            #[graphql(flatten)]
+            pub pagination: async_graphql_template::ExtraPaginationFields,
+            #[graphql(skip)]
+            pub filters: Option<Filter>,
+            /// 是否包含已軟刪除的資料，與連線本身相同
+            #[graphql(skip)]
+            pub with_deleted: Option<bool>,
+        }
+
+        #[async_graphql::ComplexObject]
+        impl ConnectionFields {
+            pub async fn aggregate<'a>(
+                &self,
+                ctx: &async_graphql::Context<'a>,
+            ) -> async_graphql::Result<Aggregate> {
+                let data_loader = ctx.data::<async_graphql::dataloader::DataLoader<crate::OrmDataloader>>()?;
+                let db = &data_loader.loader().db;
+
+                let condition = try_filter_with_deleted(
+                    ctx,
+                    self.filters.clone(),
+                    self.with_deleted,
+                    db.get_database_backend(),
+                )?;
+
+                Ok(aggregate_condition(db, condition).await?)
+            }
+
+            pub async fn group_by<'a>(
+                &self,
+                ctx: &async_graphql::Context<'a>,
+                by: Vec<QueryColumn>,
+                having: Option<AggregateFilter>,
+            ) -> async_graphql::Result<Vec<GroupByRow>> {
+                let data_loader = ctx.data::<async_graphql::dataloader::DataLoader<crate::OrmDataloader>>()?;
+                let db = &data_loader.loader().db;
+
+                for column in by.iter() {
+                    column.check_guard(ctx)?;
+                }
+
+                if let Some(having) = &having {
+                    having.check_guards(ctx)?;
+                }
+
+                let condition = try_filter_with_deleted(
+                    ctx,
+                    self.filters.clone(),
+                    self.with_deleted,
+                    db.get_database_backend(),
+                )?;
+
+                Ok(group_by_condition(db, by, condition, having).await?)
+            }
+        }
+    })
+}
+
+fn group_by_fn(
+    fields: &[IdentTypeTuple],
+    group_by_key_name: &str,
+    group_by_row_name: &str,
+    shareable: &TokenStream,
+    guards: &[FieldGuard],
+) -> TokenStream {
+    let (key_fields, key_values): (Vec<_>, Vec<_>) = fields
+        .iter()
+        .map(|(ident, ty, _)| {
+            let column = format_ident!("{}", ident.to_string().to_upper_camel_case());
+            let alias = format!("key_{}", column);
+            let output_guard = output_guard_attr(guards, ident);
+
+            (
+                quote! {
+                    #output_guard
+                    pub #ident: Option<#ty>
+                },
+                quote! {
+                    #ident: if by.contains(&QueryColumn::#column) {
+                        result.try_get("", #alias)?
+                    } else {
+                        None
+                    }
+                },
+            )
+        })
+        .unzip();
+
+    quote! {
+        /// 分組鍵，只有 `by` 中的欄位有值
+        #[derive(Debug, Clone, async_graphql::SimpleObject)]
+        #[graphql(name = #group_by_key_name)]
+        #shareable
+        pub struct GroupByKey {
+            #(#key_fields),*
+        }
+
+        #[derive(Debug, Clone, async_graphql::SimpleObject)]
+        #[graphql(name = #group_by_row_name)]
+        #shareable
+        pub struct GroupByRow {
+            pub key: GroupByKey,
+            pub aggregate: Aggregate,
+        }
+
+        /// 依 `by` 分組，`filters` 作為 WHERE、`having` 作為 HAVING 條件
+        pub async fn group_by<C>(
+            db: &C,
+            by: Vec<QueryColumn>,
+            filters: Option<Filter>,
+            having: Option<AggregateFilter>,
+        ) -> Result<Vec<GroupByRow>, sea_orm::DbErr>
+        where
+            C: sea_orm::ConnectionTrait,
+        {
+            let condition = try_filter_recursive(filters, db.get_database_backend())?;
+
+            group_by_condition(db, by, condition, having).await
+        }
+
+        /// 以任意條件作為 WHERE 分組計算
+        pub async fn group_by_condition<C>(
+            db: &C,
+            by: Vec<QueryColumn>,
+            condition: sea_orm::Condition,
+            having: Option<AggregateFilter>,
+        ) -> Result<Vec<GroupByRow>, sea_orm::DbErr>
+        where
+            C: sea_orm::ConnectionTrait,
+        {
+            use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QuerySelect, QueryTrait};
+
+            let backend = db.get_database_backend();
+
+            let mut stmt = Entity::find()
+                .filter(condition)
+                .select_only();
+
+            for query_column in by.iter() {
+                let column = Column::from(*query_column);
+                let alias = format!("key_{:?}", query_column);
+
+                // select_as 會將 Postgres 枚舉轉為 text，與 Model 讀取方式一致
+                stmt = stmt
+                    .column_as(column.select_as(sea_orm::sea_query::Expr::col((Entity, column))), alias)
+                    .group_by(column);
+            }
+
+            let mut stmt = aggregate_select(stmt, backend);
+
+            if let Some(having) = having {
+                stmt = stmt.having(having.to_condition(backend));
+            }
+
+            db.query_all(stmt.build(backend))
+                .await?
+                .iter()
+                .map(|result| {
+                    Ok(GroupByRow {
+                        key: GroupByKey {
+                            #(#key_values),*
+                        },
+                        aggregate: aggregate_from_result(result)?,
+                    })
+                })
+                .collect()
+        }
+    }
+}
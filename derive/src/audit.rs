@@ -0,0 +1,161 @@
+// Derived from Seaography (github.com/SeaQL/seaography)
+// Modifications Copyright (c) 2025 Stephen J. Li
+
+use heck::ToUpperCamelCase;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+
+use crate::mutate::IdentTypeTuple;
+
+/// `#[graphql_template(created_at)]`、`updated_at` 與 `created_by` 標記的稽核欄位
+pub struct AuditFields {
+    pub created_at: Option<IdentTypeTuple>,
+    pub updated_at: Option<IdentTypeTuple>,
+    pub created_by: Option<IdentTypeTuple>,
+}
+
+impl AuditFields {
+    pub fn iter(&self) -> impl Iterator<Item = &IdentTypeTuple> {
+        self.created_at
+            .iter()
+            .chain(self.updated_at.iter())
+            .chain(self.created_by.iter())
+    }
+}
+
+pub fn audit_fields(fields: &syn::Fields) -> Result<AuditFields, crate::error::Error> {
+    Ok(AuditFields {
+        created_at: crate::guard::flagged_field(fields, "created_at")?,
+        updated_at: crate::guard::flagged_field(fields, "updated_at")?,
+        created_by: crate::guard::flagged_field(fields, "created_by")?,
+    })
+}
+
+fn column_ident(ident: &syn::Ident) -> syn::Ident {
+    format_ident!("{}", ident.to_string().to_upper_camel_case())
+}
+
+/// 稽核欄位的 `EntityAudit` 實作，供 `update_many` 與動態 schema 使用
+pub fn entity_audit_fn(audit: &AuditFields) -> TokenStream {
+    let columns: Vec<syn::Ident> = audit
+        .iter()
+        .map(|(ident, _, _)| column_ident(ident))
+        .collect();
+
+    let set_updated_at = match &audit.updated_at {
+        Some((ident, ty, _)) => {
+            let column = column_ident(ident);
+
+            quote! {
+                fn set_updated_at(stmt: sea_orm::UpdateMany<Entity>) -> sea_orm::UpdateMany<Entity> {
+                    stmt.col_expr(
+                        Column::#column,
+                        sea_orm::sea_query::Expr::value(
+                            <#ty as async_graphql_template::AuditTimestamp>::now(),
+                        ),
+                    )
+                }
+            }
+        }
+        None => quote! {},
+    };
+
+    quote! {
+        impl async_graphql_template::EntityAudit for Entity {
+            fn audit_columns() -> Vec<Column> {
+                vec![#(Column::#columns),*]
+            }
+
+            #set_updated_at
+        }
+    }
+}
+
+/// 由時鐘與 `Context` 中的 `Actor` 設定稽核欄位
+pub fn set_audit_fn(audit: &AuditFields) -> TokenStream {
+    let set = |field: &IdentTypeTuple, value: TokenStream| {
+        let (ident, _, is_option) = field;
+        let value = if *is_option {
+            quote! { Some(#value) }
+        } else {
+            value
+        };
+
+        quote! {
+            self.#ident = sea_orm::ActiveValue::Set(#value);
+        }
+    };
+
+    let now = |field: &IdentTypeTuple| {
+        let (_, ty, _) = field;
+
+        set(
+            field,
+            quote! { <#ty as async_graphql_template::AuditTimestamp>::now() },
+        )
+    };
+
+    let updated_at: Vec<TokenStream> = audit.updated_at.iter().map(now).collect();
+
+    // 類型相同時新增的 created_at 與 updated_at 使用同一個時間
+    let created_at = match (&audit.created_at, &audit.updated_at) {
+        (Some(created_at), Some((updated_ident, updated_ty, updated_is_option)))
+            if created_at.1.to_token_stream().to_string()
+                == updated_ty.to_token_stream().to_string()
+                && created_at.2 == *updated_is_option =>
+        {
+            let set = set(
+                created_at,
+                quote! { <#updated_ty as async_graphql_template::AuditTimestamp>::now() },
+            );
+            let (created_ident, _, _) = created_at;
+
+            quote! {
+                #set
+                self.#updated_ident = self.#created_ident.clone();
+            }
+        }
+        (created_at, _) => {
+            let created_at = created_at.iter().map(now);
+
+            quote! {
+                #(#created_at)*
+                #(#updated_at)*
+            }
+        }
+    };
+
+    let created_by = match &audit.created_by {
+        Some(field) => {
+            let (_, ty, _) = field;
+            let set = set(field, quote! { actor.0.clone() });
+
+            quote! {
+                let actor = ctx.data::<async_graphql_template::Actor<#ty>>()?;
+
+                #set
+            }
+        }
+        None => quote! {
+            let _ = ctx;
+        },
+    };
+
+    quote! {
+        impl ActiveModel {
+            /// 新增前設定 `created_at`、`updated_at` 與 `created_by`
+            pub fn set_created_audit(&mut self, ctx: &async_graphql::Context<'_>) -> async_graphql::Result<()> {
+                #created_by
+
+                #created_at
+
+                Ok(())
+            }
+
+            /// 更新前設定 `updated_at`
+            pub fn set_updated_audit(&mut self) {
+                #(#updated_at)*
+            }
+        }
+    }
+}
@@ -3,6 +3,8 @@ use quote::{format_ident, quote};
 
 pub fn enum_filter_fn(ident: syn::Ident) -> TokenStream {
     let name = format_ident!("{}EnumFilter", ident);
+    let array_name = format_ident!("{}EnumArrayFilter", ident);
+    let array_mutation_name = format_ident!("{}EnumArrayMutation", ident);
 
     quote! {
         #[derive(Debug, Clone, async_graphql::InputObject)]
@@ -17,5 +19,36 @@ pub fn enum_filter_fn(ident: syn::Ident) -> TokenStream {
             pub is_not_in: Option<Vec<#ident>>,
             pub is_null: Option<bool>,
         }
+
+        #[derive(Debug, Clone, async_graphql::InputObject)]
+        pub struct #array_name {
+            pub eq: Option<Vec<#ident>>,
+            pub ne: Option<Vec<#ident>>,
+            pub is_null: Option<bool>,
+            pub contains: Option<Vec<#ident>>,
+            pub contains_any: Option<Vec<#ident>>,
+            pub contained_by: Option<Vec<#ident>>,
+            pub length: Option<async_graphql_template::TypeFilter<i32>>,
+            pub is_empty: Option<bool>,
+        }
+
+        #[derive(Debug, Clone, async_graphql::InputObject)]
+        pub struct #array_mutation_name {
+            pub set: Option<Vec<#ident>>,
+            pub push: Option<Vec<#ident>>,
+            pub remove: Option<Vec<#ident>>,
+            pub add_to_set: Option<Vec<#ident>>,
+        }
+
+        impl From<#array_mutation_name> for async_graphql_template::ArrayMutation<#ident> {
+            fn from(mutation: #array_mutation_name) -> Self {
+                Self {
+                    set: mutation.set,
+                    push: mutation.push,
+                    remove: mutation.remove,
+                    add_to_set: mutation.add_to_set,
+                }
+            }
+        }
     }
 }
@@ -0,0 +1,70 @@
+// Derived from Seaography (github.com/SeaQL/seaography)
+// Modifications Copyright (c) 2025 Stephen J. Li
+
+use heck::{ToSnakeCase, ToUpperCamelCase};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use crate::filter::{IdentTypeTuple, SeaOrm};
+
+/// `Model` 上是否標記了 `#[graphql(shareable)]`
+pub fn is_shareable(attrs: &[syn::Attribute]) -> bool {
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("graphql"))
+        .filter_map(|attr| attr.parse_meta().ok())
+        .any(|meta| match meta {
+            syn::Meta::List(list) => list.nested.iter().any(|nested| {
+                matches!(nested, syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("shareable"))
+            }),
+            _ => false,
+        })
+}
+
+/// 可共享的實體，其連線與聚合等輸出類型同樣需要 `@shareable`
+pub fn shareable_attr(shareable: bool) -> TokenStream {
+    if shareable {
+        quote! {
+            #[graphql(shareable)]
+        }
+    } else {
+        quote! {}
+    }
+}
+
+/// 以主鍵作為 `@key` 的 Apollo Federation 實體解析器
+///
+/// `_entities` 同時解析所有 representation，經由 `by_id` 的 DataLoader 合併為一條 SQL。
+pub fn entity_fn(
+    attrs: &SeaOrm,
+    primary_keys: &[IdentTypeTuple],
+) -> Result<TokenStream, crate::error::Error> {
+    let table_name = match &attrs.table_name {
+        Some(syn::Lit::Str(name)) => name.value(),
+        _ => return Err(crate::error::Error::Internal("Invalid entity name".into())),
+    };
+
+    let object_name = format!("{}EntityQuery", table_name.to_upper_camel_case());
+    let fn_name = format_ident!("find_{}_by_id", table_name.to_snake_case());
+
+    let idents: Vec<&syn::Ident> = primary_keys.iter().map(|(ident, _, _)| ident).collect();
+    let types: Vec<&syn::Type> = primary_keys.iter().map(|(_, ty, _)| ty).collect();
+
+    Ok(quote! {
+        /// 與其他查詢以 `MergedObject` 合併後提供 `_entities` 解析
+        #[derive(Debug, Default)]
+        pub struct EntityQuery;
+
+        #[async_graphql::Object(name = #object_name)]
+        impl EntityQuery {
+            #[graphql(entity)]
+            async fn #fn_name(
+                &self,
+                ctx: &async_graphql::Context<'_>,
+                #(#[graphql(key)] #idents: #types),*
+            ) -> async_graphql::Result<Option<Model>> {
+                by_id(ctx, #(#idents),*).await
+            }
+        }
+    })
+}
@@ -1,19 +1,37 @@
 // Derived from Seaography (github.com/SeaQL/seaography)
 // Modifications Copyright (c) 2025 Stephen J. Li
 
-use heck::{ToSnakeCase, ToUpperCamelCase};
+use heck::{ToLowerCamelCase, ToSnakeCase, ToUpperCamelCase};
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote, ToTokens};
 
 #[derive(Debug, Eq, PartialEq, bae::FromAttributes)]
 pub struct SeaOrm {
-    table_name: Option<syn::Lit>,
+    pub table_name: Option<syn::Lit>,
 }
 
 pub type IdentTypeTuple = (syn::Ident, syn::Type, bool);
 
 // TODO skip ignored fields
-pub fn filter_fn(item: syn::DataStruct, attrs: SeaOrm) -> Result<TokenStream, crate::error::Error> {
+pub fn filter_fn(
+    item: syn::DataStruct,
+    attrs: SeaOrm,
+    shareable: bool,
+) -> Result<TokenStream, crate::error::Error> {
+    let guards = crate::guard::field_guards(&item.fields)?;
+
+    let entity_tenant_fn =
+        crate::tenant::entity_tenant_fn(&crate::tenant::tenant_field(&item.fields)?);
+    let entity_soft_delete_fn = crate::soft_delete::entity_soft_delete_fn(
+        &crate::soft_delete::soft_delete_field(&item.fields)?,
+    );
+    let entity_audit_fn = crate::audit::entity_audit_fn(&crate::audit::audit_fields(&item.fields)?);
+    let entity_version_fn =
+        crate::version::entity_version_fn(&crate::version::version_field(&item.fields)?);
+    let entity_output_fn = crate::output::entity_output_fn(&item.fields)?;
+
+    let lookup_fn = crate::lookup::lookup_fn(&item, &attrs, &guards)?;
+
     let fields: Vec<IdentTypeTuple> = item
         .fields
         .into_iter()
@@ -31,6 +49,12 @@ pub fn filter_fn(item: syn::DataStruct, attrs: SeaOrm) -> Result<TokenStream, cr
 
     let order_by_fn = order_by_fn(&fields)?;
 
+    let aggregate_fn = crate::aggregate::aggregate_fn(&fields, &attrs, shareable, &guards)?;
+
+    let field_guard_fn = crate::guard::field_guard_fn(&guards);
+
+    let projection_fn = crate::projection::projection_fn(&fields)?;
+
     Ok(quote! {
         #filter_struct
 
@@ -39,9 +63,45 @@ pub fn filter_fn(item: syn::DataStruct, attrs: SeaOrm) -> Result<TokenStream, cr
         #order_by_struct
 
         #order_by_fn
+
+        #aggregate_fn
+
+        #projection_fn
+
+        #lookup_fn
+
+        #field_guard_fn
+
+        #entity_tenant_fn
+
+        #entity_soft_delete_fn
+
+        #entity_audit_fn
+
+        #entity_version_fn
+
+        #entity_output_fn
     })
 }
 
+/// 取出 `Vec<T>` 的元素類型 `T`
+pub fn vec_element_type(ty: &syn::Type) -> Option<&syn::Type> {
+    if !is_vec_type(ty) {
+        return None;
+    }
+
+    match ty {
+        syn::Type::Path(type_path) => match &type_path.path.segments.last()?.arguments {
+            syn::PathArguments::AngleBracketed(params) => match params.args.first()? {
+                syn::GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            },
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
 pub fn is_vec_type(ty: &syn::Type) -> bool {
     if let syn::Type::Path(type_path) = ty {
         type_path
@@ -55,6 +115,75 @@ pub fn is_vec_type(ty: &syn::Type) -> bool {
     }
 }
 
+#[cfg(feature = "with-json")]
+pub fn is_json_type(ty: &syn::Type) -> bool {
+    if let syn::Type::Path(type_path) = ty {
+        type_path
+            .path
+            .segments
+            .last()
+            .map(|seg| seg.ident == "Json")
+            .unwrap_or(false)
+    } else {
+        false
+    }
+}
+
+#[cfg(not(feature = "with-json"))]
+pub fn is_json_type(_ty: &syn::Type) -> bool {
+    false
+}
+
+/// 有日期部分、使用 `TemporalFilter` 的類型；`Time` 使用 `TypeFilter`
+#[cfg(feature = "with-chrono")]
+pub fn is_temporal_type(ty: &syn::Type) -> bool {
+    let temporal_types = ["Date", "DateTime", "DateTimeUtc", "DateTimeWithTimeZone"];
+
+    temporal_types.contains(&ty.to_token_stream().to_string().as_str())
+}
+
+#[cfg(not(feature = "with-chrono"))]
+pub fn is_temporal_type(_ty: &syn::Type) -> bool {
+    false
+}
+
+/// 沒有意義順序的類型，使用沒有區間運算的 `BasicFilter`
+pub fn is_unordered_type(ty: &syn::Type) -> bool {
+    ["bool", "BinaryVector"].contains(&ty.to_token_stream().to_string().as_str())
+}
+
+/// 使用 `TypeFilter` 的標量類型，其餘未知類型視為枚舉
+pub fn default_filter_types() -> Vec<&'static str> {
+    vec![
+        "i8",
+        "i16",
+        "i32",
+        "i64",
+        "u8",
+        "u16",
+        "u32",
+        "u64",
+        "f32",
+        "f64",
+        #[cfg(feature = "with-chrono")]
+        "Date",
+        #[cfg(feature = "with-chrono")]
+        "Time",
+        #[cfg(feature = "with-chrono")]
+        "DateTime",
+        #[cfg(feature = "with-chrono")]
+        "DateTimeUtc",
+        #[cfg(feature = "with-chrono")]
+        "DateTimeWithTimeZone",
+        #[cfg(feature = "with-decimal")]
+        "Decimal",
+        #[cfg(feature = "with-uuid")]
+        "Uuid",
+        "BinaryVector",
+        "bool",
+    ]
+}
+
 pub fn filter_struct(
     fields: &[IdentTypeTuple],
     attrs: &SeaOrm,
@@ -64,89 +193,35 @@ pub fn filter_struct(
         .map(|(ident, ty, _)| {
             let type_literal = ty.to_token_stream().to_string();
 
-            let default_filters = vec![
-                "i8",
-                "i16",
-                "i32",
-                "i64",
-                "u8",
-                "u16",
-                "u32",
-                "u64",
-                "f32",
-                "f64",
-                #[cfg(feature = "with-chrono")]
-                "Date",
-                #[cfg(feature = "with-chrono")]
-                "DateTime",
-                #[cfg(feature = "with-chrono")]
-                "DateTimeUtc",
-                #[cfg(feature = "with-chrono")]
-                "DateTimeWithTimeZone",
-                #[cfg(feature = "with-decimal")]
-                "Decimal",
-                #[cfg(feature = "with-json")]
-                "Json",
-                #[cfg(feature = "with-uuid")]
-                "Uuid",
-                "BinaryVector",
-                "bool",
-            ];
-
-            let filter_item = if is_vec_type(ty) {
-                if type_literal.contains("String") {
-                    quote! {
-                        async_graphql_template::StringArrayFilter
-                    }
-                } else if type_literal.contains("i8") {
-                    quote! {
-                        async_graphql_template::TinyIntArrayFilter
-                    }
-                } else if type_literal.contains("i16") {
-                    quote! {
-                        async_graphql_template::SmallIntArrayFilter
-                    }
-                } else if type_literal.contains("i32") {
-                    quote! {
-                        async_graphql_template::IntArrayFilter
-                    }
-                } else if type_literal.contains("i64") {
-                    quote! {
-                        async_graphql_template::BigIntArrayFilter
-                    }
-                } else if type_literal.contains("u8") {
-                    quote! {
-                        async_graphql_template::TinyUnsignedArrayFilter
-                    }
-                } else if type_literal.contains("u16") {
-                    quote! {
-                        async_graphql_template::SmallUnsignedArrayFilter
-                    }
-                } else if type_literal.contains("u32") {
-                    quote! {
-                        async_graphql_template::UnsignedArrayFilter
-                    }
-                } else if type_literal.contains("u64") {
-                    quote! {
-                        async_graphql_template::BigUnsignedArrayFilter
-                    }
-                } else if type_literal.contains("f32") {
-                    quote! {
-                        async_graphql_template::FloatArrayFilter
-                    }
-                } else if type_literal.contains("f64") {
-                    quote! {
-                        async_graphql_template::DoubleArrayFilter
-                    }
-                } else if type_literal.contains("bool") {
+            let default_filters = default_filter_types();
+
+            let filter_item = if let Some(element_ty) = vec_element_type(ty) {
+                let element_literal = element_ty.to_token_stream().to_string();
+
+                if element_literal == "String"
+                    || default_filters.contains(&element_literal.as_str())
+                {
                     quote! {
-                        async_graphql_template::BooleanArrayFilter
+                        async_graphql_template::ArrayFilter<#element_ty>
                     }
                 } else {
+                    let ident = format_ident!("{}EnumArrayFilter", element_literal);
                     quote! {
-                        async_graphql_template::IntArrayFilter
+                        crate::entities::sea_orm_active_enums::#ident
                     }
                 }
+            } else if is_json_type(ty) {
+                quote! {
+                    async_graphql_template::JsonFilter
+                }
+            } else if is_temporal_type(ty) {
+                quote! {
+                    async_graphql_template::TemporalFilter<#ty>
+                }
+            } else if is_unordered_type(ty) {
+                quote! {
+                    async_graphql_template::BasicFilter<#ty>
+                }
             } else if default_filters.contains(&type_literal.as_str()) {
                 quote! {
                     async_graphql_template::TypeFilter<#ty>
@@ -199,10 +274,32 @@ pub fn filter_struct(
     })
 }
 
+/// 列表形式的 `{ field, direction }` 與同名的欄位衝突時不生成
+fn has_item_form(fields: &[IdentTypeTuple]) -> bool {
+    !fields
+        .iter()
+        .any(|(ident, _, _)| ident == "field" || ident == "direction")
+}
+
 pub fn order_by_struct(
     fields: &[IdentTypeTuple],
     attrs: &SeaOrm,
 ) -> Result<TokenStream, crate::error::Error> {
+    let columns: Vec<syn::Ident> = fields
+        .iter()
+        .map(|(ident, _, _)| format_ident!("{}", ident.to_string().to_upper_camel_case()))
+        .collect();
+
+    let item_fields = if has_item_form(fields) {
+        quote! {
+            /// 列表形式的排序欄位，需與 `direction` 一起使用
+            pub field: Option<QueryColumn>,
+            pub direction: Option<async_graphql_template::OrderByEnum>,
+        }
+    } else {
+        quote! {}
+    };
+
     let fields: Vec<TokenStream> = fields
         .iter()
         .map(|(ident, _, _)| {
@@ -218,46 +315,218 @@ pub fn order_by_struct(
     };
 
     let filter_name = format!("{}OrderBy", entity_name.value().to_upper_camel_case());
+    let column_name = format!("{}Column", entity_name.value().to_upper_camel_case());
 
     Ok(quote! {
+        /// 排序條件，`orderBy` 參數接受單一物件或列表
+        ///
+        /// 列表依順序決定優先級，同一物件中的欄位依書寫順序；
+        /// 也可以 `[{ field: TITLE, direction: ASC }, ...]` 的形式逐項指定。
         #[derive(Debug, Clone, async_graphql::InputObject)]
         #[graphql(name = #filter_name)]
         pub struct OrderBy {
-            #(#fields),*
+            #(#fields,)*
+
+            #item_fields
+
+            /// belongs-to 關聯的排序欄位，由關聯宏生成
+            #[graphql(flatten)]
+            pub related: RelatedOrderBy,
+        }
+
+        /// 供排序、分組等參數選擇欄位
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, async_graphql::Enum)]
+        #[graphql(name = #column_name)]
+        pub enum QueryColumn {
+            #(#columns),*
+        }
+
+        pub type OrderByColumn = QueryColumn;
+
+        impl From<QueryColumn> for Column {
+            fn from(column: QueryColumn) -> Self {
+                match column {
+                    #(QueryColumn::#columns => Column::#columns),*
+                }
+            }
+        }
+
+        /// 本身欄位的排序項目
+        #[derive(Debug, Clone, Copy)]
+        pub struct OrderByItem {
+            pub field: QueryColumn,
+            pub direction: async_graphql_template::OrderByEnum,
         }
     })
 }
 
 pub fn order_by_fn(fields: &[IdentTypeTuple]) -> Result<TokenStream, crate::error::Error> {
-    let fields: Vec<TokenStream> = fields
+    let idents: Vec<&syn::Ident> = fields.iter().map(|(ident, _, _)| ident).collect();
+    let names: Vec<String> = idents
         .iter()
-        .map(|(ident, _, _)| {
-            let column = format_ident!("{}", ident.to_string().to_upper_camel_case());
+        .map(|ident| ident.to_string().to_lower_camel_case())
+        .collect();
+    let columns: Vec<syn::Ident> = idents
+        .iter()
+        .map(|ident| format_ident!("{}", ident.to_string().to_upper_camel_case()))
+        .collect();
 
+    let (item_key, item_match) = if has_item_form(fields) {
+        (
+            quote! {
+                if self.field.is_some() || self.direction.is_some() {
+                    keys.push("field".to_owned());
+                }
+            },
             quote! {
-                let stmt = if let Some(order_by) = order_by_struct.#ident {
-                    match order_by {
-                        async_graphql_template::OrderByEnum::Asc => stmt.order_by(Column::#column, sea_orm::query::Order::Asc),
-                        async_graphql_template::OrderByEnum::Desc => stmt.order_by(Column::#column, sea_orm::query::Order::Desc),
+                "field" => match (self.field, self.direction) {
+                    (Some(field), Some(direction)) => Ok(Some(OrderByItem { field, direction })),
+                    (None, None) => Ok(None),
+                    _ => Err(sea_orm::DbErr::Custom(
+                        "orderBy `field` and `direction` must be given together".into(),
+                    )),
+                },
+                // 先寫 direction 時在 field 處理
+                "direction" if self.field.is_some() => Ok(None),
+                "direction" if self.direction.is_some() => Err(sea_orm::DbErr::Custom(
+                    "orderBy `field` and `direction` must be given together".into(),
+                )),
+            },
+        )
+    } else {
+        (quote! {}, quote! {})
+    };
+
+    Ok(quote! {
+        impl OrderBy {
+            /// 依欄位宣告順序列出已設定的欄位，`{ field, direction }` 在最前
+            pub fn declared_keys(&self) -> Vec<String> {
+                let mut keys = Vec::new();
+
+                #item_key
+
+                #(
+                    if self.#idents.is_some() {
+                        keys.push(#names.to_owned());
                     }
+                )*
+
+                keys.extend(self.related.declared_keys());
+                keys
+            }
+
+            /// `key` 為本身欄位時回傳其排序項目
+            fn item(&self, key: &str) -> Result<Option<OrderByItem>, sea_orm::DbErr> {
+                match key {
+                    #item_match
+                    #(
+                        #names => Ok(self.#idents.map(|direction| OrderByItem {
+                            field: QueryColumn::#columns,
+                            direction,
+                        })),
+                    )*
+                    _ => Ok(None),
+                }
+            }
+
+            /// 依 `keys` 的順序列出本身欄位的排序項目，`keys` 為空時依欄位宣告順序；不包含關聯欄位
+            pub fn items(&self, keys: &[String]) -> Result<Vec<OrderByItem>, sea_orm::DbErr> {
+                let declared;
+                let keys = if keys.is_empty() {
+                    declared = self.declared_keys();
+                    &declared
                 } else {
-                    stmt
+                    keys
                 };
+
+                let mut items = Vec::new();
+
+                for key in keys {
+                    items.extend(self.item(key)?);
+                }
+
+                Ok(items)
             }
-        })
-        .collect();
 
-    Ok(quote! {
-        pub fn order_by(stmt: sea_orm::Select<Entity>, order_by_struct: Option<OrderBy>) -> sea_orm::Select<Entity> {
-            use sea_orm::QueryOrder;
+            /// 依 `keys` 的順序將本身與 belongs-to 關聯欄位的排序加入 `stmt`
+            ///
+            /// `alias` 為本實體 JOIN 時的別名，根查詢為 `None`；`joined` 記錄已 JOIN 的別名，避免重複 JOIN。
+            pub fn apply<S>(
+                mut self,
+                stmt: S,
+                keys: &[String],
+                alias: Option<&str>,
+                joined: &mut Vec<String>,
+            ) -> Result<S, sea_orm::DbErr>
+            where
+                S: sea_orm::QuerySelect + sea_orm::QueryOrder,
+            {
+                use sea_orm::sea_query::{Alias, DynIden, Expr, SeaRc};
+
+                let table: DynIden = match alias {
+                    Some(alias) => SeaRc::new(Alias::new(alias)),
+                    None => SeaRc::new(Entity),
+                };
 
-            if let Some(order_by_struct) = order_by_struct {
-                #(#fields)*
-                stmt
-            } else {
-                stmt
+                let keys = if keys.is_empty() {
+                    self.declared_keys()
+                } else {
+                    keys.to_vec()
+                };
+
+                let mut stmt = stmt;
+
+                for key in keys {
+                    stmt = match self.item(&key)? {
+                        Some(item) => item.direction.order_by(
+                            stmt,
+                            Expr::col((table.clone(), Column::from(item.field))),
+                        ),
+                        None => self.related.apply_key(&key, stmt, alias, joined)?,
+                    };
+                }
+
+                Ok(stmt)
             }
         }
+
+        /// 依列表順序排序，同一物件中的欄位依書寫順序；belongs-to 關聯欄位以 LEFT JOIN 排序
+        pub fn order_by(
+            stmt: sea_orm::Select<Entity>,
+            order_by: Option<Vec<async_graphql_template::Ordered<OrderBy>>>,
+        ) -> Result<sea_orm::Select<Entity>, sea_orm::DbErr> {
+            let mut stmt = stmt;
+            let mut joined = Vec::new();
+
+            for order_by in order_by.unwrap_or_default() {
+                stmt = order_by.value.apply(stmt, &order_by.keys, None, &mut joined)?;
+            }
+
+            Ok(stmt)
+        }
+
+        /// 每組 `columns` 只保留依 `order_by` 排序後的第一筆，見 `async_graphql_template::distinct_on`
+        pub fn distinct_on(
+            stmt: sea_orm::Select<Entity>,
+            columns: Vec<QueryColumn>,
+            condition: sea_orm::Condition,
+            order_by: &[OrderByItem],
+            backend: sea_orm::DbBackend,
+        ) -> Result<sea_orm::Select<Entity>, sea_orm::DbErr> {
+            let order_by: Vec<(Column, async_graphql_template::OrderByEnum)> = order_by
+                .iter()
+                .map(|item| (Column::from(item.field), item.direction))
+                .collect();
+
+            async_graphql_template::distinct_on(
+                stmt,
+                columns.into_iter().map(Column::from).collect(),
+                condition,
+                &order_by,
+                backend,
+            )
+        }
+
     })
 }
 
@@ -271,6 +540,10 @@ pub fn recursive_filter_fn(fields: &[IdentTypeTuple]) -> Result<TokenStream, cra
 
             let mut is_string = false;
             let is_vec = is_vec_type(ident_type);
+            let is_json = is_json_type(ident_type);
+            let is_temporal = is_temporal_type(ident_type);
+            let is_type_filter = default_filter_types()
+                .contains(&ident_type.to_token_stream().to_string().as_str());
 
             if let syn::Type::Path(syn::TypePath{ qself: _, path}) = ident_type {
                 let syn::Path{ leading_colon: _, segments } = path;
@@ -282,6 +555,20 @@ pub fn recursive_filter_fn(fields: &[IdentTypeTuple]) -> Result<TokenStream, cra
 
             let mut string_filter = TokenStream::new();
             let mut array_filter = TokenStream::new();
+            let mut range_filter = TokenStream::new();
+
+            // 枚舉、布林與二進位過濾器沒有區間運算
+            if (is_string || is_type_filter) && !is_unordered_type(ident_type) {
+                range_filter = quote!{
+                    if let Some(between_value) = #column_name.between {
+                        condition = condition.add(Column::#column_enum_name.between(between_value.from, between_value.to))
+                    }
+
+                    if let Some(not_between_value) = #column_name.not_between {
+                        condition = condition.add(Column::#column_enum_name.not_between(not_between_value.from, not_between_value.to))
+                    }
+                };
+            }
 
             if is_string {
                 string_filter = quote!{
@@ -292,39 +579,77 @@ pub fn recursive_filter_fn(fields: &[IdentTypeTuple]) -> Result<TokenStream, cra
             }
 
             if is_vec {
+                let column_literal = column_name.to_string();
+
                 array_filter = quote!{
-                    if let Some(contains) = &#column_name.contains {
-                        // 使用 sea_orm 提供的 contains 函數進行數組包含檢查
-                        let mut contains_condition = sea_orm::Condition::all();
-                        for item in contains.iter() {
-                            contains_condition = contains_condition.add(
-                                sea_orm::sea_query::extension::postgres::PgExpr::contains(
-                                    sea_orm::sea_query::Expr::col(Column::#column_enum_name),
-                                    item.clone()
-                                )
-                            );
-                        }
-                        condition = condition.add(contains_condition);
+                    // 數組運算子只有 Postgres 支援，其他後端直接回傳錯誤而非產生無效 SQL
+                    if backend != sea_orm::DbBackend::Postgres {
+                        return Err(sea_orm::DbErr::Custom(format!(
+                            "array filter on column `{}` is only supported on Postgres, got {:?}",
+                            #column_literal,
+                            backend,
+                        )));
+                    }
+
+                    let array_column = sea_orm::sea_query::Expr::col((Entity, Column::#column_enum_name));
+
+                    // 透過 save_as 將枚舉數組轉型為對應的資料庫枚舉數組類型
+                    if let Some(contains) = #column_name.contains {
+                        condition = condition.add(array_column.clone().contains(
+                            Column::#column_enum_name.save_as(sea_orm::sea_query::Expr::val(contains))
+                        ))
                     }
 
-                    if let Some(contains_any) = &#column_name.contains_any {
-                        // 使用 OR 條件構建 ANY 語義
-                        let mut any_condition = sea_orm::Condition::any();
-                        for item in contains_any.iter() {
-                            any_condition = any_condition.add(
-                                sea_orm::sea_query::extension::postgres::PgExpr::contains(
-                                    sea_orm::sea_query::Expr::col(Column::#column_enum_name),
-                                    item.clone()
-                                )
-                            );
+                    if let Some(contains_any) = #column_name.contains_any {
+                        condition = condition.add(array_column.clone().binary(
+                            sea_orm::sea_query::extension::postgres::PgBinOper::Overlap,
+                            Column::#column_enum_name.save_as(sea_orm::sea_query::Expr::val(contains_any)),
+                        ))
+                    }
+
+                    if let Some(contained_by) = #column_name.contained_by {
+                        condition = condition.add(array_column.clone().contained(
+                            Column::#column_enum_name.save_as(sea_orm::sea_query::Expr::val(contained_by))
+                        ))
+                    }
+
+                    // cardinality 對空數組回傳 0，array_length 則回傳 NULL
+                    let array_length: sea_orm::sea_query::SimpleExpr = sea_orm::sea_query::Func::cust(
+                        sea_orm::sea_query::Alias::new("cardinality")
+                    )
+                    .arg(array_column.clone())
+                    .into();
+
+                    if let Some(length) = #column_name.length {
+                        condition = condition.add(length.to_condition(array_length.clone()))
+                    }
+
+                    if let Some(is_empty_value) = #column_name.is_empty {
+                        if is_empty_value {
+                            condition = condition.add(sea_orm::sea_query::Expr::expr(array_length).eq(0))
+                        } else {
+                            condition = condition.add(sea_orm::sea_query::Expr::expr(array_length).gt(0))
                         }
-                        condition = condition.add(any_condition);
                     }
                 };
             }
 
             // 根據類型不同選擇不同的過濾邏輯
-            if is_vec {
+            if is_json {
+                // JSON 類型依資料庫後端轉換為對應的 JSON 運算子
+                quote!{
+                    if let Some(#column_name) = current_filter.#column_name {
+                        condition = condition.add(#column_name.to_condition(Column::#column_enum_name, backend)?);
+                    }
+                }
+            } else if is_temporal {
+                // 日期時間類型的日期部分運算依資料庫後端而不同
+                quote!{
+                    if let Some(#column_name) = current_filter.#column_name {
+                        condition = condition.add(#column_name.to_condition(Column::#column_enum_name, backend));
+                    }
+                }
+            } else if is_vec {
                 // 數組類型僅使用數組專用過濾條件
                 quote!{
                     if let Some(#column_name) = current_filter.#column_name {
@@ -384,6 +709,8 @@ pub fn recursive_filter_fn(fields: &[IdentTypeTuple]) -> Result<TokenStream, cra
                             condition = condition.add(Column::#column_enum_name.is_not_in(is_not_in_value))
                         }
 
+                        #range_filter
+
                         if let Some(is_null_value) = #column_name.is_null {
                             if is_null_value {
                                 condition = condition.add(Column::#column_enum_name.is_null())
@@ -427,6 +754,8 @@ pub fn recursive_filter_fn(fields: &[IdentTypeTuple]) -> Result<TokenStream, cra
                             condition = condition.add(Column::#column_enum_name.is_not_in(is_not_in_value))
                         }
 
+                        #range_filter
+
                         if let Some(is_null_value) = #column_name.is_null {
                             if is_null_value {
                                 condition = condition.add(Column::#column_enum_name.is_null())
@@ -439,7 +768,50 @@ pub fn recursive_filter_fn(fields: &[IdentTypeTuple]) -> Result<TokenStream, cra
         .collect();
 
     Ok(quote! {
+        /// 檢查欄位守衛並加上租戶與 `EntityGuard` 的限制且排除已軟刪除的資料，
+        /// 根查詢、聚合與批次修改以此作為 WHERE 條件
+        pub fn try_filter_guarded(
+            ctx: &async_graphql::Context<'_>,
+            root_filter: Option<Filter>,
+            backend: sea_orm::DbBackend,
+        ) -> async_graphql::Result<sea_orm::Condition> {
+            try_filter_with_deleted(ctx, root_filter, None, backend)
+        }
+
+        /// 與 `try_filter_guarded` 相同，`with_deleted` 為 `Some(true)` 時包含已軟刪除的資料
+        pub fn try_filter_with_deleted(
+            ctx: &async_graphql::Context<'_>,
+            root_filter: Option<Filter>,
+            with_deleted: Option<bool>,
+            backend: sea_orm::DbBackend,
+        ) -> async_graphql::Result<sea_orm::Condition> {
+            if let Some(filter) = &root_filter {
+                filter.check_guards(ctx)?;
+            }
+
+            Ok(try_filter_recursive(root_filter, backend)?
+                .add(async_graphql_template::row_condition::<Entity>(ctx)?)
+                .add(async_graphql_template::soft_delete_condition::<Entity>(with_deleted)))
+        }
+
+        /// 以 Postgres 的語法將過濾輸入轉換為條件，保留舊的簽名供既有的呼叫端編譯
+        ///
+        /// JSON、日期部分與數組運算子的 SQL 依後端而不同，其他後端的條件可能無效；
+        /// 請改用 `try_filter_recursive` 並傳入連線的後端。
+        #[deprecated(note = "use `try_filter_recursive(root_filter, db.get_database_backend())`")]
         pub fn filter_recursive(root_filter: Option<Filter>) -> sea_orm::Condition {
+            try_filter_recursive(root_filter, sea_orm::DbBackend::Postgres)
+                .expect("every filter operator is supported on Postgres")
+        }
+
+        /// 依 `backend` 將過濾輸入轉換為條件，後端不支援的運算子回傳錯誤
+        ///
+        /// 只轉換過濾輸入本身，不檢查欄位守衛，也不加上租戶、`EntityGuard` 與軟刪除的條件；
+        /// 處理客戶端的請求時應使用 `try_filter_guarded`。
+        pub fn try_filter_recursive(
+            root_filter: Option<Filter>,
+            backend: sea_orm::DbBackend,
+        ) -> Result<sea_orm::Condition, sea_orm::DbErr> {
             use sea_orm::sea_query::extension::postgres::PgExpr;
             let mut condition = sea_orm::Condition::all();
 
@@ -447,27 +819,31 @@ pub fn recursive_filter_fn(fields: &[IdentTypeTuple]) -> Result<TokenStream, cra
                 if let Some(or_filters) = current_filter.or {
                     let or_condition = or_filters
                         .into_iter()
-                        .fold(
+                        .try_fold(
                             sea_orm::Condition::any(),
-                            |fold_condition, filter| fold_condition.add(filter_recursive(Some(*filter)))
-                        );
+                            |fold_condition, filter| Ok::<_, sea_orm::DbErr>(
+                                fold_condition.add(try_filter_recursive(Some(*filter), backend)?)
+                            )
+                        )?;
                     condition = condition.add(or_condition);
                 }
 
                 if let Some(and_filters) = current_filter.and {
                     let and_condition = and_filters
                         .into_iter()
-                        .fold(
+                        .try_fold(
                             sea_orm::Condition::all(),
-                            |fold_condition, filter| fold_condition.add(filter_recursive(Some(*filter)))
-                        );
+                            |fold_condition, filter| Ok::<_, sea_orm::DbErr>(
+                                fold_condition.add(try_filter_recursive(Some(*filter), backend)?)
+                            )
+                        )?;
                     condition = condition.add(and_condition);
                 }
 
                 #(#columns_filters)*
             }
 
-            condition
+            Ok(condition)
         }
     })
 }
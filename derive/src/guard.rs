@@ -0,0 +1,251 @@
+// Derived from Seaography (github.com/SeaQL/seaography)
+// Modifications Copyright (c) 2025 Stephen J. Li
+
+use heck::ToUpperCamelCase;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+
+use crate::mutate::{remove_optional_from_type_and_get_is_option, IdentTypeTuple};
+
+/// 是否有 `#[graphql_template(flag)]`
+pub fn has_flag(attrs: &[syn::Attribute], flag: &str) -> bool {
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("graphql_template"))
+        .filter_map(|attr| attr.parse_meta().ok())
+        .any(|meta| match meta {
+            syn::Meta::List(list) => list.nested.iter().any(|nested| {
+                matches!(nested, syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident(flag))
+            }),
+            _ => false,
+        })
+}
+
+/// 以 `#[graphql_template(flag)]` 標記的欄位與其去掉 `Option` 後的類型，每個實體最多一個
+pub fn flagged_field(
+    fields: &syn::Fields,
+    flag: &str,
+) -> Result<Option<IdentTypeTuple>, crate::error::Error> {
+    let mut flagged = fields.iter().filter(|field| has_flag(&field.attrs, flag));
+
+    let field = match flagged.next() {
+        Some(field) => field,
+        None => return Ok(None),
+    };
+
+    if flagged.next().is_some() {
+        return Err(crate::error::Error::Internal(format!(
+            "only one field can be marked #[graphql_template({})]",
+            flag
+        )));
+    }
+
+    let (ty, is_option) = remove_optional_from_type_and_get_is_option(field.ty.clone())?;
+
+    Ok(Some((field.ident.clone().unwrap(), ty, is_option)))
+}
+
+/// `Model` 上是否有 `#[graphql_template(guard)]`，有則由使用者自行實作 `EntityGuard`
+fn has_custom_guard(attrs: &[syn::Attribute]) -> bool {
+    has_flag(attrs, "guard")
+}
+
+/// 未自訂守衛的實體使用不限制的預設實作
+pub fn entity_guard_fn(attrs: &[syn::Attribute]) -> TokenStream {
+    if has_custom_guard(attrs) {
+        quote! {}
+    } else {
+        quote! {
+            impl async_graphql_template::EntityGuard for Entity {}
+        }
+    }
+}
+
+/// 欄位與其 `#[graphql_template(guard = "path::to::fn")]` 守衛函數
+pub type FieldGuard = (syn::Ident, syn::Path);
+
+/// `#[name(guard = "...")]` 中的守衛
+fn guard_value(field: &syn::Field, name: &str) -> Result<Option<syn::LitStr>, crate::error::Error> {
+    for attr in field.attrs.iter().filter(|attr| attr.path.is_ident(name)) {
+        if let syn::Meta::List(list) = attr.parse_meta()? {
+            for nested in list.nested.iter() {
+                if let syn::NestedMeta::Meta(syn::Meta::NameValue(value)) = nested {
+                    if let (true, syn::Lit::Str(lit)) = (value.path.is_ident("guard"), &value.lit) {
+                        return Ok(Some(lit.clone()));
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+/// 讀取欄位的守衛函數，函數簽名為 `fn(&async_graphql::Context<'_>) -> async_graphql::Result<()>`
+///
+/// derive 無法修改 `SimpleObject` 的輸出，受保護的欄位需同時以 `#[graphql(guard = "...")]`
+/// 保護輸出欄位，否則拒絕編譯，避免只保護了過濾與排序。
+pub fn field_guards(fields: &syn::Fields) -> Result<Vec<FieldGuard>, crate::error::Error> {
+    let mut guards = Vec::new();
+
+    for field in fields.iter() {
+        let guard = match guard_value(field, "graphql_template")? {
+            Some(guard) => guard,
+            None => continue,
+        };
+
+        let ident = field.ident.clone().unwrap();
+
+        if guard_value(field, "graphql")?.map(|output| output.value()) != Some(guard.value()) {
+            return Err(crate::error::Error::Internal(format!(
+                "field `{}` also needs #[graphql(guard = \"{}\")] to guard the output field",
+                ident,
+                guard.value()
+            )));
+        }
+
+        guards.push((ident, guard.parse()?));
+    }
+
+    Ok(guards)
+}
+
+pub fn find_guard<'a>(guards: &'a [FieldGuard], ident: &syn::Ident) -> Option<&'a syn::Path> {
+    guards
+        .iter()
+        .find(|(guarded, _)| guarded == ident)
+        .map(|(_, path)| path)
+}
+
+/// 在輸出欄位上加上與 `Model` 相同的 `#[graphql(guard = "...")]`
+pub fn output_guard_attr(guards: &[FieldGuard], ident: &syn::Ident) -> TokenStream {
+    match find_guard(guards, ident) {
+        Some(path) => {
+            let path = path.to_token_stream().to_string().replace(' ', "");
+
+            quote! {
+                #[graphql(guard = #path)]
+            }
+        }
+        None => quote! {},
+    }
+}
+
+/// 產生 `QueryColumn`、`Filter` 與 `OrderBy` 的守衛檢查，以及先檢查再排序、去重的函數
+pub fn field_guard_fn(guards: &[FieldGuard]) -> TokenStream {
+    let idents: Vec<&syn::Ident> = guards.iter().map(|(ident, _)| ident).collect();
+    let paths: Vec<&syn::Path> = guards.iter().map(|(_, path)| path).collect();
+    let columns: Vec<syn::Ident> = idents
+        .iter()
+        .map(|ident| format_ident!("{}", ident.to_string().to_upper_camel_case()))
+        .collect();
+
+    let unused_ctx = if guards.is_empty() {
+        quote! { let _ = ctx; }
+    } else {
+        quote! {}
+    };
+
+    quote! {
+        impl QueryColumn {
+            /// 以受保護的欄位排序或分組前需先通過其守衛
+            pub fn check_guard(self, ctx: &async_graphql::Context<'_>) -> async_graphql::Result<()> {
+                #unused_ctx
+
+                #(
+                    if self == QueryColumn::#columns {
+                        #paths(ctx)?;
+                    }
+                )*
+
+                Ok(())
+            }
+        }
+
+        impl Filter {
+            /// 過濾條件（含 `and`、`or` 中的條件）使用受保護的欄位時需先通過其守衛
+            pub fn check_guards(&self, ctx: &async_graphql::Context<'_>) -> async_graphql::Result<()> {
+                for filter in self.or.iter().chain(self.and.iter()).flatten() {
+                    filter.check_guards(ctx)?;
+                }
+
+                #(
+                    if self.#idents.is_some() {
+                        #paths(ctx)?;
+                    }
+                )*
+
+                Ok(())
+            }
+        }
+
+        impl OrderBy {
+            /// 排序使用受保護的欄位（含 `field` 與 belongs-to 關聯的欄位）時需先通過其守衛
+            pub fn check_guards(&self, ctx: &async_graphql::Context<'_>) -> async_graphql::Result<()> {
+                for item in self.items(&[])? {
+                    item.field.check_guard(ctx)?;
+                }
+
+                self.related.check_guards(ctx)
+            }
+        }
+
+        /// 檢查欄位守衛後排序
+        pub fn order_by_guarded(
+            ctx: &async_graphql::Context<'_>,
+            stmt: sea_orm::Select<Entity>,
+            order_by_list: Option<Vec<async_graphql_template::Ordered<OrderBy>>>,
+        ) -> async_graphql::Result<sea_orm::Select<Entity>> {
+            for order_by in order_by_list.iter().flatten() {
+                order_by.value.check_guards(ctx)?;
+            }
+
+            Ok(order_by(stmt, order_by_list)?)
+        }
+
+        /// 檢查 `columns` 的欄位守衛後套用 `distinct_on`
+        ///
+        /// 排序項目取自 `order_by_list` 中本身的欄位。belongs-to 關聯欄位不能排在 `columns`
+        /// 對應的排序項目之前，之後的關聯欄位不參與排名；`order_by_list` 本身的守衛由
+        /// `order_by_guarded` 檢查。
+        pub fn distinct_on_guarded(
+            ctx: &async_graphql::Context<'_>,
+            stmt: sea_orm::Select<Entity>,
+            columns: Option<Vec<QueryColumn>>,
+            condition: sea_orm::Condition,
+            order_by_list: Option<&[async_graphql_template::Ordered<OrderBy>]>,
+            backend: sea_orm::DbBackend,
+        ) -> async_graphql::Result<sea_orm::Select<Entity>> {
+            let columns = columns.unwrap_or_default();
+
+            for column in columns.iter() {
+                column.check_guard(ctx)?;
+            }
+
+            let mut items = Vec::new();
+
+            for order_by in order_by_list.into_iter().flatten() {
+                let keys = if order_by.keys.is_empty() {
+                    order_by.value.declared_keys()
+                } else {
+                    order_by.keys.clone()
+                };
+                let related = order_by.value.related.declared_keys();
+
+                for key in keys {
+                    if related.contains(&key) && items.len() < columns.len() {
+                        return Err(sea_orm::DbErr::Custom(format!(
+                            "distinctOn columns must match the leading orderBy columns, got related `{}`",
+                            key,
+                        ))
+                        .into());
+                    }
+
+                    items.extend(order_by.value.items(std::slice::from_ref(&key))?);
+                }
+            }
+
+            Ok(distinct_on(stmt, columns, condition, &items, backend)?)
+        }
+    }
+}
@@ -4,13 +4,23 @@
 use quote::ToTokens;
 use syn::DeriveInput;
 
+mod aggregate;
+mod audit;
 mod enumeration;
 mod error;
+mod federation;
 mod filter;
+mod guard;
+mod lookup;
 mod mutate;
+mod output;
+mod projection;
 mod relation;
+mod soft_delete;
+mod tenant;
+mod version;
 
-#[proc_macro_derive(Filter, attributes(sea_orm))]
+#[proc_macro_derive(Filter, attributes(sea_orm, graphql_template))]
 pub fn derive_filter_fn(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let DeriveInput {
         ident, data, attrs, ..
@@ -20,7 +30,7 @@ pub fn derive_filter_fn(input: proc_macro::TokenStream) -> proc_macro::TokenStre
         syn::Data::Struct(item) => item,
         _ => {
             return quote::quote! {
-                compile_error!("Input not structure")
+                compile_error!("Input not structure");
             }
             .into()
         }
@@ -28,19 +38,28 @@ pub fn derive_filter_fn(input: proc_macro::TokenStream) -> proc_macro::TokenStre
 
     if ident.ne("Model") {
         return quote::quote! {
-            compile_error!("Struct must be SeaOrm Model structure")
+            compile_error!("Struct must be SeaOrm Model structure");
         }
         .into();
     }
 
+    let shareable = federation::is_shareable(&attrs);
+    let entity_guard = guard::entity_guard_fn(&attrs);
     let attrs = filter::SeaOrm::from_attributes(&attrs).unwrap();
 
-    filter::filter_fn(item, attrs)
+    filter::filter_fn(item, attrs, shareable)
+        .map(|tokens| {
+            quote::quote! {
+                #tokens
+
+                #entity_guard
+            }
+        })
         .unwrap_or_else(|err| {
             let error = format!("{:?}", err);
 
             quote::quote! {
-                compile_error!(#error)
+                compile_error!(#error);
             }
         })
         .into()
@@ -58,7 +77,7 @@ pub fn derive_enum_filter_fn(input: proc_macro::TokenStream) -> proc_macro::Toke
     enumeration::enum_filter_fn(ident).into()
 }
 
-#[proc_macro_derive(Mutant, attributes(sea_orm))]
+#[proc_macro_derive(Mutant, attributes(sea_orm, graphql_template))]
 pub fn derive_mutate_fn(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let DeriveInput {
         ident, data, attrs, ..
@@ -68,7 +87,7 @@ pub fn derive_mutate_fn(input: proc_macro::TokenStream) -> proc_macro::TokenStre
         syn::Data::Struct(item) => item,
         _ => {
             return quote::quote! {
-                compile_error!("Input not structure")
+                compile_error!("Input not structure");
             }
             .into()
         }
@@ -76,7 +95,7 @@ pub fn derive_mutate_fn(input: proc_macro::TokenStream) -> proc_macro::TokenStre
 
     if ident.ne("Model") {
         return quote::quote! {
-            compile_error!("Struct must be SeaOrm Model structure")
+            compile_error!("Struct must be SeaOrm Model structure");
         }
         .into();
     }
@@ -88,7 +107,7 @@ pub fn derive_mutate_fn(input: proc_macro::TokenStream) -> proc_macro::TokenStre
             let error = format!("{:?}", err);
 
             quote::quote! {
-                compile_error!(#error)
+                compile_error!(#error);
             }
         })
         .into()
@@ -106,7 +125,7 @@ pub fn derive_relations_compact_fn(input: proc_macro::TokenStream) -> proc_macro
 
     if ident.ne("Relation") {
         return quote::quote! {
-            compile_error!("Struct must be SeaOrm Relation enumeration")
+            compile_error!("Struct must be SeaOrm Relation enumeration");
         }
         .into();
     }
@@ -115,7 +134,7 @@ pub fn derive_relations_compact_fn(input: proc_macro::TokenStream) -> proc_macro
         let error = format!("{:?}", err);
 
         quote::quote! {
-            compile_error!(#error)
+            compile_error!(#error);
         }
     });
 
@@ -135,7 +154,7 @@ pub fn relation(
         .starts_with("impl RelationTrait")
     {
         return quote::quote! {
-            compile_error!("Macro should be applied on the implementation of RelationTrait trait")
+            compile_error!("Macro should be applied on the implementation of RelationTrait trait");
         }
         .into();
     }
@@ -143,7 +162,7 @@ pub fn relation(
     let item = match implementation {
         syn::Item::Impl(implementation) => implementation,
         _ => return quote::quote! {
-            compile_error!("Macro should be applied on the implementation of RelationTrait trait")
+            compile_error!("Macro should be applied on the implementation of RelationTrait trait");
         }
         .into(),
     };
@@ -152,7 +171,7 @@ pub fn relation(
         let error = format!("{:?}", err);
 
         quote::quote! {
-            compile_error!(#error)
+            compile_error!(#error);
         }
     });
 
@@ -0,0 +1,187 @@
+// Derived from Seaography (github.com/SeaQL/seaography)
+// Modifications Copyright (c) 2025 Stephen J. Li
+
+use heck::{ToSnakeCase, ToUpperCamelCase};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+
+use crate::filter::{remove_optional_from_type_and_get_is_option, IdentTypeTuple, SeaOrm};
+use crate::guard::{find_guard, FieldGuard};
+
+/// 欄位上的 `#[sea_orm(...)]` 是否含有 `flag`，如 `primary_key`、`unique`
+fn has_sea_orm_flag(field: &syn::Field, flag: &str) -> bool {
+    field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("sea_orm"))
+        .filter_map(|attr| attr.parse_meta().ok())
+        .any(|meta| match meta {
+            syn::Meta::List(list) => list.nested.iter().any(|nested| {
+                matches!(nested, syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident(flag))
+            }),
+            _ => false,
+        })
+}
+
+/// 浮點數沒有實作 `Eq` 與 `Hash`，無法作為 DataLoader 的鍵
+fn is_hashable_type(ty: &syn::Type) -> bool {
+    !["f32", "f64"].contains(&ty.to_token_stream().to_string().as_str())
+}
+
+pub fn lookup_fn(
+    item: &syn::DataStruct,
+    attrs: &SeaOrm,
+    guards: &[FieldGuard],
+) -> Result<TokenStream, crate::error::Error> {
+    let mut primary_keys: Vec<IdentTypeTuple> = Vec::new();
+    let mut unique_keys: Vec<IdentTypeTuple> = Vec::new();
+
+    for field in item.fields.iter() {
+        let (ty, is_option) = remove_optional_from_type_and_get_is_option(field.ty.clone())?;
+        let ident = field.ident.clone().unwrap();
+
+        if has_sea_orm_flag(field, "primary_key") {
+            primary_keys.push((ident, ty, is_option));
+        } else if has_sea_orm_flag(field, "unique") && is_hashable_type(&ty) {
+            unique_keys.push((ident, ty, is_option));
+        }
+    }
+
+    let mut lookups: Vec<TokenStream> = Vec::new();
+
+    if !primary_keys.is_empty() && primary_keys.iter().all(|(_, ty, _)| is_hashable_type(ty)) {
+        lookups.push(lookup_by(
+            format_ident!("ByIdKey"),
+            format_ident!("by_id"),
+            &primary_keys,
+            guards,
+        ));
+        lookups.push(crate::federation::entity_fn(attrs, &primary_keys)?);
+    }
+
+    for key in unique_keys.iter() {
+        let name = key.0.to_string();
+
+        lookups.push(lookup_by(
+            format_ident!("By{}Key", name.to_upper_camel_case()),
+            format_ident!("by_{}", name.to_snake_case()),
+            std::slice::from_ref(key),
+            guards,
+        ));
+    }
+
+    Ok(quote! {
+        #(#lookups)*
+    })
+}
+
+/// 產生以 `fields` 查詢單筆資料的 DataLoader 鍵、Loader 實作與查詢函數，鍵的最後一項為守衛條件
+fn lookup_by(
+    key_name: syn::Ident,
+    fn_name: syn::Ident,
+    fields: &[IdentTypeTuple],
+    guards: &[FieldGuard],
+) -> TokenStream {
+    let idents: Vec<&syn::Ident> = fields.iter().map(|(ident, _, _)| ident).collect();
+    let types: Vec<&syn::Type> = fields.iter().map(|(_, ty, _)| ty).collect();
+    let columns: Vec<syn::Ident> = idents
+        .iter()
+        .map(|ident| format_ident!("{}", ident.to_string().to_upper_camel_case()))
+        .collect();
+    let indexes: Vec<syn::Index> = (0..fields.len()).map(syn::Index::from).collect();
+    let guard_index = syn::Index::from(fields.len());
+
+    // 以受保護的欄位查詢等同以其過濾，需先通過守衛
+    let field_guards: Vec<&syn::Path> = idents
+        .iter()
+        .filter_map(|ident| find_guard(guards, ident))
+        .collect();
+
+    let condition = if fields.len() == 1 {
+        let column = &columns[0];
+
+        quote! {
+            sea_orm::Condition::all().add(Column::#column.is_in(keys.iter().map(|key| key.0.clone())))
+        }
+    } else {
+        quote! {
+            keys.iter().fold(sea_orm::Condition::any(), |condition, key| {
+                condition.add(
+                    sea_orm::Condition::all()
+                        #(.add(Column::#columns.eq(key.#indexes.clone())))*
+                )
+            })
+        }
+    };
+
+    // 可為空的唯一欄位中，值為 NULL 的資料不會被任何鍵查到
+    let model_key = if fields.iter().any(|(_, _, is_option)| *is_option) {
+        let values: Vec<TokenStream> = fields
+            .iter()
+            .map(|(ident, _, is_option)| {
+                if *is_option {
+                    quote! { model.#ident.clone()? }
+                } else {
+                    quote! { model.#ident.clone() }
+                }
+            })
+            .collect();
+
+        quote! {
+            .filter_map(|model| Some((#key_name(#(#values,)* guard.clone()), model)))
+        }
+    } else {
+        quote! {
+            .map(|model| (#key_name(#(model.#idents.clone(),)* guard.clone()), model))
+        }
+    };
+
+    quote! {
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub struct #key_name(#(pub #types,)* pub async_graphql_template::GuardCondition);
+
+        #[async_trait::async_trait]
+        impl async_graphql::dataloader::Loader<#key_name> for crate::OrmDataloader {
+            type Value = Model;
+            type Error = std::sync::Arc<sea_orm::error::DbErr>;
+
+            async fn load(
+                &self,
+                keys: &[#key_name],
+            ) -> Result<std::collections::HashMap<#key_name, Self::Value>, Self::Error> {
+                use sea_orm::{ColumnTrait, EntityTrait, QueryFilter};
+
+                let mut result = std::collections::HashMap::new();
+
+                // 不同呼叫者的守衛條件不同，分別查詢
+                for (guard, keys) in async_graphql_template::group_by_guard(keys, |key| &key.#guard_index) {
+                    let data = Entity::find()
+                        .filter(#condition)
+                        .filter(guard.0.clone())
+                        .all(&self.db)
+                        .await?;
+
+                    result.extend(data.into_iter() #model_key);
+                }
+
+                Ok(result)
+            }
+        }
+
+        /// 查詢單筆資料，同一請求中的多次查詢經由 DataLoader 合併為一條 SQL
+        pub async fn #fn_name(
+            ctx: &async_graphql::Context<'_>,
+            #(#idents: #types),*
+        ) -> async_graphql::Result<Option<Model>> {
+            #(#field_guards(ctx)?;)*
+
+            let data_loader = ctx.data::<async_graphql::dataloader::DataLoader<crate::OrmDataloader>>()?;
+            let guard = async_graphql_template::GuardCondition(
+                async_graphql_template::row_condition::<Entity>(ctx)?
+                    .add(async_graphql_template::soft_delete_condition::<Entity>(None)),
+            );
+
+            Ok(data_loader.load_one(#key_name(#(#idents,)* guard)).await?)
+        }
+    }
+}
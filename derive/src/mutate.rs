@@ -1,10 +1,12 @@
 // Derived from Seaography (github.com/SeaQL/seaography)
 // Modifications Copyright (c) 2025 Stephen J. Li
 
-use heck::{ToSnakeCase, ToUpperCamelCase};
+use heck::{ToLowerCamelCase, ToSnakeCase, ToUpperCamelCase};
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote, ToTokens};
 
+use crate::guard::{find_guard, FieldGuard};
+
 // 檢查類型是否是 Vec 類型
 pub fn is_vec_type(ty: &syn::Type) -> bool {
     if let syn::Type::Path(type_path) = ty {
@@ -19,6 +21,76 @@ pub fn is_vec_type(ty: &syn::Type) -> bool {
     }
 }
 
+// 取出 Vec<T> 的元素類型 T
+pub fn vec_element_type(ty: &syn::Type) -> Option<&syn::Type> {
+    if !is_vec_type(ty) {
+        return None;
+    }
+
+    match ty {
+        syn::Type::Path(type_path) => match &type_path.path.segments.last()?.arguments {
+            syn::PathArguments::AngleBracketed(params) => match params.args.first()? {
+                syn::GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            },
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+// Vec 類型使用 ArrayMutation，枚舉數組則使用 EnumFilter 生成的結構
+pub fn array_mutation_type(element_ty: &syn::Type) -> TokenStream {
+    let element_literal = element_ty.to_token_stream().to_string();
+
+    let default_elements = vec![
+        "bool",
+        "f32",
+        "f64",
+        "i8",
+        "i16",
+        "i32",
+        "i64",
+        "u8",
+        "u16",
+        "u32",
+        "u64",
+        "String",
+        #[cfg(feature = "with-chrono")]
+        "Date",
+        #[cfg(feature = "with-chrono")]
+        "DateTime",
+        #[cfg(feature = "with-chrono")]
+        "DateTimeUtc",
+        #[cfg(feature = "with-chrono")]
+        "DateTimeWithTimeZone",
+        #[cfg(feature = "with-decimal")]
+        "Decimal",
+        #[cfg(feature = "with-uuid")]
+        "Uuid",
+    ];
+
+    if default_elements.contains(&element_literal.as_str()) {
+        quote! {
+            async_graphql_template::ArrayMutation<#element_ty>
+        }
+    } else {
+        let ident = format_ident!("{}EnumArrayMutation", element_literal);
+        quote! {
+            crate::entities::sea_orm_active_enums::#ident
+        }
+    }
+}
+
+/// 不出現在 `Mutant` 中、也不能經由批次修改寫入的欄位
+///
+/// 租戶、軟刪除、稽核與版本欄位依 `#[graphql_template(...)]` 標記另外排除，不以名稱判斷。
+const IGNORED_FIELDS: [&str; 2] = ["id", "created_at"];
+
+pub fn is_ignored_field(ident: &syn::Ident) -> bool {
+    IGNORED_FIELDS.contains(&ident.to_string().to_snake_case().as_str())
+}
+
 #[derive(Debug, Eq, PartialEq, bae::FromAttributes)]
 pub struct SeaOrm {
     table_name: Option<syn::Lit>,
@@ -27,91 +99,87 @@ pub struct SeaOrm {
 pub type IdentTypeTuple = (syn::Ident, syn::Type, bool);
 
 pub fn mutate_fn(item: syn::DataStruct, attrs: SeaOrm) -> Result<TokenStream, crate::error::Error> {
+    let guards = crate::guard::field_guards(&item.fields)?;
+    let tenant = crate::tenant::tenant_field(&item.fields)?;
+    let soft_delete = crate::soft_delete::soft_delete_field(&item.fields)?;
+    let audit = crate::audit::audit_fields(&item.fields)?;
+    let version = crate::version::version_field(&item.fields)?;
+
+    // 租戶與稽核欄位由 Context 與時鐘設定，軟刪除欄位只由 delete_many 與 restore_many 修改，
+    // 版本欄位只在更新時加一，都不允許經由 Mutant 修改
     let fields: Vec<IdentTypeTuple> = item
         .fields
         .into_iter()
+        .filter(|field| {
+            tenant
+                .iter()
+                .chain(soft_delete.iter())
+                .chain(audit.iter())
+                .chain(version.iter())
+                .all(|(ident, _, _)| field.ident.as_ref() != Some(ident))
+        })
         .map(|field| {
             let (ty, is_option) = remove_optional_from_type_and_get_is_option(field.ty).unwrap();
             (field.ident.unwrap(), ty, is_option)
         })
         .collect();
 
-    let mutant_struct = mutant_struct(&fields, &attrs)?;
+    let mutant_struct = mutant_struct(&fields, &attrs, &guards)?;
     let recursive_set_fn = recursive_set_fn(&fields)?;
+    let update_many_fn = update_many_fn(&fields, &version)?;
+    let numeric_mutant = numeric_mutant_fn(&fields, &attrs, &guards)?;
+    let set_tenant_fn = crate::tenant::set_tenant_fn(&tenant);
+    let set_audit_fn = crate::audit::set_audit_fn(&audit);
+    let delete_many_fn = crate::soft_delete::delete_many_fn(&soft_delete);
+    let set_version_fn = crate::version::set_version_fn(&version);
 
     Ok(quote! {
         #mutant_struct
 
         #recursive_set_fn
+
+        #set_tenant_fn
+
+        #set_audit_fn
+
+        #set_version_fn
+
+        #update_many_fn
+
+        #delete_many_fn
+
+        #numeric_mutant
     })
 }
 
 pub fn mutant_struct(
     fields: &[IdentTypeTuple],
     attrs: &SeaOrm,
+    guards: &[FieldGuard],
 ) -> Result<TokenStream, crate::error::Error> {
+    let check_guards = check_guards_fn(
+        fields
+            .iter()
+            .map(|(ident, _, _)| ident)
+            .filter(|ident| !is_ignored_field(ident)),
+        guards,
+    );
+
     let fields: Vec<TokenStream> = fields
         .iter()
         .map(|(ident, ty, _)| {
-            let type_literal = ty.to_token_stream().to_string();
-            let is_vec = is_vec_type(ty);
-
-            let default_filters = vec![
-                "bool",
-                "f32",
-                "f64",
-                "i8",
-                "i16",
-                "i32",
-                "i64",
-                "u8",
-                "u16",
-                "u32",
-                "u64",
-                "BinaryVector",
-                "Date",
-                "DateTime",
-                "DateTimeUtc",
-                "DateTimeWithTimeZone",
-                "DeliveryTime",
-                "Decimal",
-                "Json",
-                "String",
-                "TaxType",
-                "Uuid",
-                "TaskStatus",
-                "TaskPriority",
-                "EnergyLevel",
-                "TagType",
-            ];
-
-            // 如果是 Vec 類型，直接使用原始類型
-            let filter_item = if is_vec {
-                quote! {
-                    #ty
-                }
-            } else if default_filters.contains(&type_literal.as_str()) {
+            // 如果是 Vec 類型，使用 ArrayMutation 支持元素級別的修改；
+            // 其餘類型（包含未知類型視為的枚舉）直接以新值覆蓋
+            let filter_item = if let Some(element_ty) = vec_element_type(ty) {
+                array_mutation_type(element_ty)
+            } else {
                 quote! {
                     #ty
                 }
-            } else {
-                quote! {}
             };
 
-            let ignore_fields = vec![
-                "id".to_owned(),
-                "user_id".to_owned(),
-                "created_at".to_owned(),
-            ];
-            let must_have = vec![];
-
-            let column_name = format_ident!("{}", ident.to_string().to_snake_case());
-            if ignore_fields.contains(&column_name.to_string()) {
+            if is_ignored_field(ident) {
                 quote! {}
-            } else if must_have.contains(&column_name.to_string()) {
-                quote! {
-                    pub #ident: #filter_item,
-                }
             } else {
                 quote! {
                     pub #ident: Option<#filter_item>,
@@ -133,36 +201,73 @@ pub fn mutant_struct(
         pub struct Mutant {
             #(#fields)*
         }
+
+        impl Mutant {
+            #check_guards
+        }
     })
 }
 
+/// 修改受保護的欄位前需先通過其守衛
+fn check_guards_fn<'a>(
+    idents: impl Iterator<Item = &'a syn::Ident>,
+    guards: &[FieldGuard],
+) -> TokenStream {
+    let (idents, paths): (Vec<_>, Vec<_>) = idents
+        .filter_map(|ident| find_guard(guards, ident).map(|path| (ident, path)))
+        .unzip();
+
+    let unused_ctx = if idents.is_empty() {
+        quote! { let _ = ctx; }
+    } else {
+        quote! {}
+    };
+
+    quote! {
+        pub fn check_guards(&self, ctx: &async_graphql::Context<'_>) -> async_graphql::Result<()> {
+            #unused_ctx
+
+            #(
+                if self.#idents.is_some() {
+                    #paths(ctx)?;
+                }
+            )*
+
+            Ok(())
+        }
+    }
+}
+
 pub fn recursive_set_fn(fields: &[IdentTypeTuple]) -> Result<TokenStream, crate::error::Error> {
     let columns_filters: Vec<TokenStream> = fields
         .iter()
         .map(|(ident, ty, is_option)| {
             let column_name = format_ident!("{}", ident.to_string().to_snake_case());
-            let _column_enum_name = format_ident!("{}", ident.to_string().to_upper_camel_case());
+            let column_literal = column_name.to_string();
             let is_vec = is_vec_type(ty);
 
-            let ignore_fields = vec![
-                "id".to_owned(),
-                "user_id".to_owned(),
-                "created_at".to_owned(),
-            ];
-            let must_have = vec![];
-
-            if ignore_fields.contains(&column_name.to_string()) {
+            if is_ignored_field(ident) {
                 quote! {}
-            } else if must_have.contains(&column_name.to_string()) {
+            } else if is_vec && is_option == &true {
+                // 對於 Vec 類型，在目前的值上套用數組修改，原子更新請使用 update_many_with_mutant；
+                // 欄位未載入時除了 set 以外都無法得知結果，回傳錯誤而非覆蓋整個數組
                 quote! {
-                    self.#column_name = Set(mutant.#column_name);
+                    if let Some(mutation) = mutant.#column_name {
+                        let current = self.#column_name.try_as_ref().cloned().map(Option::unwrap_or_default);
+                        self.#column_name = Set(Some(
+                            async_graphql_template::ArrayMutation::from(mutation)
+                                .apply_loaded(#column_literal, current)?
+                        ));
+                    }
                 }
             } else if is_vec {
-                // 對於 Vec 類型，直接將整個數組設置為新值，覆蓋舊的值
                 quote! {
-                    if let Some(value) = mutant.#column_name {
-                        // 直接將 Vec 作為整體設置，覆蓋舊值
-                        self.#column_name = Set(value);
+                    if let Some(mutation) = mutant.#column_name {
+                        let current = self.#column_name.try_as_ref().cloned();
+                        self.#column_name = Set(
+                            async_graphql_template::ArrayMutation::from(mutation)
+                                .apply_loaded(#column_literal, current)?
+                        );
                     }
                 }
             } else if is_option == &true {
@@ -184,9 +289,308 @@ pub fn recursive_set_fn(fields: &[IdentTypeTuple]) -> Result<TokenStream, crate:
     Ok(quote! {
         use sea_orm::ActiveValue::Set;
         impl ActiveModel {
-            pub fn recursive_set_value(&mut self, mutant: Mutant) {
+            /// 以 `Mutant` 設定欄位，數組修改需要該欄位已載入或只使用 `set`
+            pub fn recursive_set_value(&mut self, mutant: Mutant) -> Result<(), sea_orm::DbErr> {
                 #(#columns_filters)*
+
+                Ok(())
+            }
+        }
+    })
+}
+
+pub fn update_many_fn(
+    fields: &[IdentTypeTuple],
+    version: &Option<IdentTypeTuple>,
+) -> Result<TokenStream, crate::error::Error> {
+    let columns_exprs: Vec<TokenStream> = fields
+        .iter()
+        .map(|(ident, ty, _)| {
+            let column_name = format_ident!("{}", ident.to_string().to_snake_case());
+            let column_enum_name = format_ident!("{}", ident.to_string().to_upper_camel_case());
+            let column_literal = column_name.to_string();
+            let is_vec = is_vec_type(ty);
+
+            if is_ignored_field(ident) {
+                quote! {}
+            } else if is_vec {
+                quote! {
+                    if let Some(mutation) = mutant.#column_name {
+                        if backend != sea_orm::DbBackend::Postgres {
+                            return Err(sea_orm::DbErr::Custom(format!(
+                                "array mutation on column `{}` is only supported on Postgres, got {:?}",
+                                #column_literal,
+                                backend,
+                            )));
+                        }
+
+                        stmt = stmt.col_expr(
+                            Column::#column_enum_name,
+                            async_graphql_template::ArrayMutation::from(mutation)
+                                .to_expr(Column::#column_enum_name),
+                        );
+                    }
+                }
+            } else {
+                quote! {
+                    if let Some(value) = mutant.#column_name {
+                        stmt = stmt.col_expr(
+                            Column::#column_enum_name,
+                            Column::#column_enum_name.save_as(sea_orm::sea_query::Expr::val(value)),
+                        );
+                    }
+                }
             }
+        })
+        .collect();
+
+    // 有版本欄位時需帶入預期版本，只更新版本相符的資料並將版本加一；
+    // 沒有更新任何資料時，只有去掉版本條件後仍有符合的資料才回傳 CONFLICT
+    let (version_arg, version_doc, version_stmt, version_result) = match version {
+        Some((ident, ty, _)) => {
+            let column = format_ident!("{}", ident.to_string().to_upper_camel_case());
+
+            (
+                quote! { expected_version: #ty, },
+                quote! {
+                    ///
+                    /// 只更新版本為 `expected_version` 的資料並將版本加一。符合過濾條件的資料都不是該版本時
+                    /// 回傳 `CONFLICT`，沒有符合過濾條件的資料時回傳 0。
+                },
+                quote! {
+                    let stmt = stmt
+                        .col_expr(
+                            Column::#column,
+                            sea_orm::sea_query::Expr::col(Column::#column).add(1),
+                        )
+                        .filter(sea_orm::ColumnTrait::eq(&Column::#column, expected_version));
+                },
+                quote! {
+                    let rows_affected = stmt.filter(condition.clone()).exec(db).await?.rows_affected;
+
+                    if rows_affected == 0
+                        && sea_orm::PaginatorTrait::count(Entity::find().filter(condition), db).await? > 0
+                    {
+                        return Err(async_graphql_template::version_conflict());
+                    }
+
+                    Ok(rows_affected)
+                },
+            )
+        }
+        None => (
+            quote! {},
+            quote! {},
+            quote! {},
+            quote! {
+                Ok(stmt.filter(condition).exec(db).await?.rows_affected)
+            },
+        ),
+    };
+
+    // 有數值欄位時可同時帶入 NumericMutant，在同一個 UPDATE 中以 col = col + ? 等運算式修改
+    let numeric_idents: Vec<&syn::Ident> = numeric_fields(fields)
+        .into_iter()
+        .map(|(ident, _, _)| ident)
+        .collect();
+    let numeric_literals: Vec<String> = numeric_idents
+        .iter()
+        .map(|ident| ident.to_string().to_lower_camel_case())
+        .collect();
+
+    let (numeric_arg, numeric_doc, numeric_check, numeric_stmt) = if numeric_idents.is_empty() {
+        (quote! {}, quote! {}, quote! {}, quote! {})
+    } else {
+        (
+            quote! { numeric_mutant: Option<NumericMutant>, },
+            quote! {
+                ///
+                /// `numeric_mutant` 的數值運算在資料庫內完成，不能與 `mutant` 修改同一欄位。
+            },
+            quote! {
+                if let Some(numeric_mutant) = &numeric_mutant {
+                    numeric_mutant.check_guards(ctx)?;
+
+                    #(
+                        if mutant.#numeric_idents.is_some() && numeric_mutant.#numeric_idents.is_some() {
+                            return Err(async_graphql::Error::new(format!(
+                                "field `{}` is set in both mutant and numericMutant",
+                                #numeric_literals,
+                            )));
+                        }
+                    )*
+                }
+            },
+            quote! {
+                let stmt = match numeric_mutant {
+                    Some(numeric_mutant) => update_many_with_numeric_mutant(stmt, numeric_mutant),
+                    None => stmt,
+                };
+            },
+        )
+    };
+
+    Ok(quote! {
+        /// 以運算式更新欄位，數組修改在資料庫內完成而不需先讀取舊值；不檢查欄位守衛
+        pub fn update_many_with_mutant(
+            stmt: sea_orm::UpdateMany<Entity>,
+            mutant: Mutant,
+            backend: sea_orm::DbBackend,
+        ) -> Result<sea_orm::UpdateMany<Entity>, sea_orm::DbErr> {
+            let mut stmt = stmt;
+
+            #(#columns_exprs)*
+
+            Ok(stmt)
+        }
+
+        /// 更新同一租戶中符合過濾條件且通過 `EntityGuard` 的資料並寫入 `updated_at`，回傳更新筆數
+        #numeric_doc
+        #version_doc
+        pub async fn update_many(
+            ctx: &async_graphql::Context<'_>,
+            filters: Option<Filter>,
+            mutant: Mutant,
+            #numeric_arg
+            #version_arg
+        ) -> async_graphql::Result<u64> {
+            use sea_orm::{ConnectionTrait, EntityTrait, QueryFilter};
+
+            let db = &ctx
+                .data::<async_graphql::dataloader::DataLoader<crate::OrmDataloader>>()?
+                .loader()
+                .db;
+            let backend = db.get_database_backend();
+
+            mutant.check_guards(ctx)?;
+            #numeric_check
+
+            let condition = try_filter_guarded(ctx, filters, backend)?;
+            let stmt = update_many_with_mutant(Entity::update_many(), mutant, backend)?;
+            #numeric_stmt
+            let stmt = <Entity as async_graphql_template::EntityAudit>::set_updated_at(stmt);
+            #version_stmt
+
+            #version_result
+        }
+
+        /// 以 `Mutant` 新增一筆資料，租戶與稽核欄位取自 `Context` 中的 `Tenant`、`Actor` 與時鐘
+        pub async fn create_one(
+            ctx: &async_graphql::Context<'_>,
+            mutant: Mutant,
+        ) -> async_graphql::Result<Model> {
+            use sea_orm::{ActiveModelBehavior, ActiveModelTrait};
+
+            let db = &ctx
+                .data::<async_graphql::dataloader::DataLoader<crate::OrmDataloader>>()?
+                .loader()
+                .db;
+
+            mutant.check_guards(ctx)?;
+
+            let mut active_model = ActiveModel::new();
+            active_model.recursive_set_value(mutant)?;
+            active_model.set_tenant(ctx)?;
+            active_model.set_created_audit(ctx)?;
+            active_model.set_initial_version();
+
+            Ok(active_model.insert(db).await?)
+        }
+    })
+}
+
+/// 可使用 `NumericMutation` 的數值欄位
+fn numeric_fields(fields: &[IdentTypeTuple]) -> Vec<&IdentTypeTuple> {
+    let numeric_types = [
+        "i8",
+        "i16",
+        "i32",
+        "i64",
+        "u8",
+        "u16",
+        "u32",
+        "u64",
+        "f32",
+        "f64",
+        #[cfg(feature = "with-decimal")]
+        "Decimal",
+    ];
+
+    fields
+        .iter()
+        .filter(|(ident, ty, _)| {
+            numeric_types.contains(&ty.to_token_stream().to_string().as_str())
+                && !is_ignored_field(ident)
+        })
+        .collect()
+}
+
+pub fn numeric_mutant_fn(
+    fields: &[IdentTypeTuple],
+    attrs: &SeaOrm,
+    guards: &[FieldGuard],
+) -> Result<TokenStream, crate::error::Error> {
+    let numeric_fields = numeric_fields(fields);
+
+    // 沒有數值欄位時不生成，避免出現沒有欄位的 InputObject
+    if numeric_fields.is_empty() {
+        return Ok(quote! {});
+    }
+
+    let struct_fields: Vec<TokenStream> = numeric_fields
+        .iter()
+        .map(|(ident, ty, _)| {
+            quote! {
+                pub #ident: Option<async_graphql_template::NumericMutation<#ty>>,
+            }
+        })
+        .collect();
+
+    let columns_exprs: Vec<TokenStream> = numeric_fields
+        .iter()
+        .map(|(ident, _, _)| {
+            let column_enum_name = format_ident!("{}", ident.to_string().to_upper_camel_case());
+
+            quote! {
+                if let Some(expr) = numeric_mutant
+                    .#ident
+                    .and_then(|mutation| mutation.to_expr(Column::#column_enum_name))
+                {
+                    stmt = stmt.col_expr(Column::#column_enum_name, expr);
+                }
+            }
+        })
+        .collect();
+
+    let entity_name = match &attrs.table_name {
+        Some(syn::Lit::Str(name)) => name,
+        _ => return Err(crate::error::Error::Internal("Invalid entity name".into())),
+    };
+
+    let numeric_mutant_name = format!("{}NumericMutant", entity_name.value().to_upper_camel_case());
+    let check_guards = check_guards_fn(numeric_fields.iter().map(|(ident, _, _)| ident), guards);
+
+    Ok(quote! {
+        #[derive(Debug, Clone, async_graphql::InputObject)]
+        #[graphql(name = #numeric_mutant_name)]
+        pub struct NumericMutant {
+            #(#struct_fields)*
+        }
+
+        impl NumericMutant {
+            #check_guards
+        }
+
+        /// 以 `col = col + ?` 等運算式更新數值欄位，不檢查欄位守衛
+        pub fn update_many_with_numeric_mutant(
+            stmt: sea_orm::UpdateMany<Entity>,
+            numeric_mutant: NumericMutant,
+        ) -> sea_orm::UpdateMany<Entity> {
+            let mut stmt = stmt;
+
+            #(#columns_exprs)*
+
+            stmt
         }
     })
 }
@@ -0,0 +1,69 @@
+// Derived from Seaography (github.com/SeaQL/seaography)
+// Modifications Copyright (c) 2025 Stephen J. Li
+
+use heck::ToUpperCamelCase;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+/// 欄位上 `#[graphql(name = "...")]` 指定的輸出名稱
+fn output_name(field: &syn::Field) -> Result<Option<syn::LitStr>, crate::error::Error> {
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("graphql"))
+    {
+        if let syn::Meta::List(list) = attr.parse_meta()? {
+            for nested in list.nested.iter() {
+                if let syn::NestedMeta::Meta(syn::Meta::NameValue(value)) = nested {
+                    if let (true, syn::Lit::Str(lit)) = (value.path.is_ident("name"), &value.lit) {
+                        return Ok(Some(lit.clone()));
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+/// 改名欄位的 `EntityOutput` 實作，供 `SchemaBuilder` 產生與 `SimpleObject` 相同的輸出欄位
+pub fn entity_output_fn(fields: &syn::Fields) -> Result<TokenStream, crate::error::Error> {
+    let mut arms: Vec<TokenStream> = Vec::new();
+
+    for field in fields.iter() {
+        if let Some(name) = output_name(field)? {
+            let column = format_ident!(
+                "{}",
+                field
+                    .ident
+                    .as_ref()
+                    .unwrap()
+                    .to_string()
+                    .to_upper_camel_case()
+            );
+
+            arms.push(quote! {
+                Column::#column => #name.into(),
+            });
+        }
+    }
+
+    if arms.is_empty() {
+        return Ok(quote! {
+            impl async_graphql_template::EntityOutput for Entity {}
+        });
+    }
+
+    Ok(quote! {
+        impl async_graphql_template::EntityOutput for Entity {
+            fn output_name(column: Column) -> String {
+                use async_graphql_template::heck::ToLowerCamelCase;
+
+                match column {
+                    #(#arms)*
+                    _ => format!("{:?}", column).to_lower_camel_case(),
+                }
+            }
+        }
+    })
+}
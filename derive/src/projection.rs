@@ -0,0 +1,190 @@
+// Derived from Seaography (github.com/SeaQL/seaography)
+// Modifications Copyright (c) 2025 Stephen J. Li
+
+use heck::{ToLowerCamelCase, ToUpperCamelCase};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+
+use crate::filter::{is_vec_type, IdentTypeTuple};
+
+/// 未被選取的欄位以 `Default::default()` 填入，只有確定實作 `Default` 的類型可以省略
+fn is_defaultable_type(ty: &syn::Type, is_option: bool) -> bool {
+    let defaultable_types = [
+        "bool",
+        "i8",
+        "i16",
+        "i32",
+        "i64",
+        "u8",
+        "u16",
+        "u32",
+        "u64",
+        "f32",
+        "f64",
+        "String",
+        "BinaryVector",
+        #[cfg(feature = "with-chrono")]
+        "Date",
+        #[cfg(feature = "with-chrono")]
+        "Time",
+        #[cfg(feature = "with-chrono")]
+        "DateTime",
+        #[cfg(feature = "with-chrono")]
+        "DateTimeUtc",
+        #[cfg(feature = "with-decimal")]
+        "Decimal",
+        #[cfg(feature = "with-json")]
+        "Json",
+        #[cfg(feature = "with-uuid")]
+        "Uuid",
+    ];
+
+    is_option
+        || is_vec_type(ty)
+        || defaultable_types.contains(&ty.to_token_stream().to_string().as_str())
+}
+
+pub fn projection_fn(fields: &[IdentTypeTuple]) -> Result<TokenStream, crate::error::Error> {
+    let mut required_columns: Vec<syn::Ident> = Vec::new();
+    let mut selection_arms: Vec<TokenStream> = Vec::new();
+    let mut model_fields: Vec<TokenStream> = Vec::new();
+
+    for (ident, ty, is_option) in fields.iter() {
+        let column = format_ident!("{}", ident.to_string().to_upper_camel_case());
+        let graphql_name = ident.to_string().to_lower_camel_case();
+
+        selection_arms.push(quote! {
+            #graphql_name => Some(Column::#column),
+        });
+
+        if is_defaultable_type(ty, *is_option) {
+            model_fields.push(quote! {
+                #ident: if is_selected(Column::#column) {
+                    res.try_get(pre, Column::#column.as_str())?
+                } else {
+                    Default::default()
+                }
+            });
+        } else {
+            required_columns.push(column.clone());
+
+            model_fields.push(quote! {
+                #ident: res.try_get(pre, Column::#column.as_str())?
+            });
+        }
+    }
+
+    Ok(quote! {
+        /// 依 GraphQL 選取的欄位決定要查詢的資料庫欄位
+        ///
+        /// 主鍵、關聯使用的欄位，以及無法以預設值填入的欄位總是包含在內。
+        pub fn selected_columns(field: &async_graphql::SelectionField<'_>) -> Vec<Column> {
+            selected_columns_in(field.selection_set())
+        }
+
+        /// 分頁連線中 `nodes` 與 `edges { node }` 選取的欄位，規則與 `selected_columns` 相同
+        pub fn selected_connection_columns(field: &async_graphql::SelectionField<'_>) -> Vec<Column> {
+            let mut nodes = Vec::new();
+
+            for child in field.selection_set() {
+                match child.name() {
+                    "nodes" => nodes.extend(child.selection_set()),
+                    "edges" => {
+                        for edge in child.selection_set().filter(|edge| edge.name() == "node") {
+                            nodes.extend(edge.selection_set());
+                        }
+                    }
+                    _ => {}
+                }
+            }
+
+            selected_columns_in(nodes)
+        }
+
+        /// 選取不對應欄位的輸出欄位（如改名或計算欄位）時無法判斷其所需欄位，改為選取全部欄位
+        fn selected_columns_in<'a>(
+            fields: impl IntoIterator<Item = async_graphql::SelectionField<'a>>,
+        ) -> Vec<Column> {
+            use async_graphql_template::heck::{ToLowerCamelCase, ToSnakeCase};
+            use sea_orm::{IdenStatic, Iterable, PrimaryKeyToColumn, RelationTrait};
+            use ::std::str::FromStr;
+
+            let mut columns: Vec<Column> = vec![#(Column::#required_columns),*];
+
+            let mut push = |column: Column| {
+                if !columns.iter().any(|selected| selected.as_str() == column.as_str()) {
+                    columns.push(column);
+                }
+            };
+
+            // 關聯與其聚合欄位只需要關聯使用的欄位，在下方加入
+            let is_relation_field = |name: &str| {
+                Relation::iter().any(|relation| {
+                    let relation = format!("{:?}", relation).to_lower_camel_case();
+
+                    name == relation || name.strip_suffix("Aggregate") == Some(relation.as_str())
+                })
+            };
+
+            for child in fields {
+                let column = match child.name() {
+                    #(#selection_arms)*
+                    _ => None,
+                };
+
+                match column {
+                    Some(column) => push(column),
+                    None if child.name().starts_with("__") || is_relation_field(child.name()) => {}
+                    None => return Column::iter().collect(),
+                }
+            }
+
+            for primary_key in PrimaryKey::iter() {
+                push(primary_key.into_column());
+            }
+
+            for relation in Relation::iter() {
+                if let Ok(column) = Column::from_str(
+                    relation.def().from_col.to_string().to_snake_case().as_str(),
+                ) {
+                    push(column);
+                }
+            }
+
+            columns
+        }
+
+        /// 只選取 `columns`，搭配 `PartialModel` 讀取
+        pub fn select_columns(stmt: sea_orm::Select<Entity>, columns: &[Column]) -> sea_orm::Select<Entity> {
+            use sea_orm::QuerySelect;
+
+            stmt.select_only().columns(columns.iter().copied())
+        }
+
+        /// 部分欄位的 Model，未選取的欄位為預設值
+        #[derive(Debug, Clone)]
+        pub struct PartialModel(pub Model);
+
+        impl sea_orm::FromQueryResult for PartialModel {
+            fn from_query_result(res: &sea_orm::QueryResult, pre: &str) -> Result<Self, sea_orm::DbErr> {
+                use sea_orm::IdenStatic;
+
+                let selected = res.column_names();
+                let is_selected = |column: Column| {
+                    let name = format!("{}{}", pre, column.as_str());
+                    selected.iter().any(|selected| selected == &name)
+                };
+
+                Ok(Self(Model {
+                    #(#model_fields),*
+                }))
+            }
+        }
+
+        impl From<PartialModel> for Model {
+            fn from(partial: PartialModel) -> Self {
+                partial.0
+            }
+        }
+    })
+}
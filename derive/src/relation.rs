@@ -1,4 +1,4 @@
-use heck::ToUpperCamelCase;
+use heck::{ToLowerCamelCase, ToSnakeCase, ToUpperCamelCase};
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote, ToTokens};
 
@@ -6,6 +6,7 @@ use quote::{format_ident, quote, ToTokens};
 pub struct SeaOrm {
     belongs_to: Option<syn::Lit>,
     has_many: Option<syn::Lit>,
+    has_one: Option<syn::Lit>,
     from: Option<syn::Lit>,
     to: Option<syn::Lit>,
     on_update: Option<syn::Lit>,
@@ -20,7 +21,8 @@ pub fn compact_relation_fn(item: &syn::DataEnum) -> Result<TokenStream, crate::e
             |variant| -> Result<(TokenStream, TokenStream), crate::error::Error> {
                 let attrs = SeaOrm::from_attributes(&variant.attrs)?;
 
-                let belongs_to = match attrs.belongs_to {
+                // has-one 與 belongs-to 同樣對應單筆關聯資料
+                let belongs_to = match attrs.belongs_to.or(attrs.has_one) {
                     Some(syn::Lit::Str(belongs_to)) => Some(belongs_to.value()),
                     _ => None,
                 };
@@ -38,9 +40,31 @@ pub fn compact_relation_fn(item: &syn::DataEnum) -> Result<TokenStream, crate::e
         .map(|(loader, func)| (loader, func))
         .unzip();
 
+    let belongs_to: Vec<(String, String)> = item
+        .variants
+        .iter()
+        .map(|variant| -> Result<_, crate::error::Error> {
+            let attrs = SeaOrm::from_attributes(&variant.attrs)?;
+
+            Ok(match attrs.belongs_to {
+                Some(syn::Lit::Str(belongs_to)) => {
+                    Some((variant.ident.to_string(), belongs_to.value()))
+                }
+                _ => None,
+            })
+        })
+        .collect::<Result<Vec<_>, crate::error::Error>>()?
+        .into_iter()
+        .flatten()
+        .collect();
+
+    let related_order_by = related_order_by_fn(&belongs_to)?;
+
     Ok(quote! {
         #(#loaders)*
 
+        #related_order_by
+
         #[async_graphql::ComplexObject]
         impl Model {
             #(#functions)*
@@ -96,9 +120,13 @@ pub fn expanded_relation_fn(item: &syn::ItemImpl) -> Result<TokenStream, crate::
         .to_string()
         .contains("No RelationDef")
     {
+        let related_order_by = related_order_by_fn(&[])?;
+
         return Ok(quote! {
             #item
 
+            #related_order_by
+
             #[async_graphql::ComplexObject]
             impl Model {
             }
@@ -146,11 +174,26 @@ pub fn expanded_relation_fn(item: &syn::ItemImpl) -> Result<TokenStream, crate::
         .map(|(loader, func)| (loader, func))
         .unzip();
 
+    let belongs_to: Vec<(String, String)> = expanded_params
+        .iter()
+        .filter(|params| params.relation_type.to_string().eq("belongs_to"))
+        .map(|params| {
+            (
+                params.variant.to_string(),
+                params.related_type.to_token_stream().to_string(),
+            )
+        })
+        .collect();
+
+    let related_order_by = related_order_by_fn(&belongs_to)?;
+
     Ok(quote! {
         #item
 
         #(#loaders)*
 
+        #related_order_by
+
         #[async_graphql::ComplexObject]
         impl Model {
             #(#functions)*
@@ -175,15 +218,8 @@ pub fn relation_fn(
         ));
     };
 
-    let path: TokenStream = if target_path.ne("Entity") {
-        target_path.as_str()[..target_path.len() - 8]
-            .parse()
-            .unwrap()
-    } else {
-        return Err(crate::error::Error::Internal(
-            "Cannot parse entity path".into(),
-        ));
-    };
+    let path = entity_module_path(target_path)?;
+    let soft_delete_visible = format!("{}::has_soft_delete", path.to_string().replace(' ', ""));
 
     let (return_type, extra_imports, map_method) = if has_many.is_some() {
         (
@@ -200,12 +236,117 @@ pub fn relation_fn(
     };
 
     let relation_enum = quote! {Relation::#relation_ident};
+
+    // has-many 關聯額外提供聚合欄位，以外鍵條件加上子實體的 Filter 計算；
+    // 同一批次中相同條件的父資料列以 DataLoader 合併為一條 GROUP BY 查詢
+    let (aggregate_loader, aggregate_function) = if has_many.is_some() {
+        let aggregate_ident = format_ident!("{}Aggregate", relation_ident);
+        let aggregate_key_name = format_ident!("{}AggregateKey", relation_ident);
+
+        (
+            quote! {
+                #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+                pub struct #aggregate_key_name(
+                    pub seaography::RelationKeyStruct<(), ()>,
+                    /// 子實體的過濾、租戶、`EntityGuard` 與軟刪除條件
+                    pub seaography::GuardCondition,
+                );
+
+                #[async_trait::async_trait]
+                impl async_graphql::dataloader::Loader<#aggregate_key_name> for crate::OrmDataloader {
+                    type Value = #path::Aggregate;
+                    type Error = std::sync::Arc<sea_orm::error::DbErr>;
+
+                    async fn load(
+                        &self,
+                        keys: &[#aggregate_key_name],
+                    ) -> Result<std::collections::HashMap<#aggregate_key_name, Self::Value>, Self::Error> {
+                        use seaography::heck::ToSnakeCase;
+                        use ::std::str::FromStr;
+
+                        let to_column = <#path::Column as FromStr>::from_str(
+                            #relation_enum.def().to_col.to_string().to_snake_case().as_str()
+                        ).unwrap();
+
+                        let mut result = std::collections::HashMap::new();
+
+                        for (condition, keys) in seaography::group_by_guard(keys, |key| &key.1) {
+                            let values: Vec<sea_orm::Value> = keys
+                                .iter()
+                                .map(|key| key.0 .0.clone())
+                                .collect();
+
+                            let data: std::collections::HashMap<_, _> = #path::aggregate_grouped(
+                                &self.db,
+                                to_column,
+                                values,
+                                condition.0,
+                            )
+                            .await?
+                            .into_iter()
+                            .map(|(value, aggregate)| (seaography::RelationKeyStruct(value, (), ()), aggregate))
+                            .collect();
+
+                            // 沒有子資料列的父資料列不在查詢結果中
+                            result.extend(keys.into_iter().map(|key| {
+                                let aggregate = data.get(&key.0).cloned().unwrap_or_default();
+
+                                (key.clone(), aggregate)
+                            }));
+                        }
+
+                        Ok(result)
+                    }
+                }
+            },
+            quote! {
+                pub async fn #aggregate_ident<'a>(
+                    &self,
+                    ctx: &async_graphql::Context<'a>,
+                    filters: Option<#path::Filter>,
+                    #[graphql(visible = #soft_delete_visible)]
+                    with_deleted: Option<bool>,
+                ) -> async_graphql::Result<#path::Aggregate> {
+                    use seaography::heck::ToSnakeCase;
+                    use ::std::str::FromStr;
+
+                    let data_loader = ctx
+                        .data::<async_graphql::dataloader::DataLoader<crate::OrmDataloader>>()?;
+                    let db = &data_loader.loader().db;
+
+                    let from_column: Column = Column::from_str(
+                        #relation_enum.def().from_col.to_string().to_snake_case().as_str()
+                    ).unwrap();
+
+                    let key = #aggregate_key_name(
+                        seaography::RelationKeyStruct(self.get(from_column), (), ()),
+                        seaography::GuardCondition(#path::try_filter_with_deleted(
+                            ctx,
+                            filters,
+                            with_deleted,
+                            db.get_database_backend(),
+                        )?),
+                    );
+
+                    Ok(data_loader.load_one(key).await?.unwrap_or_default())
+                }
+            },
+        )
+    } else {
+        (quote! {}, quote! {})
+    };
     let foreign_key_name = format_ident!("{}FK", relation_ident).to_token_stream();
 
     Ok((
         quote! {
             #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-            pub struct #foreign_key_name(pub seaography::RelationKeyStruct<Option<#path::Filter>, Option<#path::OrderBy>>);
+            pub struct #foreign_key_name(
+                pub seaography::RelationKeyStruct<Option<#path::Filter>, Option<#path::OrderBy>>,
+                /// 需要選取的欄位，`None` 表示選取全部
+                pub Option<Vec<String>>,
+                /// 關聯實體的租戶、`EntityGuard` 與軟刪除條件
+                pub seaography::GuardCondition,
+            );
 
             #[async_trait::async_trait]
             impl async_graphql::dataloader::Loader<#foreign_key_name> for crate::OrmDataloader {
@@ -219,39 +360,63 @@ pub fn relation_fn(
                     use seaography::heck::ToSnakeCase;
                     use ::std::str::FromStr;
 
-                    let keys: Vec<_> = keys
-                        .into_iter()
-                        .map(|key| key.0.to_owned())
-                        .collect();
-
                     #extra_imports
 
-                    let data: std::collections::HashMap<#foreign_key_name, Self::Value> = seaography
-                        ::fetch_relation_data::<#path::Entity, #path::Filter, #path::OrderBy>(
-                            keys,
-                            #relation_enum.def(),
-                            &self.db,
-                        ).await?
-                        .into_iter()
-                        .map(|(key, model)| (#foreign_key_name(key), model))
-                        #map_method;
-
-
-                    Ok(data)
+                    let mut result = std::collections::HashMap::new();
+
+                    // 不同呼叫者的守衛條件不同，分別查詢
+                    for (guard, keys) in seaography::group_by_guard(keys, |key| &key.2) {
+                        // 同一批次選取所有請求欄位的聯集，任一請求需要全部欄位時選取全部
+                        let columns: Option<Vec<String>> = keys.iter().try_fold(Vec::new(), |mut columns, key| {
+                            for column in key.1.as_ref()? {
+                                if !columns.contains(column) {
+                                    columns.push(column.clone());
+                                }
+                            }
+
+                            Some(columns)
+                        });
+
+                        let relation_keys: Vec<_> = keys
+                            .iter()
+                            .map(|key| key.0.to_owned())
+                            .collect();
+
+                        let data: std::collections::HashMap<_, Self::Value> = seaography
+                            ::fetch_relation_data_with_columns::<#path::Entity, #path::Filter, #path::OrderBy, #path::PartialModel>(
+                                relation_keys,
+                                #relation_enum.def(),
+                                columns,
+                                guard.0,
+                                &self.db,
+                            ).await?
+                            .into_iter()
+                            #map_method;
+
+                        result.extend(
+                            keys.into_iter()
+                                .filter_map(|key| data.get(&key.0).map(|value| (key.clone(), value.clone())))
+                        );
+                    }
+
+                    Ok(result)
                 }
             }
+
+            #aggregate_loader
         },
         quote! {
             pub async fn #relation_ident<'a>(
                 &self,
                 ctx: &async_graphql::Context<'a>,
-            ) -> Option<#return_type> {
+                #[graphql(visible = #soft_delete_visible)]
+                with_deleted: Option<bool>,
+            ) -> async_graphql::Result<Option<#return_type>> {
                 use seaography::heck::ToSnakeCase;
                 use ::std::str::FromStr;
 
                 let data_loader = ctx
-                    .data::<async_graphql::dataloader::DataLoader<crate::OrmDataloader>>()
-                    .unwrap();
+                    .data::<async_graphql::dataloader::DataLoader<crate::OrmDataloader>>()?;
 
                 let from_column: Column = Column::from_str(
                     #relation_enum
@@ -262,12 +427,174 @@ pub fn relation_fn(
                         .as_str()
                 ).unwrap();
 
-                let key = #foreign_key_name(seaography::RelationKeyStruct(self.get(from_column), None, None));
-
-                let data: Option<_> = data_loader.load_one(key).await.unwrap();
-
-                data
+                let mut columns: Vec<String> = #path::selected_columns(&ctx.field())
+                    .iter()
+                    .map(|column| sea_orm::IdenStatic::as_str(column).to_owned())
+                    .collect();
+                columns.sort();
+
+                let key = #foreign_key_name(
+                    seaography::RelationKeyStruct(self.get(from_column), None, None),
+                    Some(columns),
+                    seaography::GuardCondition(
+                        seaography::row_condition::<#path::Entity>(ctx)?
+                            .add(seaography::soft_delete_condition::<#path::Entity>(with_deleted)),
+                    ),
+                );
+
+                Ok(data_loader.load_one(key).await?)
             }
+
+            #aggregate_function
         },
     ))
 }
+
+/// 由 `super::language::Entity` 取得實體所在模塊 `super::language`
+fn entity_module_path(target_path: &str) -> Result<TokenStream, crate::error::Error> {
+    if target_path.ne("Entity") {
+        Ok(target_path[..target_path.len() - 8].parse().unwrap())
+    } else {
+        Err(crate::error::Error::Internal(
+            "Cannot parse entity path".into(),
+        ))
+    }
+}
+
+/// 產生 belongs-to 關聯的排序欄位，由 `OrderBy` 以 flatten 方式合併
+///
+/// 每個關聯以獨立別名 LEFT JOIN，避免與過濾或自關聯的 JOIN 衝突；
+/// 關聯實體的 `OrderBy` 可再包含其 belongs-to 關聯，別名沿路徑串接（如 `order_by_film_language`）。
+pub fn related_order_by_fn(
+    belongs_to: &[(String, String)],
+) -> Result<TokenStream, crate::error::Error> {
+    if belongs_to.is_empty() {
+        return Ok(quote! {
+            pub type RelatedOrderBy = seaography::NoRelatedOrderBy;
+        });
+    }
+
+    let (fields, joins): (Vec<_>, Vec<_>) = belongs_to
+        .iter()
+        .map(
+            |(relation_name, target_path)| -> Result<(TokenStream, TokenStream), crate::error::Error> {
+                let relation_ident = format_ident!("{}", relation_name.to_upper_camel_case());
+                let field_ident = format_ident!("{}", relation_name.to_snake_case());
+                let key = relation_name.to_snake_case().to_lower_camel_case();
+                let suffix = relation_name.to_snake_case();
+                let path = entity_module_path(target_path)?;
+
+                Ok((
+                    quote! {
+                        pub #field_ident: Option<Box<seaography::Ordered<#path::OrderBy>>>
+                    },
+                    quote! {
+                        #key => {
+                            if let Some(order_by) = self.#field_ident.take() {
+                                let join_alias = format!("{}_{}", alias.unwrap_or("order_by"), #suffix);
+
+                                if !joined.contains(&join_alias) {
+                                    let mut relation = Relation::#relation_ident.def();
+
+                                    if let Some(alias) = alias {
+                                        relation.from_tbl = relation.from_tbl.alias(Alias::new(alias));
+                                    }
+
+                                    stmt = stmt.join_as(
+                                        sea_orm::JoinType::LeftJoin,
+                                        relation,
+                                        Alias::new(&join_alias),
+                                    );
+                                    joined.push(join_alias.clone());
+                                }
+
+                                stmt = order_by.value.apply(stmt, &order_by.keys, Some(&join_alias), joined)?;
+                            }
+                        }
+                    },
+                ))
+            },
+        )
+        .collect::<Result<Vec<_>, crate::error::Error>>()?
+        .into_iter()
+        .unzip();
+
+    let field_idents: Vec<syn::Ident> = belongs_to
+        .iter()
+        .map(|(relation_name, _)| format_ident!("{}", relation_name.to_snake_case()))
+        .collect();
+    let keys: Vec<String> = belongs_to
+        .iter()
+        .map(|(relation_name, _)| relation_name.to_snake_case().to_lower_camel_case())
+        .collect();
+
+    Ok(quote! {
+        #[derive(Debug, Clone, async_graphql::InputObject)]
+        #[graphql(name_type)]
+        pub struct RelatedOrderBy {
+            #(#fields),*
+        }
+
+        impl RelatedOrderBy {
+            /// 關聯實體的欄位守衛
+            pub fn check_guards(&self, ctx: &async_graphql::Context<'_>) -> async_graphql::Result<()> {
+                #(
+                    if let Some(order_by) = &self.#field_idents {
+                        order_by.value.check_guards(ctx)?;
+                    }
+                )*
+
+                Ok(())
+            }
+
+            /// 依欄位宣告順序列出已設定的關聯
+            pub fn declared_keys(&self) -> Vec<String> {
+                let mut keys = Vec::new();
+
+                #(
+                    if self.#field_idents.is_some() {
+                        keys.push(#keys.to_owned());
+                    }
+                )*
+
+                keys
+            }
+
+            /// `key` 為關聯欄位時以 `{alias}_{關聯}` 別名 LEFT JOIN 關聯實體並遞迴套用其排序，
+            /// 同一別名只 JOIN 一次
+            pub fn apply_key<S>(
+                &mut self,
+                key: &str,
+                stmt: S,
+                alias: Option<&str>,
+                joined: &mut Vec<String>,
+            ) -> Result<S, sea_orm::DbErr>
+            where
+                S: sea_orm::QuerySelect + sea_orm::QueryOrder,
+            {
+                use sea_orm::sea_query::Alias;
+                use sea_orm::RelationTrait;
+
+                let mut stmt = stmt;
+
+                match key {
+                    #(#joins)*
+                    _ => {}
+                }
+
+                Ok(stmt)
+            }
+        }
+
+        impl async_graphql::TypeName for RelatedOrderBy {
+            fn type_name() -> ::std::borrow::Cow<'static, str> {
+                use seaography::heck::ToUpperCamelCase;
+                use sea_orm::EntityName;
+
+                let name = format!("{}RelatedOrderBy", Entity::default().table_name().to_upper_camel_case());
+
+                ::std::borrow::Cow::Owned(name)
+            }
+        }
+    })
+}
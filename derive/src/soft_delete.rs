@@ -0,0 +1,139 @@
+// Derived from Seaography (github.com/SeaQL/seaography)
+// Modifications Copyright (c) 2025 Stephen J. Li
+
+use heck::ToUpperCamelCase;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use crate::mutate::IdentTypeTuple;
+
+/// `#[graphql_template(soft_delete)]` 標記的軟刪除欄位，需可為空
+pub fn soft_delete_field(
+    fields: &syn::Fields,
+) -> Result<Option<IdentTypeTuple>, crate::error::Error> {
+    let field = crate::guard::flagged_field(fields, "soft_delete")?;
+
+    if let Some((ident, _, false)) = &field {
+        return Err(crate::error::Error::Internal(format!(
+            "soft delete field `{}` must be an Option",
+            ident
+        )));
+    }
+
+    Ok(field)
+}
+
+/// 軟刪除欄位的 `EntitySoftDelete` 實作，以及控制 `withDeleted` 參數是否顯示的函數
+pub fn entity_soft_delete_fn(soft_delete: &Option<IdentTypeTuple>) -> TokenStream {
+    let (column, has_soft_delete) = match soft_delete {
+        Some((ident, _, _)) => {
+            let column = format_ident!("{}", ident.to_string().to_upper_camel_case());
+
+            (
+                quote! {
+                    fn soft_delete_column() -> Option<Column> {
+                        Some(Column::#column)
+                    }
+                },
+                true,
+            )
+        }
+        None => (quote! {}, false),
+    };
+
+    quote! {
+        impl async_graphql_template::EntitySoftDelete for Entity {
+            #column
+        }
+
+        /// 作為 `withDeleted` 參數的 `visible`，只在有軟刪除欄位的實體上顯示
+        pub fn has_soft_delete(_: &async_graphql::Context<'_>) -> bool {
+            #has_soft_delete
+        }
+    }
+}
+
+/// 有軟刪除欄位時 `delete_many` 改為寫入目前時間，並提供 `restore_many` 清除該欄位
+pub fn delete_many_fn(soft_delete: &Option<IdentTypeTuple>) -> TokenStream {
+    let (ident, ty) = match soft_delete {
+        Some((ident, ty, _)) => (ident, ty),
+        None => {
+            return quote! {
+                /// 刪除同一租戶中符合過濾條件且通過 `EntityGuard` 的資料，回傳刪除筆數
+                pub async fn delete_many(
+                    ctx: &async_graphql::Context<'_>,
+                    filters: Option<Filter>,
+                ) -> async_graphql::Result<u64> {
+                    use sea_orm::{ConnectionTrait, EntityTrait, QueryFilter};
+
+                    let db = &ctx
+                        .data::<async_graphql::dataloader::DataLoader<crate::OrmDataloader>>()?
+                        .loader()
+                        .db;
+
+                    let condition = try_filter_guarded(ctx, filters, db.get_database_backend())?;
+
+                    Ok(Entity::delete_many().filter(condition).exec(db).await?.rows_affected)
+                }
+            };
+        }
+    };
+
+    let column = format_ident!("{}", ident.to_string().to_upper_camel_case());
+
+    quote! {
+        /// 軟刪除同一租戶中符合過濾條件且通過 `EntityGuard` 的資料，回傳刪除筆數
+        ///
+        /// 以資料庫的 `CURRENT_TIMESTAMP` 寫入軟刪除欄位，已刪除的資料保留原本的時間。
+        pub async fn delete_many(
+            ctx: &async_graphql::Context<'_>,
+            filters: Option<Filter>,
+        ) -> async_graphql::Result<u64> {
+            use sea_orm::{ConnectionTrait, EntityTrait, QueryFilter};
+
+            let db = &ctx
+                .data::<async_graphql::dataloader::DataLoader<crate::OrmDataloader>>()?
+                .loader()
+                .db;
+
+            let condition = try_filter_guarded(ctx, filters, db.get_database_backend())?;
+
+            Ok(Entity::update_many()
+                .col_expr(
+                    Column::#column,
+                    sea_orm::sea_query::Expr::current_timestamp().into(),
+                )
+                .filter(condition)
+                .exec(db)
+                .await?
+                .rows_affected)
+        }
+
+        /// 還原同一租戶中符合過濾條件且通過 `EntityGuard` 的已刪除資料，回傳還原筆數
+        pub async fn restore_many(
+            ctx: &async_graphql::Context<'_>,
+            filters: Option<Filter>,
+        ) -> async_graphql::Result<u64> {
+            use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter};
+
+            let db = &ctx
+                .data::<async_graphql::dataloader::DataLoader<crate::OrmDataloader>>()?
+                .loader()
+                .db;
+
+            let condition =
+                try_filter_with_deleted(ctx, filters, Some(true), db.get_database_backend())?;
+
+            Ok(Entity::update_many()
+                .col_expr(
+                    Column::#column,
+                    sea_orm::sea_query::Expr::value(Option::<#ty>::None),
+                )
+                .filter(condition)
+                .filter(Column::#column.is_not_null())
+                .exec(db)
+                .await?
+                .rows_affected)
+        }
+    }
+}
@@ -0,0 +1,78 @@
+// Derived from Seaography (github.com/SeaQL/seaography)
+// Modifications Copyright (c) 2025 Stephen J. Li
+
+use heck::ToUpperCamelCase;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use crate::mutate::IdentTypeTuple;
+
+/// `#[graphql_template(tenant)]` 標記的租戶欄位
+pub fn tenant_field(fields: &syn::Fields) -> Result<Option<IdentTypeTuple>, crate::error::Error> {
+    crate::guard::flagged_field(fields, "tenant")
+}
+
+/// 以 `Context` 中的 `Tenant` 限制資料列，沒有租戶欄位時使用不限制的預設實作
+pub fn entity_tenant_fn(tenant: &Option<IdentTypeTuple>) -> TokenStream {
+    let (ident, ty, _) = match tenant {
+        Some(tenant) => tenant,
+        None => {
+            return quote! {
+                impl async_graphql_template::EntityTenant for Entity {}
+            }
+        }
+    };
+
+    let column = format_ident!("{}", ident.to_string().to_upper_camel_case());
+
+    quote! {
+        impl async_graphql_template::EntityTenant for Entity {
+            fn tenant_column() -> Option<Column> {
+                Some(Column::#column)
+            }
+
+            fn tenant(
+                ctx: &async_graphql::Context<'_>,
+            ) -> async_graphql::Result<sea_orm::Condition> {
+                use sea_orm::ColumnTrait;
+
+                let tenant = ctx.data::<async_graphql_template::Tenant<#ty>>()?;
+
+                Ok(sea_orm::Condition::all().add(Column::#column.eq(tenant.0.clone())))
+            }
+        }
+    }
+}
+
+/// 新增資料時由 `Context` 中的 `Tenant` 設定租戶欄位
+pub fn set_tenant_fn(tenant: &Option<IdentTypeTuple>) -> TokenStream {
+    let set = match tenant {
+        Some((ident, ty, is_option)) => {
+            let value = if *is_option {
+                quote! { Some(tenant.0.clone()) }
+            } else {
+                quote! { tenant.0.clone() }
+            };
+
+            quote! {
+                let tenant = ctx.data::<async_graphql_template::Tenant<#ty>>()?;
+
+                self.#ident = sea_orm::ActiveValue::Set(#value);
+            }
+        }
+        None => quote! {
+            let _ = ctx;
+        },
+    };
+
+    quote! {
+        impl ActiveModel {
+            /// 設定租戶欄位，沒有租戶欄位時不做任何事
+            pub fn set_tenant(&mut self, ctx: &async_graphql::Context<'_>) -> async_graphql::Result<()> {
+                #set
+
+                Ok(())
+            }
+        }
+    }
+}
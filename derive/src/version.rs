@@ -0,0 +1,123 @@
+// Derived from Seaography (github.com/SeaQL/seaography)
+// Modifications Copyright (c) 2025 Stephen J. Li
+
+use heck::ToUpperCamelCase;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+
+use crate::mutate::IdentTypeTuple;
+
+/// `#[graphql_template(version)]` 標記的版本欄位，需為不可為空的整數
+pub fn version_field(fields: &syn::Fields) -> Result<Option<IdentTypeTuple>, crate::error::Error> {
+    let integer_types = ["i8", "i16", "i32", "i64", "u8", "u16", "u32", "u64"];
+
+    let field = crate::guard::flagged_field(fields, "version")?;
+
+    if let Some((ident, ty, is_option)) = &field {
+        if *is_option || !integer_types.contains(&ty.to_token_stream().to_string().as_str()) {
+            return Err(crate::error::Error::Internal(format!(
+                "version field `{}` must be a non-optional integer",
+                ident
+            )));
+        }
+    }
+
+    Ok(field)
+}
+
+/// 版本欄位的 `EntityVersion` 實作，供動態 schema 使用
+pub fn entity_version_fn(version: &Option<IdentTypeTuple>) -> TokenStream {
+    let column = match version {
+        Some((ident, _, _)) => {
+            let column = format_ident!("{}", ident.to_string().to_upper_camel_case());
+
+            quote! {
+                fn version_column() -> Option<Column> {
+                    Some(Column::#column)
+                }
+            }
+        }
+        None => quote! {},
+    };
+
+    quote! {
+        impl async_graphql_template::EntityVersion for Entity {
+            #column
+        }
+    }
+}
+
+/// 新增時的初始版本，以及依預期版本更新單筆資料
+pub fn set_version_fn(version: &Option<IdentTypeTuple>) -> TokenStream {
+    let (ident, ty) = match version {
+        Some((ident, ty, _)) => (ident, ty),
+        None => {
+            return quote! {
+                impl ActiveModel {
+                    /// 設定初始版本，沒有版本欄位時不做任何事
+                    pub fn set_initial_version(&mut self) {}
+                }
+            };
+        }
+    };
+
+    let column = format_ident!("{}", ident.to_string().to_upper_camel_case());
+
+    quote! {
+        impl ActiveModel {
+            /// 設定初始版本
+            pub fn set_initial_version(&mut self) {
+                self.#ident = sea_orm::ActiveValue::Set(Default::default());
+            }
+
+            /// 只在資料庫中的版本仍為 `expected_version` 時寫入並將版本加一，
+            /// 與 `update_many` 相同限制在同一租戶中通過 `EntityGuard` 且未軟刪除的資料
+            ///
+            /// 資料存在但版本不符時回傳 `CONFLICT`，避免同時修改時互相覆蓋；
+            /// 資料不存在或無權存取時回傳 `DbErr::RecordNotUpdated`。
+            pub async fn update_versioned(
+                mut self,
+                ctx: &async_graphql::Context<'_>,
+                expected_version: #ty,
+            ) -> async_graphql::Result<Model> {
+                use sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, Iterable, PaginatorTrait, PrimaryKeyToColumn, QueryFilter};
+
+                let db = &ctx
+                    .data::<async_graphql::dataloader::DataLoader<crate::OrmDataloader>>()?
+                    .loader()
+                    .db;
+
+                let mut condition = async_graphql_template::row_condition::<Entity>(ctx)?
+                    .add(async_graphql_template::soft_delete_condition::<Entity>(None));
+
+                // 以主鍵加上相同的限制確認資料是否存在，區分版本不符與找不到資料
+                let mut exists = condition.clone();
+
+                for key in PrimaryKey::iter() {
+                    let column = key.into_column();
+
+                    if let Some(value) = self.get(column).into_value() {
+                        exists = exists.add(column.eq(value));
+                    }
+                }
+
+                condition = condition.add(Column::#column.eq(expected_version));
+
+                self.set_updated_audit();
+                self.#ident = sea_orm::ActiveValue::Set(expected_version + 1);
+
+                match Entity::update(self).filter(condition).exec(db).await {
+                    Ok(model) => Ok(model),
+                    Err(sea_orm::DbErr::RecordNotUpdated) => {
+                        if Entity::find().filter(exists).count(db).await? > 0 {
+                            Err(async_graphql_template::version_conflict())
+                        } else {
+                            Err(sea_orm::DbErr::RecordNotUpdated.into())
+                        }
+                    }
+                    Err(err) => Err(err.into()),
+                }
+            }
+        }
+    }
+}
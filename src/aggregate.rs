@@ -0,0 +1,46 @@
+// Derived from Seaography (github.com/SeaQL/seaography)
+// Modifications Copyright (c) 2025 Stephen J. Li
+
+use sea_orm::sea_query::{Expr, Func, SimpleExpr};
+use sea_orm::DbBackend;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AggregateFunc {
+    Sum,
+    Avg,
+    Min,
+    Max,
+}
+
+/// 產生聚合運算式
+///
+/// `sum` 與 `avg` 的結果類型依後端與欄位類型而不同（如 Postgres 的 `SUM(integer)` 為 bigint、
+/// `AVG(integer)` 為 numeric），`as_double` 為真時統一轉為雙精度浮點數，方便以 `f64` 讀取。
+/// `min` 與 `max` 保留欄位本身的類型。
+pub fn aggregate_expr(
+    func: AggregateFunc,
+    column: SimpleExpr,
+    backend: DbBackend,
+    as_double: bool,
+) -> SimpleExpr {
+    let expr: SimpleExpr = match func {
+        AggregateFunc::Sum => Func::sum(column).into(),
+        AggregateFunc::Avg => Func::avg(column).into(),
+        AggregateFunc::Min => Func::min(column).into(),
+        AggregateFunc::Max => Func::max(column).into(),
+    };
+
+    if !as_double || matches!(func, AggregateFunc::Min | AggregateFunc::Max) {
+        return expr;
+    }
+
+    // cast_as 會為類型名稱加上引號，Postgres 的 "double precision" 因此無法辨識；
+    // Postgres 的佔位符為 `$n`，`?` 不會被替換
+    let template = match backend {
+        DbBackend::Postgres => "CAST($1 AS double precision)",
+        DbBackend::MySql => "CAST(? AS DOUBLE)",
+        DbBackend::Sqlite => "CAST(? AS REAL)",
+    };
+
+    Expr::cust_with_exprs(template, [expr])
+}
@@ -1,146 +1,163 @@
 // Derived from Seaography (github.com/SeaQL/seaography)
 // Modifications Copyright (c) 2025 Stephen J. Li
 
-/// 整數數組過濾器
+use sea_orm::sea_query::extension::postgres::{PgBinOper, PgExpr};
+use sea_orm::sea_query::{Alias, Expr, Func, SimpleExpr};
+use sea_orm::{ColumnTrait, Condition, DbBackend, DbErr};
+
+/// 數組過濾器
 ///
-/// 用於支持對 Vec<i32> 類型的數據進行高級過濾操作
+/// 用於支持對 Vec<T> 類型的數據進行高級過濾操作，枚舉數組由 `EnumFilter` 生成對應的結構
 #[derive(Debug, Clone, async_graphql::InputObject)]
-pub struct IntArrayFilter {
+#[graphql(concrete(name = "TinyIntArrayFilter", params(i8)))]
+#[graphql(concrete(name = "SmallIntArrayFilter", params(i16)))]
+#[graphql(concrete(name = "IntArrayFilter", params(i32)))]
+#[graphql(concrete(name = "BigIntArrayFilter", params(i64)))]
+#[graphql(concrete(name = "TinyUnsignedArrayFilter", params(u8)))]
+#[graphql(concrete(name = "SmallUnsignedArrayFilter", params(u16)))]
+#[graphql(concrete(name = "UnsignedArrayFilter", params(u32)))]
+#[graphql(concrete(name = "BigUnsignedArrayFilter", params(u64)))]
+#[graphql(concrete(name = "FloatArrayFilter", params(f32)))]
+#[graphql(concrete(name = "DoubleArrayFilter", params(f64)))]
+#[graphql(concrete(name = "BooleanArrayFilter", params(bool)))]
+#[graphql(concrete(name = "StringArrayFilter", params(String)))]
+#[cfg_attr(
+    feature = "with-chrono",
+    graphql(concrete(name = "DateArrayFilter", params(sea_orm::prelude::Date)))
+)]
+#[cfg_attr(
+    feature = "with-chrono",
+    graphql(concrete(name = "DateTimeArrayFilter", params(sea_orm::prelude::DateTime)))
+)]
+#[cfg_attr(
+    feature = "with-chrono",
+    graphql(concrete(name = "DateTimeUtcArrayFilter", params(sea_orm::prelude::DateTimeUtc)))
+)]
+#[cfg_attr(
+    feature = "with-chrono",
+    graphql(concrete(
+        name = "DateTimeWithTimeZoneArrayFilter",
+        params(sea_orm::prelude::DateTimeWithTimeZone)
+    ))
+)]
+#[cfg_attr(
+    feature = "with-decimal",
+    graphql(concrete(name = "DecimalArrayFilter", params(sea_orm::prelude::Decimal)))
+)]
+#[cfg_attr(
+    feature = "with-uuid",
+    graphql(concrete(name = "UuidArrayFilter", params(sea_orm::prelude::Uuid)))
+)]
+pub struct ArrayFilter<T: async_graphql::InputType> {
     /// 完全匹配數組
-    pub eq: Option<Vec<i32>>,
+    pub eq: Option<Vec<T>>,
     /// 不等於數組
-    pub ne: Option<Vec<i32>>,
+    pub ne: Option<Vec<T>>,
     pub is_null: Option<bool>,
 
     /// 包含所有指定元素（PostgreSQL @>）
-    pub contains: Option<Vec<i32>>,
+    pub contains: Option<Vec<T>>,
     /// 包含任意指定元素（PostgreSQL &&）
-    pub contains_any: Option<Vec<i32>>,
+    pub contains_any: Option<Vec<T>>,
+    /// 所有元素都在指定數組中（PostgreSQL <@）
+    pub contained_by: Option<Vec<T>>,
+    /// 數組長度比較
+    pub length: Option<crate::TypeFilter<i32>>,
+    /// 是否為空數組
+    pub is_empty: Option<bool>,
 }
 
-/// 字符串數組過濾器
-///
-/// 用於支持對 Vec<String> 類型的數據進行高級過濾操作
-#[derive(Debug, Clone, async_graphql::InputObject)]
-pub struct StringArrayFilter {
-    /// 完全匹配數組
-    pub eq: Option<Vec<String>>,
-    /// 不等於數組
-    pub ne: Option<Vec<String>>,
-    pub is_null: Option<bool>,
+impl<T> ArrayFilter<T>
+where
+    T: async_graphql::InputType,
+    Vec<T>: Into<sea_orm::Value>,
+{
+    /// 轉換為 `column` 的條件，與 `Filter` derive 對數組欄位產生的條件相同
+    ///
+    /// 數組運算子只有 Postgres 支援，其他後端回傳錯誤而非產生無效 SQL。
+    pub fn to_condition<C>(self, column: C, backend: DbBackend) -> Result<Condition, DbErr>
+    where
+        C: ColumnTrait,
+    {
+        if backend != DbBackend::Postgres {
+            return Err(DbErr::Custom(format!(
+                "array filter on column `{}` is only supported on Postgres, got {:?}",
+                column.as_str(),
+                backend,
+            )));
+        }
 
-    /// 包含所有指定元素（PostgreSQL @>）
-    pub contains: Option<Vec<String>>,
-    /// 包含任意指定元素（PostgreSQL &&）
-    pub contains_any: Option<Vec<String>>,
-}
+        let mut condition = Condition::all();
+        let array_column = Expr::col((column.entity_name(), column));
 
-/// 小型整數數組過濾器
-#[derive(Debug, Clone, async_graphql::InputObject)]
-pub struct TinyIntArrayFilter {
-    pub eq: Option<Vec<i8>>,
-    pub ne: Option<Vec<i8>>,
-    pub is_null: Option<bool>,
-    pub contains: Option<Vec<i8>>,
-    pub contains_any: Option<Vec<i8>>,
-}
+        if let Some(contains) = self.contains {
+            condition = condition.add(
+                array_column
+                    .clone()
+                    .contains(column.save_as(Expr::val(contains))),
+            );
+        }
 
-/// 短整數數組過濾器
-#[derive(Debug, Clone, async_graphql::InputObject)]
-pub struct SmallIntArrayFilter {
-    pub eq: Option<Vec<i16>>,
-    pub ne: Option<Vec<i16>>,
-    pub is_null: Option<bool>,
-    pub contains: Option<Vec<i16>>,
-    pub contains_any: Option<Vec<i16>>,
-}
+        if let Some(contains_any) = self.contains_any {
+            condition = condition.add(
+                array_column
+                    .clone()
+                    .binary(PgBinOper::Overlap, column.save_as(Expr::val(contains_any))),
+            );
+        }
 
-/// 大整數數組過濾器
-#[derive(Debug, Clone, async_graphql::InputObject)]
-pub struct BigIntArrayFilter {
-    pub eq: Option<Vec<i64>>,
-    pub ne: Option<Vec<i64>>,
-    pub is_null: Option<bool>,
-    pub contains: Option<Vec<i64>>,
-    pub contains_any: Option<Vec<i64>>,
-}
+        if let Some(contained_by) = self.contained_by {
+            condition = condition.add(
+                array_column
+                    .clone()
+                    .contained(column.save_as(Expr::val(contained_by))),
+            );
+        }
 
-/// 無符號整數數組過濾器
-#[derive(Debug, Clone, async_graphql::InputObject)]
-pub struct UnsignedArrayFilter {
-    pub eq: Option<Vec<u32>>,
-    pub ne: Option<Vec<u32>>,
-    pub is_null: Option<bool>,
-    pub contains: Option<Vec<u32>>,
-    pub contains_any: Option<Vec<u32>>,
-}
+        // cardinality 對空數組回傳 0，array_length 則回傳 NULL
+        let array_length: SimpleExpr = Func::cust(Alias::new("cardinality"))
+            .arg(array_column)
+            .into();
 
-/// 小型無符號整數數組過濾器
-#[derive(Debug, Clone, async_graphql::InputObject)]
-pub struct TinyUnsignedArrayFilter {
-    pub eq: Option<Vec<u8>>,
-    pub ne: Option<Vec<u8>>,
-    pub is_null: Option<bool>,
-    pub contains: Option<Vec<u8>>,
-    pub contains_any: Option<Vec<u8>>,
-}
+        if let Some(length) = self.length {
+            condition = condition.add(length.to_condition(array_length.clone()));
+        }
 
-/// 短無符號整數數組過濾器
-#[derive(Debug, Clone, async_graphql::InputObject)]
-pub struct SmallUnsignedArrayFilter {
-    pub eq: Option<Vec<u16>>,
-    pub ne: Option<Vec<u16>>,
-    pub is_null: Option<bool>,
-    pub contains: Option<Vec<u16>>,
-    pub contains_any: Option<Vec<u16>>,
-}
+        if let Some(is_empty) = self.is_empty {
+            condition = condition.add(if is_empty {
+                Expr::expr(array_length).eq(0)
+            } else {
+                Expr::expr(array_length).gt(0)
+            });
+        }
 
-/// 大無符號整數數組過濾器
-#[derive(Debug, Clone, async_graphql::InputObject)]
-pub struct BigUnsignedArrayFilter {
-    pub eq: Option<Vec<u64>>,
-    pub ne: Option<Vec<u64>>,
-    pub is_null: Option<bool>,
-    pub contains: Option<Vec<u64>>,
-    pub contains_any: Option<Vec<u64>>,
-}
+        if let Some(eq) = self.eq {
+            condition = condition.add(column.eq(eq));
+        }
 
-/// 浮點數數組過濾器
-#[derive(Debug, Clone, async_graphql::InputObject)]
-pub struct FloatArrayFilter {
-    pub eq: Option<Vec<f32>>,
-    pub ne: Option<Vec<f32>>,
-    pub is_null: Option<bool>,
-    pub contains: Option<Vec<f32>>,
-    pub contains_any: Option<Vec<f32>>,
-}
+        if let Some(ne) = self.ne {
+            condition = condition.add(column.ne(ne));
+        }
 
-/// 雙精度浮點數數組過濾器
-#[derive(Debug, Clone, async_graphql::InputObject)]
-pub struct DoubleArrayFilter {
-    pub eq: Option<Vec<f64>>,
-    pub ne: Option<Vec<f64>>,
-    pub is_null: Option<bool>,
-    pub contains: Option<Vec<f64>>,
-    pub contains_any: Option<Vec<f64>>,
-}
+        if let Some(true) = self.is_null {
+            condition = condition.add(column.is_null());
+        }
 
-/// 布爾值數組過濾器
-#[derive(Debug, Clone, async_graphql::InputObject)]
-pub struct BooleanArrayFilter {
-    pub eq: Option<Vec<bool>>,
-    pub ne: Option<Vec<bool>>,
-    pub is_null: Option<bool>,
-    pub contains: Option<Vec<bool>>,
-    pub contains_any: Option<Vec<bool>>,
+        Ok(condition)
+    }
 }
 
+pub type TinyIntArrayFilter = ArrayFilter<i8>;
+pub type SmallIntArrayFilter = ArrayFilter<i16>;
+pub type IntArrayFilter = ArrayFilter<i32>;
+pub type BigIntArrayFilter = ArrayFilter<i64>;
+pub type TinyUnsignedArrayFilter = ArrayFilter<u8>;
+pub type SmallUnsignedArrayFilter = ArrayFilter<u16>;
+pub type UnsignedArrayFilter = ArrayFilter<u32>;
+pub type BigUnsignedArrayFilter = ArrayFilter<u64>;
+pub type FloatArrayFilter = ArrayFilter<f32>;
+pub type DoubleArrayFilter = ArrayFilter<f64>;
+pub type BooleanArrayFilter = ArrayFilter<bool>;
+pub type StringArrayFilter = ArrayFilter<String>;
 #[cfg(feature = "with-uuid")]
-#[derive(Debug, Clone, async_graphql::InputObject)]
-pub struct UuidArrayFilter {
-    pub eq: Option<Vec<sea_orm::prelude::Uuid>>,
-    pub ne: Option<Vec<sea_orm::prelude::Uuid>>,
-    pub is_null: Option<bool>,
-    pub contains: Option<Vec<sea_orm::prelude::Uuid>>,
-    pub contains_any: Option<Vec<sea_orm::prelude::Uuid>>,
-}
+pub type UuidArrayFilter = ArrayFilter<sea_orm::prelude::Uuid>;
@@ -0,0 +1,168 @@
+// Derived from Seaography (github.com/SeaQL/seaography)
+// Modifications Copyright (c) 2025 Stephen J. Li
+
+use sea_orm::sea_query::{Alias, Expr, Func, SimpleExpr};
+use sea_orm::{ColumnTrait, ColumnType, ColumnTypeTrait, DbErr, IntoSimpleExpr};
+
+/// 數組修改操作
+///
+/// 依 `set`、`remove`、`push`、`addToSet` 的順序套用。透過 `to_expr` 產生的運算式
+/// 在資料庫內完成修改，同時編輯同一數組時不會遺失寫入。
+#[derive(Debug, Clone, async_graphql::InputObject)]
+#[graphql(concrete(name = "TinyIntArrayMutation", params(i8)))]
+#[graphql(concrete(name = "SmallIntArrayMutation", params(i16)))]
+#[graphql(concrete(name = "IntArrayMutation", params(i32)))]
+#[graphql(concrete(name = "BigIntArrayMutation", params(i64)))]
+#[graphql(concrete(name = "TinyUnsignedArrayMutation", params(u8)))]
+#[graphql(concrete(name = "SmallUnsignedArrayMutation", params(u16)))]
+#[graphql(concrete(name = "UnsignedArrayMutation", params(u32)))]
+#[graphql(concrete(name = "BigUnsignedArrayMutation", params(u64)))]
+#[graphql(concrete(name = "FloatArrayMutation", params(f32)))]
+#[graphql(concrete(name = "DoubleArrayMutation", params(f64)))]
+#[graphql(concrete(name = "BooleanArrayMutation", params(bool)))]
+#[graphql(concrete(name = "StringArrayMutation", params(String)))]
+#[cfg_attr(
+    feature = "with-chrono",
+    graphql(concrete(name = "DateArrayMutation", params(sea_orm::prelude::Date)))
+)]
+#[cfg_attr(
+    feature = "with-chrono",
+    graphql(concrete(name = "DateTimeArrayMutation", params(sea_orm::prelude::DateTime)))
+)]
+#[cfg_attr(
+    feature = "with-chrono",
+    graphql(concrete(
+        name = "DateTimeUtcArrayMutation",
+        params(sea_orm::prelude::DateTimeUtc)
+    ))
+)]
+#[cfg_attr(
+    feature = "with-chrono",
+    graphql(concrete(
+        name = "DateTimeWithTimeZoneArrayMutation",
+        params(sea_orm::prelude::DateTimeWithTimeZone)
+    ))
+)]
+#[cfg_attr(
+    feature = "with-decimal",
+    graphql(concrete(name = "DecimalArrayMutation", params(sea_orm::prelude::Decimal)))
+)]
+#[cfg_attr(
+    feature = "with-uuid",
+    graphql(concrete(name = "UuidArrayMutation", params(sea_orm::prelude::Uuid)))
+)]
+pub struct ArrayMutation<T: async_graphql::InputType> {
+    /// 以新數組整個覆蓋舊值
+    pub set: Option<Vec<T>>,
+    /// 在尾端加入元素（array_cat）
+    pub push: Option<Vec<T>>,
+    /// 移除所有相同的元素（array_remove）
+    pub remove: Option<Vec<T>>,
+    /// 只加入尚未存在的元素
+    pub add_to_set: Option<Vec<T>>,
+}
+
+impl<T> ArrayMutation<T>
+where
+    T: async_graphql::InputType + PartialEq + Clone + Into<sea_orm::Value>,
+    Vec<T>: Into<sea_orm::Value>,
+{
+    /// 在記憶體中套用到目前的值，供 `ActiveModel` 使用
+    pub fn apply(self, current: Vec<T>) -> Vec<T> {
+        let mut value = self.set.unwrap_or(current);
+
+        if let Some(remove) = self.remove {
+            value.retain(|item| !remove.contains(item));
+        }
+
+        if let Some(push) = self.push {
+            value.extend(push);
+        }
+
+        if let Some(add_to_set) = self.add_to_set {
+            for item in add_to_set {
+                if !value.contains(&item) {
+                    value.push(item);
+                }
+            }
+        }
+
+        value
+    }
+
+    /// 套用到 `ActiveModel` 中已載入的值，`current` 為 `None` 表示欄位未載入
+    ///
+    /// 未載入時只有 `set` 能得到確定的結果，其餘操作回傳錯誤，避免以空數組覆蓋資料庫中的值。
+    pub fn apply_loaded(self, column: &str, current: Option<Vec<T>>) -> Result<Vec<T>, DbErr> {
+        match current {
+            Some(current) => Ok(self.apply(current)),
+            None if self.set.is_some() => Ok(self.apply(Vec::new())),
+            None => Err(DbErr::Custom(format!(
+                "array mutation on column `{}` needs the current value; load the model, use `set` or `update_many`",
+                column
+            ))),
+        }
+    }
+
+    /// 產生 Postgres 的更新運算式，用於 `UpdateMany::col_expr`
+    pub fn to_expr<C>(self, column: C) -> SimpleExpr
+    where
+        C: ColumnTrait,
+    {
+        let mut expr = match self.set {
+            Some(set) => column.save_as(Expr::val(set)),
+            None => column.into_simple_expr(),
+        };
+
+        if let Some(remove) = self.remove {
+            expr = remove.into_iter().fold(expr, |expr, item| {
+                Func::cust(Alias::new("array_remove"))
+                    .arg(expr)
+                    .arg(element_expr(&column, item))
+                    .into()
+            });
+        }
+
+        if let Some(push) = self.push {
+            expr = Func::cust(Alias::new("array_cat"))
+                .arg(expr)
+                .arg(column.save_as(Expr::val(push)))
+                .into();
+        }
+
+        if let Some(add_to_set) = self.add_to_set {
+            let mut items: Vec<T> = Vec::new();
+            for item in add_to_set {
+                if !items.contains(&item) {
+                    items.push(item);
+                }
+            }
+
+            // 只串接目前數組中不存在的元素；COALESCE 避免 NULL 數組使比較結果為 NULL
+            expr = Func::cust(Alias::new("array_cat"))
+                .arg(expr.clone())
+                .arg(Expr::cust_with_exprs(
+                    "ARRAY(SELECT item FROM unnest($1) AS item WHERE NOT (item = ANY(COALESCE($2, '{}'))))",
+                    [column.save_as(Expr::val(items)), expr],
+                ))
+                .into();
+        }
+
+        expr
+    }
+}
+
+/// 枚舉數組的單一元素需轉型為枚舉類型，否則 Postgres 會以 text 比較
+fn element_expr<C, T>(column: &C, item: T) -> SimpleExpr
+where
+    C: ColumnTrait,
+    T: Into<sea_orm::Value>,
+{
+    match column.def().get_column_type() {
+        ColumnType::Array(element_type) => match element_type.get_enum_name() {
+            Some(enum_name) => Expr::val(item).as_enum(enum_name.clone()),
+            None => Expr::val(item).into(),
+        },
+        _ => Expr::val(item).into(),
+    }
+}
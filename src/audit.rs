@@ -0,0 +1,57 @@
+// Derived from Seaography (github.com/SeaQL/seaography)
+// Modifications Copyright (c) 2025 Stephen J. Li
+
+/// 目前請求的使用者，以 `Request::data` 放入 `Context`
+///
+/// 新增資料時寫入 `#[graphql_template(created_by)]` 欄位，類型需與該欄位去掉 `Option`
+/// 後的類型相同，缺少時新增會回傳錯誤。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Actor<T>(pub T);
+
+/// 可由伺服器時鐘取得目前時間的類型，用於 `created_at` 與 `updated_at` 欄位
+pub trait AuditTimestamp {
+    fn now() -> Self;
+}
+
+#[cfg(feature = "with-chrono")]
+impl AuditTimestamp for sea_orm::prelude::DateTimeUtc {
+    fn now() -> Self {
+        std::time::SystemTime::now().into()
+    }
+}
+
+#[cfg(feature = "with-chrono")]
+impl AuditTimestamp for sea_orm::prelude::DateTime {
+    fn now() -> Self {
+        <sea_orm::prelude::DateTimeUtc as AuditTimestamp>::now().naive_utc()
+    }
+}
+
+#[cfg(feature = "with-chrono")]
+impl AuditTimestamp for sea_orm::prelude::DateTimeWithTimeZone {
+    fn now() -> Self {
+        <sea_orm::prelude::DateTimeUtc as AuditTimestamp>::now().into()
+    }
+}
+
+#[cfg(feature = "with-chrono")]
+impl AuditTimestamp for sea_orm::prelude::DateTimeLocal {
+    fn now() -> Self {
+        std::time::SystemTime::now().into()
+    }
+}
+
+/// 由伺服器填入的稽核欄位
+///
+/// `Filter` derive 依 `Model` 上的 `#[graphql_template(created_at)]`、`updated_at`
+/// 與 `created_by` 欄位產生實作，這些欄位不出現在 `Mutant` 中。
+pub trait EntityAudit: sea_orm::EntityTrait {
+    fn audit_columns() -> Vec<Self::Column> {
+        Vec::new()
+    }
+
+    /// 批次更新時寫入 `updated_at`，沒有該欄位時不修改
+    fn set_updated_at(stmt: sea_orm::UpdateMany<Self>) -> sea_orm::UpdateMany<Self> {
+        stmt
+    }
+}
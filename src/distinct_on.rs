@@ -0,0 +1,90 @@
+// Derived from Seaography (github.com/SeaQL/seaography)
+// Modifications Copyright (c) 2025 Stephen J. Li
+
+use sea_orm::sea_query::{Alias, Expr, Func, OverStatement, Query, WindowStatement};
+use sea_orm::{
+    Condition, DbBackend, DbErr, EntityTrait, IdenStatic, Iterable, PrimaryKeyToColumn,
+    QueryFilter, QuerySelect, Select,
+};
+
+use crate::OrderByEnum;
+
+/// 每組 `columns` 只保留依 `order_by` 排序後的第一筆
+///
+/// Postgres 使用 `DISTINCT ON`，其他後端以 `ROW_NUMBER()` 視窗函數在子查詢中排名，
+/// 再以主鍵篩選第一名。`condition` 需與主查詢的過濾條件相同。
+/// 與 Postgres 的規則一致，`order_by` 開頭的欄位必須都在 `columns` 之中。
+pub fn distinct_on<E>(
+    stmt: Select<E>,
+    columns: Vec<E::Column>,
+    condition: Condition,
+    order_by: &[(E::Column, OrderByEnum)],
+    backend: DbBackend,
+) -> Result<Select<E>, DbErr>
+where
+    E: EntityTrait,
+{
+    if columns.is_empty() {
+        return Ok(stmt);
+    }
+
+    if let Some((column, _)) = order_by.iter().take(columns.len()).find(|(column, _)| {
+        !columns
+            .iter()
+            .any(|distinct| distinct.as_str() == column.as_str())
+    }) {
+        return Err(DbErr::Custom(format!(
+            "distinctOn columns must match the leading orderBy columns, got {:?}",
+            column,
+        )));
+    }
+
+    if backend == DbBackend::Postgres {
+        return Ok(stmt.distinct_on(columns.into_iter().map(|column| (E::default(), column))));
+    }
+
+    let mut window = WindowStatement::new();
+
+    for column in columns {
+        window.add_partition_by(Expr::col((E::default(), column)).into());
+    }
+
+    for (column, direction) in order_by {
+        let column = Expr::col((E::default(), *column));
+
+        match direction.nulls() {
+            Some(nulls) => window.order_by_expr_with_nulls(column.into(), direction.order(), nulls),
+            None => window.order_by_expr(column.into(), direction.order()),
+        };
+    }
+
+    let primary_keys: Vec<E::Column> = E::PrimaryKey::iter()
+        .map(|primary_key| primary_key.into_column())
+        .collect();
+
+    let mut ranked = Query::select();
+    ranked
+        .columns(primary_keys.iter().map(|column| (E::default(), *column)))
+        .expr_window_as(
+            Func::cust(Alias::new("ROW_NUMBER")),
+            window,
+            Alias::new("distinct_on_rank"),
+        )
+        .from(E::default())
+        .cond_where(condition);
+
+    let mut first = Query::select();
+    first
+        .columns(primary_keys.iter().copied())
+        .from_subquery(ranked, Alias::new("distinct_on_ranked"))
+        .and_where(Expr::col(Alias::new("distinct_on_rank")).eq(1));
+
+    Ok(stmt.filter(
+        Expr::tuple(
+            primary_keys
+                .iter()
+                .map(|column| Expr::col((E::default(), *column)).into()),
+        )
+        .in_subquery(first),
+    ))
+}
@@ -0,0 +1,63 @@
+// Derived from Seaography (github.com/SeaQL/seaography)
+// Modifications Copyright (c) 2025 Stephen J. Li
+
+use std::hash::{Hash, Hasher};
+
+use sea_orm::Condition;
+
+/// 依呼叫者限制實體可存取的資料列
+///
+/// 回傳的條件以 AND 加在根查詢、關聯與單筆查詢的 DataLoader、聚合、`update_many` 與
+/// `delete_many` 上。`Filter` derive 預設產生不限制的實作；在 `Model` 加上
+/// `#[graphql_template(guard)]` 後改由使用者實作：
+///
+/// ```ignore
+/// impl EntityGuard for Entity {
+///     fn guard(ctx: &async_graphql::Context<'_>) -> async_graphql::Result<Condition> {
+///         let user = ctx.data::<CurrentUser>()?;
+///
+///         Ok(Condition::all().add(Column::OwnerId.eq(user.id)))
+///     }
+/// }
+/// ```
+pub trait EntityGuard: sea_orm::EntityTrait {
+    fn guard(ctx: &async_graphql::Context<'_>) -> async_graphql::Result<Condition> {
+        let _ = ctx;
+
+        Ok(Condition::all())
+    }
+}
+
+/// 作為 DataLoader 鍵一部分的守衛條件
+///
+/// DataLoader 在所有請求間共用，不同呼叫者的鍵以條件區分後分別查詢。
+#[derive(Debug, Clone, PartialEq)]
+pub struct GuardCondition(pub Condition);
+
+impl Eq for GuardCondition {}
+
+impl Hash for GuardCondition {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Condition 沒有實作 Hash，與 RelationKeyStruct 相同以 Debug 輸出計算
+        format!("{:?}", self.0).hash(state)
+    }
+}
+
+/// 依守衛條件將 DataLoader 的鍵分組，相同條件的鍵以一條 SQL 查詢
+pub fn group_by_guard<K>(
+    keys: &[K],
+    guard: impl Fn(&K) -> &GuardCondition,
+) -> Vec<(GuardCondition, Vec<&K>)> {
+    let mut groups: Vec<(GuardCondition, Vec<&K>)> = Vec::new();
+
+    for key in keys.iter() {
+        let condition = guard(key);
+
+        match groups.iter_mut().find(|(group, _)| group == condition) {
+            Some((_, keys)) => keys.push(key),
+            None => groups.push((condition.clone(), vec![key])),
+        }
+    }
+
+    groups
+}
@@ -0,0 +1,434 @@
+// Derived from Seaography (github.com/SeaQL/seaography)
+// Modifications Copyright (c) 2025 Stephen J. Li
+
+use sea_orm::prelude::Json;
+use sea_orm::sea_query::{Alias, Expr, Func, IntoCondition, SimpleExpr};
+use sea_orm::{Condition, DbBackend, DbErr, IntoSimpleExpr};
+
+/// JSON 欄位過濾器
+///
+/// 指定 `path` 時，所有運算子作用在該路徑取出的值上；否則作用在整個文件上。
+/// Postgres 使用 JSONB 運算子，MySQL / SQLite 使用 `JSON_EXTRACT` 系列函數。
+#[derive(Debug, Clone, async_graphql::InputObject)]
+pub struct JsonFilter {
+    /// 要比較的 JSON 路徑，例如 `["address", "city"]` 或 `["tags", "[0]"]`
+    ///
+    /// 只有 `[0]` 形式的片段是陣列索引，其他片段（包含 `"2024"` 等純數字）一律是物件鍵
+    pub path: Option<Vec<String>>,
+
+    pub eq: Option<Json>,
+    pub ne: Option<Json>,
+    pub gt: Option<Json>,
+    pub gte: Option<Json>,
+    pub lt: Option<Json>,
+    pub lte: Option<Json>,
+    pub is_in: Option<Vec<Json>>,
+    pub is_not_in: Option<Vec<Json>>,
+    pub is_null: Option<bool>,
+
+    /// 物件包含指定鍵（PostgreSQL ?）
+    pub has_key: Option<String>,
+    /// 物件包含所有指定鍵（PostgreSQL ?&）
+    pub has_keys: Option<Vec<String>>,
+    /// 包含指定的 JSON（PostgreSQL @>）
+    pub contains: Option<Json>,
+    /// 被指定的 JSON 包含（PostgreSQL <@）
+    pub contained_by: Option<Json>,
+}
+
+impl JsonFilter {
+    pub fn to_condition<C>(&self, column: C, backend: DbBackend) -> Result<Condition, DbErr>
+    where
+        C: IntoSimpleExpr,
+    {
+        let column = column.into_simple_expr();
+        let path = self.path.clone().unwrap_or_default();
+
+        let mut condition = Condition::all();
+
+        match backend {
+            DbBackend::Postgres => {
+                use sea_orm::sea_query::extension::postgres::PgExpr;
+
+                let target = pg_target(column, &path);
+
+                if let Some(eq_value) = &self.eq {
+                    condition = condition.add(Expr::expr(target.clone()).eq(pg_jsonb(eq_value)));
+                }
+
+                if let Some(ne_value) = &self.ne {
+                    condition = condition.add(Expr::expr(target.clone()).ne(pg_jsonb(ne_value)));
+                }
+
+                if let Some(gt_value) = &self.gt {
+                    condition = condition.add(Expr::expr(target.clone()).gt(pg_jsonb(gt_value)));
+                }
+
+                if let Some(gte_value) = &self.gte {
+                    condition = condition.add(Expr::expr(target.clone()).gte(pg_jsonb(gte_value)));
+                }
+
+                if let Some(lt_value) = &self.lt {
+                    condition = condition.add(Expr::expr(target.clone()).lt(pg_jsonb(lt_value)));
+                }
+
+                if let Some(lte_value) = &self.lte {
+                    condition = condition.add(Expr::expr(target.clone()).lte(pg_jsonb(lte_value)));
+                }
+
+                if let Some(is_in_value) = &self.is_in {
+                    condition = condition
+                        .add(Expr::expr(target.clone()).is_in(is_in_value.iter().map(pg_jsonb)));
+                }
+
+                if let Some(is_not_in_value) = &self.is_not_in {
+                    condition = condition.add(
+                        Expr::expr(target.clone()).is_not_in(is_not_in_value.iter().map(pg_jsonb)),
+                    );
+                }
+
+                if let Some(has_key_value) = &self.has_key {
+                    condition = condition.add(pg_has_key(target.clone(), has_key_value));
+                }
+
+                if let Some(has_keys_value) = &self.has_keys {
+                    condition = has_keys_value.iter().fold(condition, |condition, key| {
+                        condition.add(pg_has_key(target.clone(), key))
+                    });
+                }
+
+                if let Some(contains_value) = &self.contains {
+                    condition = condition
+                        .add(Expr::expr(target.clone()).contains(pg_jsonb(contains_value)));
+                }
+
+                if let Some(contained_by_value) = &self.contained_by {
+                    condition = condition
+                        .add(Expr::expr(target.clone()).contained(pg_jsonb(contained_by_value)));
+                }
+
+                if let Some(is_null_value) = self.is_null {
+                    if is_null_value {
+                        condition = condition.add(Expr::expr(target).is_null());
+                    }
+                }
+            }
+            DbBackend::MySql => {
+                let target = if path.is_empty() {
+                    column.clone()
+                } else {
+                    Func::cust(Alias::new("JSON_EXTRACT"))
+                        .arg(column.clone())
+                        .arg(json_path(&path))
+                        .into()
+                };
+
+                if let Some(eq_value) = &self.eq {
+                    condition = condition.add(Expr::expr(target.clone()).eq(mysql_json(eq_value)));
+                }
+
+                if let Some(ne_value) = &self.ne {
+                    condition = condition.add(Expr::expr(target.clone()).ne(mysql_json(ne_value)));
+                }
+
+                if let Some(gt_value) = &self.gt {
+                    condition = condition.add(Expr::expr(target.clone()).gt(mysql_json(gt_value)));
+                }
+
+                if let Some(gte_value) = &self.gte {
+                    condition =
+                        condition.add(Expr::expr(target.clone()).gte(mysql_json(gte_value)));
+                }
+
+                if let Some(lt_value) = &self.lt {
+                    condition = condition.add(Expr::expr(target.clone()).lt(mysql_json(lt_value)));
+                }
+
+                if let Some(lte_value) = &self.lte {
+                    condition =
+                        condition.add(Expr::expr(target.clone()).lte(mysql_json(lte_value)));
+                }
+
+                if let Some(is_in_value) = &self.is_in {
+                    condition = condition
+                        .add(Expr::expr(target.clone()).is_in(is_in_value.iter().map(mysql_json)));
+                }
+
+                if let Some(is_not_in_value) = &self.is_not_in {
+                    condition = condition.add(
+                        Expr::expr(target.clone())
+                            .is_not_in(is_not_in_value.iter().map(mysql_json)),
+                    );
+                }
+
+                if let Some(has_key_value) = &self.has_key {
+                    condition = condition.add(mysql_has_keys(
+                        column.clone(),
+                        &path,
+                        std::slice::from_ref(has_key_value),
+                    ));
+                }
+
+                if let Some(has_keys_value) = &self.has_keys {
+                    condition =
+                        condition.add(mysql_has_keys(column.clone(), &path, has_keys_value));
+                }
+
+                if let Some(contains_value) = &self.contains {
+                    condition = condition.add(SimpleExpr::from(
+                        Func::cust(Alias::new("JSON_CONTAINS"))
+                            .arg(target.clone())
+                            .arg(mysql_json(contains_value)),
+                    ));
+                }
+
+                if let Some(contained_by_value) = &self.contained_by {
+                    condition = condition.add(SimpleExpr::from(
+                        Func::cust(Alias::new("JSON_CONTAINS"))
+                            .arg(mysql_json(contained_by_value))
+                            .arg(target.clone()),
+                    ));
+                }
+
+                if let Some(is_null_value) = self.is_null {
+                    if is_null_value {
+                        condition = condition.add(Expr::expr(target).is_null());
+                    }
+                }
+            }
+            DbBackend::Sqlite => {
+                // SQLite 的 json_extract 回傳 SQL 純量，比較時需轉為對應的 SQL 值
+                let target = sqlite_extract(&column, &path);
+
+                if let Some(eq_value) = &self.eq {
+                    condition = condition.add(sqlite_eq(&column, &path, eq_value));
+                }
+
+                if let Some(ne_value) = &self.ne {
+                    condition = condition.add(sqlite_ne(&column, &path, ne_value));
+                }
+
+                if let Some(gt_value) = &self.gt {
+                    condition =
+                        condition.add(Expr::expr(target.clone()).gt(sqlite_scalar(gt_value)));
+                }
+
+                if let Some(gte_value) = &self.gte {
+                    condition =
+                        condition.add(Expr::expr(target.clone()).gte(sqlite_scalar(gte_value)));
+                }
+
+                if let Some(lt_value) = &self.lt {
+                    condition =
+                        condition.add(Expr::expr(target.clone()).lt(sqlite_scalar(lt_value)));
+                }
+
+                if let Some(lte_value) = &self.lte {
+                    condition =
+                        condition.add(Expr::expr(target.clone()).lte(sqlite_scalar(lte_value)));
+                }
+
+                if let Some(is_in_value) = &self.is_in {
+                    condition = condition.add(
+                        is_in_value
+                            .iter()
+                            .fold(Condition::any(), |condition, value| {
+                                condition.add(sqlite_eq(&column, &path, value))
+                            }),
+                    );
+                }
+
+                if let Some(is_not_in_value) = &self.is_not_in {
+                    condition = is_not_in_value.iter().fold(condition, |condition, value| {
+                        condition.add(sqlite_ne(&column, &path, value))
+                    });
+                }
+
+                if let Some(has_key_value) = &self.has_key {
+                    condition = condition.add(sqlite_has_key(column.clone(), &path, has_key_value));
+                }
+
+                if let Some(has_keys_value) = &self.has_keys {
+                    condition = has_keys_value.iter().fold(condition, |condition, key| {
+                        condition.add(sqlite_has_key(column.clone(), &path, key))
+                    });
+                }
+
+                if let Some(contains_value) = &self.contains {
+                    condition = condition.add(sqlite_contains(&column, &path, contains_value));
+                }
+
+                if self.contained_by.is_some() {
+                    return Err(DbErr::Custom(
+                        "JsonFilter.containedBy is not supported on SQLite".into(),
+                    ));
+                }
+
+                if let Some(is_null_value) = self.is_null {
+                    if is_null_value {
+                        condition = condition.add(Expr::expr(target).is_null());
+                    }
+                }
+            }
+        }
+
+        Ok(condition)
+    }
+}
+
+/// 轉為 MySQL / SQLite 的 JSON 路徑字串，例如 `$."address"."city"` 或 `$."tags"[0]`
+///
+/// 片段的寫法與 `JsonFilter::path` 相同，`[0]` 為陣列索引，其他片段為加上引號的物件鍵
+pub fn json_path(path: &[String]) -> String {
+    path.iter().fold("$".to_owned(), |json_path, segment| {
+        match array_index(segment) {
+            Some(index) => format!("{}[{}]", json_path, index),
+            None => format!(
+                "{}.\"{}\"",
+                json_path,
+                segment.replace('\\', "\\\\").replace('"', "\\\"")
+            ),
+        }
+    })
+}
+
+/// `[0]` 形式的路徑片段中的陣列索引
+fn array_index(segment: &str) -> Option<&str> {
+    segment
+        .strip_prefix('[')?
+        .strip_suffix(']')
+        .filter(|index| !index.is_empty() && index.chars().all(|c| c.is_ascii_digit()))
+}
+
+fn pg_target(column: SimpleExpr, path: &[String]) -> SimpleExpr {
+    let document = Expr::expr(column).cast_as(Alias::new("jsonb"));
+
+    if path.is_empty() {
+        document
+    } else {
+        path.iter()
+            .fold(
+                Func::cust(Alias::new("jsonb_extract_path")).arg(document),
+                // jsonb_extract_path 依值的類型將文字片段視為鍵或索引
+                |func, segment| func.arg(array_index(segment).unwrap_or(segment)),
+            )
+            .into()
+    }
+}
+
+fn pg_jsonb(value: &Json) -> SimpleExpr {
+    Expr::val(value.clone()).cast_as(Alias::new("jsonb"))
+}
+
+fn pg_has_key(target: SimpleExpr, key: &str) -> SimpleExpr {
+    Func::cust(Alias::new("jsonb_exists"))
+        .arg(target)
+        .arg(key)
+        .into()
+}
+
+fn mysql_json(value: &Json) -> SimpleExpr {
+    Expr::val(value.to_string()).cast_as(Alias::new("JSON"))
+}
+
+fn mysql_has_keys(column: SimpleExpr, path: &[String], keys: &[String]) -> SimpleExpr {
+    keys.iter()
+        .fold(
+            Func::cust(Alias::new("JSON_CONTAINS_PATH"))
+                .arg(column)
+                .arg("all"),
+            |func, key| {
+                let mut key_path = path.to_vec();
+                key_path.push(key.clone());
+                func.arg(json_path(&key_path))
+            },
+        )
+        .into()
+}
+
+fn sqlite_has_key(column: SimpleExpr, path: &[String], key: &str) -> SimpleExpr {
+    let mut key_path = path.to_vec();
+    key_path.push(key.to_owned());
+
+    Expr::expr(
+        Func::cust(Alias::new("json_type"))
+            .arg(column)
+            .arg(json_path(&key_path)),
+    )
+    .is_not_null()
+}
+
+/// SQLite 沒有 JSON 包含運算子，物件依鍵遞迴比對，陣列以 json_each 檢查每個元素
+fn sqlite_contains(column: &SimpleExpr, path: &[String], value: &Json) -> Condition {
+    match value {
+        Json::Object(object) => object
+            .iter()
+            .fold(Condition::all(), |condition, (key, value)| {
+                let mut key_path = path.to_vec();
+                key_path.push(key.clone());
+                condition.add(sqlite_contains(column, &key_path, value))
+            }),
+        Json::Array(array) => array.iter().fold(Condition::all(), |condition, item| {
+            condition.add(match item {
+                Json::Null => Expr::cust_with_exprs(
+                    "EXISTS (SELECT 1 FROM json_each(?, ?) WHERE json_each.type = 'null')",
+                    [column.clone(), Expr::val(json_path(path)).into()],
+                ),
+                _ => Expr::cust_with_exprs(
+                    "EXISTS (SELECT 1 FROM json_each(?, ?) WHERE json_each.value = ?)",
+                    [
+                        column.clone(),
+                        Expr::val(json_path(path)).into(),
+                        sqlite_scalar(item),
+                    ],
+                ),
+            })
+        }),
+        _ => sqlite_eq(column, path, value).into_condition(),
+    }
+}
+
+/// json_extract 將 JSON null 與不存在的路徑都轉為 SQL NULL，比較 null 時改用 json_type 區分
+fn sqlite_eq(column: &SimpleExpr, path: &[String], value: &Json) -> SimpleExpr {
+    match value {
+        Json::Null => Expr::expr(sqlite_json_type(column, path)).eq("null"),
+        _ => Expr::expr(sqlite_extract(column, path)).eq(sqlite_scalar(value)),
+    }
+}
+
+fn sqlite_ne(column: &SimpleExpr, path: &[String], value: &Json) -> SimpleExpr {
+    match value {
+        Json::Null => Expr::expr(sqlite_json_type(column, path)).ne("null"),
+        _ => Expr::expr(sqlite_extract(column, path)).ne(sqlite_scalar(value)),
+    }
+}
+
+fn sqlite_extract(column: &SimpleExpr, path: &[String]) -> SimpleExpr {
+    Func::cust(Alias::new("json_extract"))
+        .arg(column.clone())
+        .arg(json_path(path))
+        .into()
+}
+
+fn sqlite_json_type(column: &SimpleExpr, path: &[String]) -> SimpleExpr {
+    Func::cust(Alias::new("json_type"))
+        .arg(column.clone())
+        .arg(json_path(path))
+        .into()
+}
+
+/// 轉為 json_extract 回傳的 SQL 純量；null 只用於大小比較，結果與 SQL 相同不會成立
+fn sqlite_scalar(value: &Json) -> SimpleExpr {
+    match value {
+        Json::Null => Expr::cust("NULL"),
+        Json::Bool(value) => Expr::val(*value as i32).into(),
+        Json::Number(value) => match value.as_i64() {
+            Some(value) => Expr::val(value).into(),
+            None => Expr::val(value.as_f64()).into(),
+        },
+        Json::String(value) => Expr::val(value.as_str()).into(),
+        Json::Array(_) | Json::Object(_) => {
+            Func::cust(Alias::new("json")).arg(value.to_string()).into()
+        }
+    }
+}
@@ -142,63 +142,356 @@ use itertools::Itertools;
 mod array_filter;
 pub use array_filter::*;
 
+mod array_mutation;
+pub use array_mutation::*;
+
+mod numeric_mutation;
+pub use numeric_mutation::*;
+
+mod aggregate;
+pub use aggregate::*;
+
+mod distinct_on;
+pub use distinct_on::*;
+
+mod guard;
+pub use guard::*;
+
+mod tenant;
+pub use tenant::*;
+
+mod soft_delete;
+pub use soft_delete::*;
+
+mod audit;
+pub use audit::*;
+
+mod version;
+pub use version::*;
+
+mod output;
+pub use output::*;
+
+mod schema_builder;
+pub use schema_builder::*;
+
+#[cfg(feature = "with-json")]
+mod json_filter;
+#[cfg(feature = "with-json")]
+pub use json_filter::*;
+
+#[cfg(feature = "with-chrono")]
+mod temporal_filter;
+#[cfg(feature = "with-chrono")]
+pub use temporal_filter::*;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, async_graphql::Enum)]
 pub enum OrderByEnum {
     Asc,
     Desc,
+    AscNullsFirst,
+    AscNullsLast,
+    DescNullsFirst,
+    DescNullsLast,
 }
 
+impl OrderByEnum {
+    pub fn order(self) -> sea_orm::query::Order {
+        match self {
+            OrderByEnum::Asc | OrderByEnum::AscNullsFirst | OrderByEnum::AscNullsLast => {
+                sea_orm::query::Order::Asc
+            }
+            OrderByEnum::Desc | OrderByEnum::DescNullsFirst | OrderByEnum::DescNullsLast => {
+                sea_orm::query::Order::Desc
+            }
+        }
+    }
+
+    /// 未指定時沿用資料庫預設的 NULL 位置；MySQL 不支持 NULLS FIRST/LAST，
+    /// 由 sea-query 以 `col IS NULL` 排序鍵模擬
+    pub fn nulls(self) -> Option<sea_orm::sea_query::NullOrdering> {
+        match self {
+            OrderByEnum::Asc | OrderByEnum::Desc => None,
+            OrderByEnum::AscNullsFirst | OrderByEnum::DescNullsFirst => {
+                Some(sea_orm::sea_query::NullOrdering::First)
+            }
+            OrderByEnum::AscNullsLast | OrderByEnum::DescNullsLast => {
+                Some(sea_orm::sea_query::NullOrdering::Last)
+            }
+        }
+    }
+
+    /// 以此方向依 `expr` 排序
+    pub fn order_by<S, E>(self, stmt: S, expr: E) -> S
+    where
+        S: sea_orm::QueryOrder,
+        E: Into<sea_orm::sea_query::SimpleExpr>,
+    {
+        match self.nulls() {
+            Some(nulls) => stmt.order_by_with_nulls(expr.into(), self.order(), nulls),
+            None => stmt.order_by(expr.into(), self.order()),
+        }
+    }
+}
+
+/// 記錄客戶端書寫欄位順序的輸入物件
+///
+/// GraphQL 的輸入物件解析後沒有欄位順序，`OrderBy` 以 `keys` 決定同一物件中的排序優先級。
+/// 在 schema 中與 `T` 相同；由 Rust 建立時 `keys` 為空，改用欄位宣告順序。
+#[derive(Debug, Clone)]
+pub struct Ordered<T> {
+    pub value: T,
+    pub keys: Vec<String>,
+}
+
+impl<T> From<T> for Ordered<T> {
+    fn from(value: T) -> Self {
+        Self {
+            value,
+            keys: Vec::new(),
+        }
+    }
+}
+
+impl<T: async_graphql::InputType> async_graphql::InputType for Ordered<T> {
+    type RawValueType = T::RawValueType;
+
+    fn type_name() -> std::borrow::Cow<'static, str> {
+        T::type_name()
+    }
+
+    fn create_type_info(registry: &mut async_graphql::registry::Registry) -> String {
+        T::create_type_info(registry)
+    }
+
+    fn parse(value: Option<async_graphql::Value>) -> async_graphql::InputValueResult<Self> {
+        let keys = match &value {
+            Some(async_graphql::Value::Object(object)) => {
+                object.keys().map(|key| key.to_string()).collect()
+            }
+            _ => Vec::new(),
+        };
+
+        Ok(Self {
+            value: T::parse(value).map_err(async_graphql::InputValueError::propagate)?,
+            keys,
+        })
+    }
+
+    fn to_value(&self) -> async_graphql::Value {
+        self.value.to_value()
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        self.value.as_raw_value()
+    }
+}
+
+/// 沒有 belongs-to 關聯時 `RelatedOrderBy` 的佔位類型
+///
+/// 以 flatten 合併進 `OrderBy` 時不提供任何欄位；本身未被引用，建立 schema 時會被移除。
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoRelatedOrderBy;
+
+impl NoRelatedOrderBy {
+    pub fn check_guards(&self, _ctx: &async_graphql::Context<'_>) -> async_graphql::Result<()> {
+        Ok(())
+    }
+
+    pub fn declared_keys(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    pub fn apply_key<S>(
+        &mut self,
+        _key: &str,
+        stmt: S,
+        _alias: Option<&str>,
+        _joined: &mut Vec<String>,
+    ) -> std::result::Result<S, sea_orm::DbErr> {
+        Ok(stmt)
+    }
+}
+
+impl async_graphql::InputType for NoRelatedOrderBy {
+    type RawValueType = Self;
+
+    fn type_name() -> std::borrow::Cow<'static, str> {
+        std::borrow::Cow::Borrowed("NoRelatedOrderBy")
+    }
+
+    fn create_type_info(registry: &mut async_graphql::registry::Registry) -> String {
+        registry.create_input_type::<Self, _>(
+            async_graphql::registry::MetaTypeId::InputObject,
+            |_| async_graphql::registry::MetaType::InputObject {
+                name: Self::type_name().to_string(),
+                description: None,
+                input_fields: Default::default(),
+                visible: None,
+                inaccessible: false,
+                tags: vec![],
+                rust_typename: Some(std::any::type_name::<Self>()),
+                oneof: false,
+                directive_invocations: vec![],
+            },
+        )
+    }
+
+    fn parse(_value: Option<async_graphql::Value>) -> async_graphql::InputValueResult<Self> {
+        Ok(Self)
+    }
+
+    fn to_value(&self) -> async_graphql::Value {
+        async_graphql::Value::Object(Default::default())
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+}
+
+impl async_graphql::InputObjectType for NoRelatedOrderBy {}
+
 pub type BinaryVector = Vec<u8>;
 
+/// 區間輸入，`from` 與 `to` 皆包含在內
 #[derive(Debug, Clone, async_graphql::InputObject)]
-#[graphql(concrete(name = "TinyIntegerFilter", params(i8)))]
-#[graphql(concrete(name = "SmallIntegerFilter", params(i16)))]
-#[graphql(concrete(name = "IntegerFilter", params(i32)))]
-#[graphql(concrete(name = "BigIntegerFilter", params(i64)))]
-#[graphql(concrete(name = "TinyUnsignedFilter", params(u8)))]
-#[graphql(concrete(name = "SmallUnsignedFilter", params(u16)))]
-#[graphql(concrete(name = "UnsignedFilter", params(u32)))]
-#[graphql(concrete(name = "BigUnsignedFilter", params(u64)))]
-#[graphql(concrete(name = "FloatFilter", params(f32)))]
-#[graphql(concrete(name = "DoubleFilter", params(f64)))]
+#[graphql(concrete(name = "TinyIntegerRange", params(i8)))]
+#[graphql(concrete(name = "SmallIntegerRange", params(i16)))]
+#[graphql(concrete(name = "IntegerRange", params(i32)))]
+#[graphql(concrete(name = "BigIntegerRange", params(i64)))]
+#[graphql(concrete(name = "TinyUnsignedRange", params(u8)))]
+#[graphql(concrete(name = "SmallUnsignedRange", params(u16)))]
+#[graphql(concrete(name = "UnsignedRange", params(u32)))]
+#[graphql(concrete(name = "BigUnsignedRange", params(u64)))]
+#[graphql(concrete(name = "FloatRange", params(f32)))]
+#[graphql(concrete(name = "DoubleRange", params(f64)))]
+#[graphql(concrete(name = "StringRange", params(String)))]
 #[cfg_attr(
-    feature = "with-json",
-    graphql(concrete(name = "JsonFilter", params(sea_orm::prelude::Json)))
+    feature = "with-chrono",
+    graphql(concrete(name = "DateRange", params(sea_orm::prelude::Date)))
 )]
-// TODO #[graphql(concrete(name = "DateFilter", params()))]
-// TODO #[graphql(concrete(name = "TimeFilter", params()))]
 #[cfg_attr(
     feature = "with-chrono",
-    graphql(concrete(name = "DateFilter", params(sea_orm::prelude::Date)))
+    graphql(concrete(name = "TimeRange", params(sea_orm::prelude::Time)))
 )]
 #[cfg_attr(
     feature = "with-chrono",
-    graphql(concrete(name = "DateTimeFilter", params(sea_orm::prelude::DateTime)))
+    graphql(concrete(name = "DateTimeRange", params(sea_orm::prelude::DateTime)))
 )]
 #[cfg_attr(
     feature = "with-chrono",
-    graphql(concrete(name = "DateTimeUtcFilter", params(sea_orm::prelude::DateTimeUtc)))
+    graphql(concrete(name = "DateTimeUtcRange", params(sea_orm::prelude::DateTimeUtc)))
 )]
 #[cfg_attr(
     feature = "with-chrono",
     graphql(concrete(
-        name = "DateTimeWithTimeZoneFilter",
+        name = "DateTimeWithTimeZoneRange",
         params(sea_orm::prelude::DateTimeWithTimeZone)
     ))
 )]
-// TODO #[graphql(concrete(name = "TimestampFilter", params()))]
-// TODO #[graphql(concrete(name = "TimestampWithTimeZoneFilter", params()))]
+#[cfg_attr(
+    feature = "with-decimal",
+    graphql(concrete(name = "DecimalRange", params(sea_orm::prelude::Decimal)))
+)]
+#[cfg_attr(
+    feature = "with-uuid",
+    graphql(concrete(name = "UuidRange", params(sea_orm::prelude::Uuid)))
+)]
+pub struct RangeInput<T: async_graphql::InputType> {
+    pub from: T,
+    pub to: T,
+}
+
+#[derive(Debug, Clone, async_graphql::InputObject)]
+#[graphql(concrete(name = "TinyIntegerFilter", params(i8)))]
+#[graphql(concrete(name = "SmallIntegerFilter", params(i16)))]
+#[graphql(concrete(name = "IntegerFilter", params(i32)))]
+#[graphql(concrete(name = "BigIntegerFilter", params(i64)))]
+#[graphql(concrete(name = "TinyUnsignedFilter", params(u8)))]
+#[graphql(concrete(name = "SmallUnsignedFilter", params(u16)))]
+#[graphql(concrete(name = "UnsignedFilter", params(u32)))]
+#[graphql(concrete(name = "BigUnsignedFilter", params(u64)))]
+#[graphql(concrete(name = "FloatFilter", params(f32)))]
+#[graphql(concrete(name = "DoubleFilter", params(f64)))]
 #[cfg_attr(
     feature = "with-decimal",
     graphql(concrete(name = "DecimalFilter", params(sea_orm::prelude::Decimal)))
 )]
+#[cfg_attr(
+    feature = "with-chrono",
+    graphql(concrete(name = "TimeFilter", params(sea_orm::prelude::Time)))
+)]
 #[cfg_attr(
     feature = "with-uuid",
     graphql(concrete(name = "UuidFilter", params(sea_orm::prelude::Uuid)))
 )]
+pub struct TypeFilter<T: async_graphql::InputType>
+where
+    RangeInput<T>: async_graphql::InputType,
+{
+    pub eq: Option<T>,
+    pub ne: Option<T>,
+    pub gt: Option<T>,
+    pub gte: Option<T>,
+    pub lt: Option<T>,
+    pub lte: Option<T>,
+    pub is_in: Option<Vec<T>>,
+    pub is_not_in: Option<Vec<T>>,
+    pub is_null: Option<bool>,
+    /// 介於 `from` 與 `to` 之間（包含兩端）
+    pub between: Option<RangeInput<T>>,
+    /// 不在 `from` 與 `to` 之間
+    pub not_between: Option<RangeInput<T>>,
+}
+
+impl<T> TypeFilter<T>
+where
+    T: async_graphql::InputType + Into<sea_orm::Value>,
+    RangeInput<T>: async_graphql::InputType,
+{
+    /// 將比較運算套用在任意運算式上，例如 `cardinality(col)`
+    pub fn to_condition<E>(self, expr: E) -> sea_orm::Condition
+    where
+        E: Into<sea_orm::sea_query::SimpleExpr>,
+    {
+        use sea_orm::sea_query::Expr;
+
+        let expr = Expr::expr(expr);
+
+        let mut condition = BasicFilter {
+            eq: self.eq,
+            ne: self.ne,
+            gt: self.gt,
+            gte: self.gte,
+            lt: self.lt,
+            lte: self.lte,
+            is_in: self.is_in,
+            is_not_in: self.is_not_in,
+            is_null: self.is_null,
+        }
+        .to_condition(expr.clone());
+
+        if let Some(between_value) = self.between {
+            condition = condition.add(expr.clone().between(between_value.from, between_value.to))
+        }
+
+        if let Some(not_between_value) = self.not_between {
+            condition = condition.add(
+                expr.clone()
+                    .not_between(not_between_value.from, not_between_value.to),
+            )
+        }
+
+        condition
+    }
+}
+
+/// 布林與二進位欄位的過濾器，沒有意義的順序，因此不提供 `between` 區間運算
+#[derive(Debug, Clone, async_graphql::InputObject)]
 #[graphql(concrete(name = "BinaryFilter", params(BinaryVector)))]
 #[graphql(concrete(name = "BooleanFilter", params(bool)))]
-pub struct TypeFilter<T: async_graphql::InputType> {
+pub struct BasicFilter<T: async_graphql::InputType> {
     pub eq: Option<T>,
     pub ne: Option<T>,
     pub gt: Option<T>,
@@ -210,9 +503,67 @@ pub struct TypeFilter<T: async_graphql::InputType> {
     pub is_null: Option<bool>,
 }
 
+impl<T> BasicFilter<T>
+where
+    T: async_graphql::InputType + Into<sea_orm::Value>,
+{
+    pub fn to_condition<E>(self, expr: E) -> sea_orm::Condition
+    where
+        E: Into<sea_orm::sea_query::SimpleExpr>,
+    {
+        use sea_orm::sea_query::Expr;
+
+        let expr = Expr::expr(expr);
+        let mut condition = sea_orm::Condition::all();
+
+        if let Some(eq_value) = self.eq {
+            condition = condition.add(expr.clone().eq(eq_value))
+        }
+
+        if let Some(ne_value) = self.ne {
+            condition = condition.add(expr.clone().ne(ne_value))
+        }
+
+        if let Some(gt_value) = self.gt {
+            condition = condition.add(expr.clone().gt(gt_value))
+        }
+
+        if let Some(gte_value) = self.gte {
+            condition = condition.add(expr.clone().gte(gte_value))
+        }
+
+        if let Some(lt_value) = self.lt {
+            condition = condition.add(expr.clone().lt(lt_value))
+        }
+
+        if let Some(lte_value) = self.lte {
+            condition = condition.add(expr.clone().lte(lte_value))
+        }
+
+        if let Some(is_in_value) = self.is_in {
+            condition = condition.add(expr.clone().is_in(is_in_value))
+        }
+
+        if let Some(is_not_in_value) = self.is_not_in {
+            condition = condition.add(expr.clone().is_not_in(is_not_in_value))
+        }
+
+        if let Some(is_null_value) = self.is_null {
+            if is_null_value {
+                condition = condition.add(expr.is_null())
+            }
+        }
+
+        condition
+    }
+}
+
 #[derive(Debug, Clone, async_graphql::InputObject)]
 #[graphql(concrete(name = "StringFilter", params(String)))]
-pub struct StringFilter<T: async_graphql::InputType> {
+pub struct StringFilter<T: async_graphql::InputType>
+where
+    RangeInput<T>: async_graphql::InputType,
+{
     pub like: Option<T>,
     pub eq: Option<T>,
     pub ne: Option<T>,
@@ -223,6 +574,43 @@ pub struct StringFilter<T: async_graphql::InputType> {
     pub is_in: Option<Vec<T>>,
     pub is_not_in: Option<Vec<T>>,
     pub is_null: Option<bool>,
+    /// 介於 `from` 與 `to` 之間（包含兩端）
+    pub between: Option<RangeInput<T>>,
+    /// 不在 `from` 與 `to` 之間
+    pub not_between: Option<RangeInput<T>>,
+}
+
+impl StringFilter<String> {
+    /// 將字串比較套用在任意運算式上
+    pub fn to_condition<E>(self, expr: E) -> sea_orm::Condition
+    where
+        E: Into<sea_orm::sea_query::SimpleExpr>,
+    {
+        use sea_orm::sea_query::Expr;
+
+        let expr = expr.into();
+
+        let mut condition = TypeFilter {
+            eq: self.eq,
+            ne: self.ne,
+            gt: self.gt,
+            gte: self.gte,
+            lt: self.lt,
+            lte: self.lte,
+            is_in: self.is_in,
+            is_not_in: self.is_not_in,
+            is_null: self.is_null,
+            between: self.between,
+            not_between: self.not_between,
+        }
+        .to_condition(expr.clone());
+
+        if let Some(like_value) = self.like {
+            condition = condition.add(Expr::expr(expr).like(like_value))
+        }
+
+        condition
+    }
 }
 
 #[derive(Debug, async_graphql::InputObject)]
@@ -601,9 +989,41 @@ pub async fn fetch_relation_data<Entity, Filter, Order>(
 where
     Entity: sea_orm::EntityTrait,
     <Entity::Column as FromStr>::Err: Debug,
+{
+    fetch_relation_data_with_columns::<Entity, Filter, Order, Entity::Model>(
+        keys,
+        relation,
+        None,
+        sea_orm::Condition::all(),
+        db,
+    )
+    .await
+}
+
+/// 與 `fetch_relation_data` 相同，但只選取 `columns` 中的欄位（外鍵欄位總是包含在內），
+/// 以 `Partial` 讀取後轉為 `Model`；`columns` 為 `None` 時選取所有欄位。
+/// `condition` 為關聯實體的租戶、`EntityGuard` 與軟刪除條件
+pub async fn fetch_relation_data_with_columns<Entity, Filter, Order, Partial>(
+    keys: Vec<RelationKeyStruct<Option<Filter>, Option<Order>>>,
+    relation: sea_orm::RelationDef,
+    columns: Option<Vec<String>>,
+    condition: sea_orm::Condition,
+    db: &sea_orm::DatabaseConnection,
+) -> std::result::Result<
+    Vec<(
+        RelationKeyStruct<Option<Filter>, Option<Order>>,
+        <Entity as sea_orm::EntityTrait>::Model,
+    )>,
+    sea_orm::error::DbErr,
+>
+where
+    Entity: sea_orm::EntityTrait,
+    <Entity::Column as FromStr>::Err: Debug,
+    Partial: sea_orm::FromQueryResult + Into<<Entity as sea_orm::EntityTrait>::Model>,
 {
     use heck::ToSnakeCase;
     use sea_orm::prelude::*;
+    use sea_orm::QuerySelect;
 
     let keys: Vec<sea_orm::Value> = keys.into_iter().map(|key| key.0).collect();
 
@@ -616,8 +1036,36 @@ where
 
     let stmt =
         <sea_orm::Select<Entity> as sea_orm::QueryFilter>::filter(stmt, to_column.is_in(keys));
+    let stmt = <sea_orm::Select<Entity> as sea_orm::QueryFilter>::filter(stmt, condition);
+
+    let models: Vec<<Entity as EntityTrait>::Model> = match columns {
+        Some(columns) => {
+            let mut selected: Vec<Entity::Column> = vec![to_column];
 
-    let data = stmt.all(db).await?.into_iter().map(
+            for name in columns {
+                let column = <Entity::Column as FromStr>::from_str(name.as_str()).unwrap();
+
+                if !selected
+                    .iter()
+                    .any(|selected| selected.as_str() == column.as_str())
+                {
+                    selected.push(column);
+                }
+            }
+
+            stmt.select_only()
+                .columns(selected)
+                .into_model::<Partial>()
+                .all(db)
+                .await?
+                .into_iter()
+                .map(Into::into)
+                .collect()
+        }
+        None => stmt.all(db).await?,
+    };
+
+    let data = models.into_iter().map(
         |model: <Entity as EntityTrait>::Model| -> (
             RelationKeyStruct<Option<Filter>, Option<Order>>,
             <Entity as EntityTrait>::Model,
@@ -634,3 +1082,160 @@ where
 
     Ok(data.collect())
 }
+
+/// 依 `pagination` 查詢 `stmt` 並包裝為分頁連線，游標由主鍵值編碼
+///
+/// 頁碼分頁額外回傳總頁數與目前頁碼；游標分頁多取一筆判斷是否還有下一頁；
+/// 未提供分頁時回傳所有資料。`additional_fields` 以分頁資訊建立連線的額外欄位。
+pub async fn fetch_connection<Entity, Fields>(
+    db: &sea_orm::DatabaseConnection,
+    stmt: sea_orm::Select<Entity>,
+    pagination: Option<Pagination>,
+    additional_fields: impl FnOnce(ExtraPaginationFields) -> Fields,
+) -> std::result::Result<
+    async_graphql::types::connection::Connection<
+        String,
+        Entity::Model,
+        Fields,
+        async_graphql::types::connection::EmptyFields,
+    >,
+    async_graphql::Error,
+>
+where
+    Entity: sea_orm::EntityTrait,
+    Entity::Model: async_graphql::OutputType + Sync,
+    Fields: async_graphql::ObjectType,
+{
+    fetch_connection_with_columns::<Entity, Entity::Model, Fields>(
+        db,
+        stmt,
+        None,
+        pagination,
+        additional_fields,
+    )
+    .await
+}
+
+/// 與 `fetch_connection` 相同，`columns` 為 `Some` 時只選取這些欄位並以 `Partial` 讀取
+///
+/// `columns` 需包含主鍵，供游標編碼與游標分頁使用。
+pub async fn fetch_connection_with_columns<Entity, Partial, Fields>(
+    db: &sea_orm::DatabaseConnection,
+    stmt: sea_orm::Select<Entity>,
+    columns: Option<Vec<Entity::Column>>,
+    pagination: Option<Pagination>,
+    additional_fields: impl FnOnce(ExtraPaginationFields) -> Fields,
+) -> std::result::Result<
+    async_graphql::types::connection::Connection<
+        String,
+        Entity::Model,
+        Fields,
+        async_graphql::types::connection::EmptyFields,
+    >,
+    async_graphql::Error,
+>
+where
+    Entity: sea_orm::EntityTrait,
+    Entity::Model: async_graphql::OutputType + Sync,
+    Partial: sea_orm::FromQueryResult + Into<Entity::Model> + Send + Sync,
+    Fields: async_graphql::ObjectType,
+{
+    use async_graphql::types::connection::{Connection, CursorType, Edge};
+    use sea_orm::{
+        Identity, Iterable, ModelTrait, PaginatorTrait, PrimaryKeyToColumn, QuerySelect,
+    };
+
+    let stmt = match columns {
+        Some(columns) => stmt.select_only().columns(columns),
+        None => stmt,
+    };
+
+    let encode_cursor = |model: &Entity::Model| {
+        CursorValues(
+            Entity::PrimaryKey::iter()
+                .map(|primary_key| model.get(primary_key.into_column()))
+                .collect(),
+        )
+        .encode_cursor()
+    };
+
+    let (data, has_previous_page, has_next_page, pagination) = match pagination {
+        Some(Pagination::Pages(PageInput { limit, page })) => {
+            let paginator = stmt.into_model::<Partial>().paginate(db, limit as u64);
+            let pages = paginator.num_pages().await? as usize;
+            let data = paginator.fetch_page(page as u64).await?;
+
+            (
+                data,
+                page > 0,
+                page + 1 < pages,
+                ExtraPaginationFields {
+                    pages: Some(pages),
+                    current: Some(page),
+                },
+            )
+        }
+        Some(Pagination::Cursor(CursorInput { cursor, limit })) => {
+            let columns: Vec<sea_orm::sea_query::DynIden> = Entity::PrimaryKey::iter()
+                .map(|primary_key| sea_orm::sea_query::SeaRc::new(primary_key.into_column()) as _)
+                .collect();
+
+            let identity = match columns.len() {
+                1 => Identity::Unary(columns[0].clone()),
+                2 => Identity::Binary(columns[0].clone(), columns[1].clone()),
+                3 => Identity::Ternary(columns[0].clone(), columns[1].clone(), columns[2].clone()),
+                _ => Identity::Many(columns),
+            };
+
+            let mut stmt = stmt.cursor_by(identity);
+
+            if let Some(cursor) = &cursor {
+                let values = CursorValues::decode_cursor(cursor)?;
+
+                stmt.after(map_cursor_values(values.0));
+            }
+
+            let mut data = stmt
+                .into_model::<Partial>()
+                .first(limit + 1)
+                .all(db)
+                .await?;
+            let has_next_page = data.len() as u64 > limit;
+
+            data.truncate(limit as usize);
+
+            (
+                data,
+                cursor.is_some(),
+                has_next_page,
+                ExtraPaginationFields {
+                    pages: None,
+                    current: None,
+                },
+            )
+        }
+        None => (
+            stmt.into_model::<Partial>().all(db).await?,
+            false,
+            false,
+            ExtraPaginationFields {
+                pages: Some(1),
+                current: Some(0),
+            },
+        ),
+    };
+
+    let mut connection = Connection::with_additional_fields(
+        has_previous_page,
+        has_next_page,
+        additional_fields(pagination),
+    );
+
+    connection.edges.extend(data.into_iter().map(|partial| {
+        let model: Entity::Model = partial.into();
+
+        Edge::new(encode_cursor(&model), model)
+    }));
+
+    Ok(connection)
+}
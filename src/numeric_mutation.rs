@@ -0,0 +1,61 @@
+// Derived from Seaography (github.com/SeaQL/seaography)
+// Modifications Copyright (c) 2025 Stephen J. Li
+
+use sea_orm::sea_query::{Expr, SimpleExpr};
+use sea_orm::{ColumnTrait, IntoSimpleExpr};
+
+/// 數值原子更新操作
+///
+/// 依 `multiply`、`increment`、`decrement` 的順序組成 `col = col * ? + ? - ?`，
+/// 在資料庫內完成計算，避免先讀後寫造成的競爭。
+#[derive(Debug, Clone, async_graphql::InputObject)]
+#[graphql(concrete(name = "TinyIntegerMutation", params(i8)))]
+#[graphql(concrete(name = "SmallIntegerMutation", params(i16)))]
+#[graphql(concrete(name = "IntegerMutation", params(i32)))]
+#[graphql(concrete(name = "BigIntegerMutation", params(i64)))]
+#[graphql(concrete(name = "TinyUnsignedMutation", params(u8)))]
+#[graphql(concrete(name = "SmallUnsignedMutation", params(u16)))]
+#[graphql(concrete(name = "UnsignedMutation", params(u32)))]
+#[graphql(concrete(name = "BigUnsignedMutation", params(u64)))]
+#[graphql(concrete(name = "FloatMutation", params(f32)))]
+#[graphql(concrete(name = "DoubleMutation", params(f64)))]
+#[cfg_attr(
+    feature = "with-decimal",
+    graphql(concrete(name = "DecimalMutation", params(sea_orm::prelude::Decimal)))
+)]
+pub struct NumericMutation<T: async_graphql::InputType> {
+    pub increment: Option<T>,
+    pub decrement: Option<T>,
+    pub multiply: Option<T>,
+}
+
+impl<T> NumericMutation<T>
+where
+    T: async_graphql::InputType + Into<sea_orm::Value>,
+{
+    /// 產生更新運算式，用於 `UpdateMany::col_expr`；沒有任何操作時回傳 `None`
+    pub fn to_expr<C>(self, column: C) -> Option<SimpleExpr>
+    where
+        C: ColumnTrait,
+    {
+        if self.increment.is_none() && self.decrement.is_none() && self.multiply.is_none() {
+            return None;
+        }
+
+        let mut expr = column.into_simple_expr();
+
+        if let Some(multiply) = self.multiply {
+            expr = Expr::expr(expr).mul(multiply);
+        }
+
+        if let Some(increment) = self.increment {
+            expr = Expr::expr(expr).add(increment);
+        }
+
+        if let Some(decrement) = self.decrement {
+            expr = Expr::expr(expr).sub(decrement);
+        }
+
+        Some(expr)
+    }
+}
@@ -0,0 +1,14 @@
+// Derived from Seaography (github.com/SeaQL/seaography)
+// Modifications Copyright (c) 2025 Stephen J. Li
+
+use heck::ToLowerCamelCase;
+
+/// `Model` 欄位在 GraphQL 輸出類型中的名稱
+///
+/// `Filter` derive 依欄位上的 `#[graphql(name = "...")]` 產生實作，沒有改名的欄位與預設相同，
+/// 為欄位名稱的 lowerCamelCase。過濾、排序與 `Mutant` 仍使用欄位名稱。
+pub trait EntityOutput: sea_orm::EntityTrait {
+    fn output_name(column: Self::Column) -> String {
+        format!("{:?}", column).to_lower_camel_case()
+    }
+}
@@ -0,0 +1,3222 @@
+// Derived from Seaography (github.com/SeaQL/seaography)
+// Modifications Copyright (c) 2025 Stephen J. Li
+
+use std::any::Any;
+use std::collections::{BTreeMap, HashMap};
+use std::future::Future;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use async_graphql::connection::CursorType;
+use async_graphql::dataloader::{DataLoader, Loader};
+use async_graphql::dynamic::{
+    Enum, Field, FieldFuture, FieldValue, InputObject, InputValue, Object, ObjectAccessor,
+    ResolverContext, Scalar, Schema, SchemaError, Type, TypeRef, ValueAccessor,
+};
+use async_graphql::resolver_utils::EnumType;
+use heck::{ToLowerCamelCase, ToShoutySnakeCase, ToSnakeCase, ToUpperCamelCase};
+use sea_orm::sea_query::{
+    Alias, Asterisk, ColumnType, DynIden, Expr, IntoIden, JoinType, NullOrdering, Order, SeaRc,
+    SimpleExpr, TableRef,
+};
+use sea_orm::{
+    ColumnTrait, Condition, ConnectionTrait, DatabaseConnection, EntityTrait, IdenStatic,
+    IntoSimpleExpr, Iterable, ModelTrait, PaginatorTrait, PrimaryKeyToColumn, QueryFilter,
+    QueryOrder, QuerySelect, QueryTrait, RelationDef, RelationTrait, RelationType, UpdateMany,
+};
+
+use crate::{
+    aggregate_expr, distinct_on, row_condition, soft_delete_condition, version_conflict,
+    AggregateFunc, BasicFilter, CursorValues, EntityAudit, EntityGuard, EntityOutput,
+    EntitySoftDelete, EntityTenant, EntityVersion, GuardCondition, NumericMutation, OrderByEnum,
+    RelationKeyStruct, StringFilter, TypeFilter,
+};
+
+type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// 實體的守衛條件，第二個參數為 `withDeleted`
+type GuardFn = fn(&async_graphql::Context<'_>, Option<bool>) -> async_graphql::Result<Condition>;
+
+/// 以 SeaORM 實體的中繼資料在執行期建立 GraphQL schema
+///
+/// 每個註冊的實體產生與 derive 相同的類型與欄位（文件說明除外）：根查詢的連線、
+/// 以主鍵與唯一欄位的單筆查詢、聚合與分組、關聯與關聯聚合欄位，以及 `{table}UpdateMany`
+/// mutation；關聯只連結到同樣註冊過的實體。解析器從 context 的 `DataLoader<RelationLoader>`
+/// 取得資料庫連線，關聯、關聯聚合與單筆查詢經由同一個 DataLoader 批次查詢。
+///
+/// ```ignore
+/// let schema = SchemaBuilder::new()
+///     .register::<film::Entity>()
+///     .register::<language::Entity>()
+///     .finish(db, tokio::spawn)?;
+/// ```
+#[derive(Default)]
+pub struct SchemaBuilder {
+    entities: Vec<Arc<dyn DynamicEntity>>,
+}
+
+impl SchemaBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 註冊不套用守衛、租戶與軟刪除的實體，`withDeleted` 參數沒有作用
+    pub fn register<E>(mut self) -> Self
+    where
+        E: EntityOutput,
+        E::Model: Sync,
+    {
+        self.entities.push(Arc::new(EntityMeta::<E>::new(
+            |_, _| Ok(Condition::all()),
+            Vec::new(),
+            |stmt| stmt,
+            None,
+        )));
+        self
+    }
+
+    /// 與 `register` 相同，並將 `EntityGuard` 與租戶的條件加在查詢、關聯、聚合與 `UpdateMany` 上，
+    /// 且除非帶入 `withDeleted: true` 否則排除已軟刪除的資料；`UpdateMany` 寫入 `updated_at`，
+    /// 有版本欄位時需帶入 `expectedVersion`，租戶、軟刪除、稽核與版本欄位不出現在 `Mutant` 中
+    pub fn register_guarded<E>(mut self) -> Self
+    where
+        E: EntityGuard
+            + EntityTenant
+            + EntitySoftDelete
+            + EntityAudit
+            + EntityVersion
+            + EntityOutput,
+        E::Model: Sync,
+    {
+        self.entities.push(Arc::new(EntityMeta::<E>::new(
+            |ctx, with_deleted| {
+                Ok(row_condition::<E>(ctx)?.add(soft_delete_condition::<E>(with_deleted)))
+            },
+            E::tenant_column()
+                .into_iter()
+                .chain(E::soft_delete_column())
+                .chain(E::audit_columns())
+                .chain(E::version_column())
+                .collect(),
+            E::set_updated_at,
+            E::version_column(),
+        )));
+        self
+    }
+
+    /// 產生 async-graphql 的 `SchemaBuilder`，可再加入 data 或擴充設定；
+    /// 需自行加入 `DataLoader<RelationLoader>`
+    pub fn build(self) -> async_graphql::dynamic::SchemaBuilder {
+        let entities: Arc<EntityMap> = Arc::new(
+            self.entities
+                .iter()
+                .map(|entity| (entity.table_name(), entity.clone()))
+                .collect(),
+        );
+
+        let mut types = TypeMap::default();
+        let mut query = Object::new("Query");
+        let mut mutation = Object::new("Mutation");
+        let mut has_mutation = false;
+
+        for entity in self.entities.iter() {
+            entity.register_types(&entities, &mut types);
+
+            for field in entity.query_fields(entities.clone()) {
+                query = query.field(field);
+            }
+
+            if let Some(field) = entity.mutation_field() {
+                mutation = mutation.field(field);
+                has_mutation = true;
+            }
+        }
+
+        let schema = Schema::build(
+            query.type_name(),
+            has_mutation
+                .then(|| mutation.type_name().to_owned())
+                .as_deref(),
+            None,
+        );
+
+        let schema = types
+            .0
+            .into_values()
+            .fold(schema, |schema, ty| schema.register(ty))
+            .register(query);
+
+        if has_mutation {
+            schema.register(mutation)
+        } else {
+            schema
+        }
+    }
+
+    /// 加入 `DataLoader<RelationLoader>` 後建立 schema，`spawner` 傳給 `DataLoader::new`
+    pub fn finish<S, R>(self, db: DatabaseConnection, spawner: S) -> Result<Schema, SchemaError>
+    where
+        S: Fn(BoxFuture<'static, ()>) -> R + Send + Sync + 'static,
+    {
+        self.build()
+            .data(DataLoader::new(RelationLoader { db }, spawner))
+            .finish()
+    }
+}
+
+/// 依名稱去重的類型集合，共用類型（如 `IntegerFilter`）只註冊一次
+#[derive(Default)]
+struct TypeMap(BTreeMap<String, Type>);
+
+impl TypeMap {
+    fn insert_with<T, F>(&mut self, name: &str, create: F)
+    where
+        T: Into<Type>,
+        F: FnOnce() -> T,
+    {
+        if !self.0.contains_key(name) {
+            self.0.insert(name.to_owned(), create().into());
+        }
+    }
+}
+
+type EntityMap = BTreeMap<String, Arc<dyn DynamicEntity>>;
+
+/// `find_by_keys` 讀取的一列資料，實際類型為關聯實體的 `Model`
+type RelatedRow = Arc<dyn Any + Send + Sync>;
+
+/// 各列的關聯欄位值與資料
+type RelatedRows = Vec<(Vec<sea_orm::Value>, RelatedRow)>;
+
+/// `SchemaBuilder` 關聯欄位的 DataLoader 鍵
+///
+/// 由關聯實體、對應的欄位與值及守衛條件組成，實體、欄位與條件相同的鍵以一條 SQL 查詢。
+#[derive(Clone)]
+pub struct RelationKey {
+    entity: Arc<dyn DynamicEntity>,
+    columns: Vec<String>,
+    values: Vec<RelationKeyStruct<(), ()>>,
+    guard: GuardCondition,
+}
+
+impl RelationKey {
+    fn is_same_query(&self, other: &Self) -> bool {
+        self.entity.table_name() == other.entity.table_name()
+            && self.columns == other.columns
+            && self.guard == other.guard
+    }
+}
+
+impl PartialEq for RelationKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.is_same_query(other) && self.values == other.values
+    }
+}
+
+impl Eq for RelationKey {}
+
+impl Hash for RelationKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.entity.table_name().hash(state);
+        self.columns.hash(state);
+        self.values.hash(state);
+        self.guard.hash(state);
+    }
+}
+
+/// 批次查詢 `SchemaBuilder` 關聯欄位的 DataLoader，解析器也由此取得資料庫連線
+pub struct RelationLoader {
+    pub db: DatabaseConnection,
+}
+
+#[async_trait::async_trait]
+impl Loader<RelationKey> for RelationLoader {
+    type Value = Vec<RelatedRow>;
+    type Error = async_graphql::Error;
+
+    async fn load(
+        &self,
+        keys: &[RelationKey],
+    ) -> Result<HashMap<RelationKey, Self::Value>, Self::Error> {
+        let mut data: HashMap<RelationKey, Self::Value> = HashMap::new();
+
+        for group in group_relation_keys(keys.iter()) {
+            let first = group[0];
+            let values = relation_key_values(&group);
+
+            let rows = first
+                .entity
+                .find_by_keys(&self.db, &first.columns, values, first.guard.0.clone())
+                .await?;
+
+            for (values, row) in rows {
+                let key = RelationKey {
+                    values: values
+                        .into_iter()
+                        .map(|value| RelationKeyStruct(value, (), ()))
+                        .collect(),
+                    ..first.clone()
+                };
+
+                data.entry(key).or_default().push(row);
+            }
+        }
+
+        Ok(data)
+    }
+}
+
+/// `SchemaBuilder` has-many 關聯聚合欄位的 DataLoader 鍵，守衛條件包含子實體的過濾條件
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct AggregateKey(RelationKey);
+
+#[async_trait::async_trait]
+impl Loader<AggregateKey> for RelationLoader {
+    type Value = AggregateData;
+    type Error = async_graphql::Error;
+
+    async fn load(
+        &self,
+        keys: &[AggregateKey],
+    ) -> Result<HashMap<AggregateKey, Self::Value>, Self::Error> {
+        let mut data: HashMap<AggregateKey, Self::Value> = HashMap::new();
+
+        for group in group_relation_keys(keys.iter().map(|key| &key.0)) {
+            let first = group[0];
+
+            let rows: HashMap<Vec<RelationKeyStruct<(), ()>>, AggregateData> = first
+                .entity
+                .aggregate_by_keys(
+                    &self.db,
+                    &first.columns,
+                    relation_key_values(&group),
+                    first.guard.0.clone(),
+                )
+                .await?
+                .into_iter()
+                .map(|(values, aggregate)| {
+                    let values = values
+                        .into_iter()
+                        .map(|value| RelationKeyStruct(value, (), ()))
+                        .collect();
+
+                    (values, aggregate)
+                })
+                .collect();
+
+            // 沒有子資料列的父資料列不在查詢結果中
+            data.extend(group.into_iter().map(|key| {
+                let aggregate = rows.get(&key.values).cloned().unwrap_or_default();
+
+                (AggregateKey(key.clone()), aggregate)
+            }));
+        }
+
+        Ok(data)
+    }
+}
+
+/// 依實體、欄位與守衛條件分組，同一組以一條 SQL 查詢
+fn group_relation_keys<'a, I>(keys: I) -> Vec<Vec<&'a RelationKey>>
+where
+    I: Iterator<Item = &'a RelationKey>,
+{
+    let mut groups: Vec<Vec<&RelationKey>> = Vec::new();
+
+    for key in keys {
+        match groups.iter_mut().find(|group| group[0].is_same_query(key)) {
+            Some(group) => group.push(key),
+            None => groups.push(vec![key]),
+        }
+    }
+
+    groups
+}
+
+fn relation_key_values(group: &[&RelationKey]) -> Vec<Vec<sea_orm::Value>> {
+    group
+        .iter()
+        .map(|key| key.values.iter().map(|value| value.0.clone()).collect())
+        .collect()
+}
+
+type OrderExpr = (SimpleExpr, Order, Option<NullOrdering>);
+
+/// 聚合函數及其在查詢結果別名與 GraphQL 欄位中的名稱
+const AGGREGATE_FUNCS: [(AggregateFunc, &str); 4] = [
+    (AggregateFunc::Sum, "sum"),
+    (AggregateFunc::Avg, "avg"),
+    (AggregateFunc::Min, "min"),
+    (AggregateFunc::Max, "max"),
+];
+
+trait DynamicEntity: Send + Sync {
+    fn table_name(&self) -> String;
+
+    /// 實體的守衛條件，未以 `register_guarded` 註冊時不限制
+    fn guard(
+        &self,
+        ctx: &async_graphql::Context<'_>,
+        with_deleted: Option<bool>,
+    ) -> async_graphql::Result<Condition>;
+
+    /// 守衛條件加上 `filters` 的過濾條件
+    fn condition(
+        &self,
+        ctx: &async_graphql::Context<'_>,
+        filters: Option<ObjectAccessor<'_>>,
+        with_deleted: Option<bool>,
+        backend: sea_orm::DbBackend,
+    ) -> async_graphql::Result<Condition>;
+
+    fn register_types(&self, entities: &EntityMap, types: &mut TypeMap);
+
+    /// 根查詢的連線欄位，以及以主鍵與唯一欄位查詢單筆資料的欄位
+    fn query_fields(&self, entities: Arc<EntityMap>) -> Vec<Field>;
+
+    /// `{table}UpdateMany` 欄位，沒有可更新的欄位時回傳 `None`
+    fn mutation_field(&self) -> Option<Field>;
+
+    /// 以一條查詢讀取 `columns` 的值為 `keys` 之一的資料，回傳各列的欄位值與資料，
+    /// 供其他實體的關聯欄位批次使用
+    fn find_by_keys<'a>(
+        &'a self,
+        db: &'a DatabaseConnection,
+        columns: &'a [String],
+        keys: Vec<Vec<sea_orm::Value>>,
+        guard: Condition,
+    ) -> BoxFuture<'a, async_graphql::Result<RelatedRows>>;
+
+    /// 將 `find_by_keys` 讀取的資料轉為欄位值
+    fn field_value(&self, row: &RelatedRow) -> Option<FieldValue<'static>>;
+
+    /// 依 `columns` 分組，一次計算 `keys` 中每組值的聚合結果，供其他實體的 has-many 聚合欄位批次使用；
+    /// 沒有資料列的值不在結果中
+    fn aggregate_by_keys<'a>(
+        &'a self,
+        db: &'a DatabaseConnection,
+        columns: &'a [String],
+        keys: Vec<Vec<sea_orm::Value>>,
+        condition: Condition,
+    ) -> BoxFuture<'a, async_graphql::Result<GroupByRows>>;
+
+    /// 以 `alias` 限定欄位的排序運算式，供其他實體依 belongs-to 關聯排序；
+    /// 關聯實體的 belongs-to 關聯所需的 JOIN 加入 `joins`
+    fn order_by_exprs(
+        &self,
+        alias: &str,
+        order_by: &ObjectAccessor<'_>,
+        entities: &EntityMap,
+        joins: &mut Vec<(RelationDef, String)>,
+    ) -> async_graphql::Result<Vec<OrderExpr>>;
+}
+
+struct EntityMeta<E: EntityTrait> {
+    guard: GuardFn,
+    /// 租戶、軟刪除與稽核等不允許經由 `Mutant` 更新的欄位
+    immutable_columns: Vec<E::Column>,
+    /// `UpdateMany` 時寫入 `updated_at`
+    set_updated_at: fn(UpdateMany<E>) -> UpdateMany<E>,
+    /// 樂觀鎖的版本欄位，`UpdateMany` 需帶入 `expectedVersion`
+    version_column: Option<E::Column>,
+    entity: PhantomData<fn() -> E>,
+}
+
+struct ColumnInfo<C> {
+    column: C,
+    name: String,
+    scalar: ScalarKind,
+    nullable: bool,
+}
+
+struct ConnectionData<M> {
+    nodes: Vec<(String, M)>,
+    page_info: PageInfoData,
+    pages: Option<u64>,
+    current: Option<u64>,
+    /// 連線的過濾與守衛條件，`aggregate` 與 `groupBy` 以此計算
+    condition: Condition,
+}
+
+/// 依欄位名稱存放的值，用於聚合結果與分組鍵
+type ColumnValues = HashMap<String, sea_orm::Value>;
+
+/// 分組鍵的值與該組的聚合結果
+type GroupByRows = Vec<(Vec<sea_orm::Value>, AggregateData)>;
+
+/// `SchemaBuilder` 聚合欄位的結果
+#[derive(Clone, Default)]
+pub struct AggregateData {
+    count: i64,
+    sum: ColumnValues,
+    avg: ColumnValues,
+    min: ColumnValues,
+    max: ColumnValues,
+}
+
+impl AggregateData {
+    fn values(&self, func: AggregateFunc) -> &ColumnValues {
+        match func {
+            AggregateFunc::Sum => &self.sum,
+            AggregateFunc::Avg => &self.avg,
+            AggregateFunc::Min => &self.min,
+            AggregateFunc::Max => &self.max,
+        }
+    }
+
+    fn values_mut(&mut self, func: AggregateFunc) -> &mut ColumnValues {
+        match func {
+            AggregateFunc::Sum => &mut self.sum,
+            AggregateFunc::Avg => &mut self.avg,
+            AggregateFunc::Min => &mut self.min,
+            AggregateFunc::Max => &mut self.max,
+        }
+    }
+}
+
+/// 分組結果，`key` 只有 `by` 中的欄位
+struct GroupByRowData {
+    key: ColumnValues,
+    aggregate: AggregateData,
+}
+
+#[derive(Clone)]
+struct PageInfoData {
+    has_previous_page: bool,
+    has_next_page: bool,
+    start_cursor: Option<String>,
+    end_cursor: Option<String>,
+}
+
+impl<E> EntityMeta<E>
+where
+    E: EntityOutput,
+    E::Model: Sync,
+{
+    fn new(
+        guard: GuardFn,
+        immutable_columns: Vec<E::Column>,
+        set_updated_at: fn(UpdateMany<E>) -> UpdateMany<E>,
+        version_column: Option<E::Column>,
+    ) -> Self {
+        Self {
+            guard,
+            immutable_columns,
+            set_updated_at,
+            version_column,
+            entity: PhantomData,
+        }
+    }
+
+    fn is_immutable_column(&self, column: E::Column) -> bool {
+        is_ignored_mutant_column(column)
+            || self
+                .immutable_columns
+                .iter()
+                .any(|immutable| immutable.as_str() == column.as_str())
+    }
+
+    fn type_name() -> String {
+        E::default().table_name().to_upper_camel_case()
+    }
+
+    /// 過濾、排序、`Mutant` 與聚合類型中的欄位名稱，輸出類型的名稱見 `EntityOutput`
+    fn column_name(column: E::Column) -> String {
+        format!("{:?}", column).to_lower_camel_case()
+    }
+
+    fn columns() -> Vec<ColumnInfo<E::Column>> {
+        E::Column::iter()
+            .filter_map(|column| {
+                let def = column.def();
+
+                ScalarKind::from_column_type(def.get_column_type()).map(|scalar| ColumnInfo {
+                    column,
+                    name: Self::column_name(column),
+                    scalar,
+                    nullable: def.is_null(),
+                })
+            })
+            .collect()
+    }
+
+    fn object(entities: &EntityMap) -> Object {
+        let object =
+            Self::columns()
+                .into_iter()
+                .fold(Object::new(Self::type_name()), |object, info| {
+                    let ColumnInfo {
+                        column,
+                        scalar,
+                        nullable,
+                        ..
+                    } = info;
+
+                    let type_ref = if nullable {
+                        scalar.type_ref()
+                    } else {
+                        TypeRef::NonNull(Box::new(scalar.type_ref()))
+                    };
+
+                    object.field(Field::new(E::output_name(column), type_ref, move |ctx| {
+                        let scalar = scalar.clone();
+
+                        FieldFuture::new(async move {
+                            let model = ctx.parent_value.try_downcast_ref::<E::Model>()?;
+
+                            Ok(scalar
+                                .to_graphql_value(model.get(column))
+                                .map(FieldValue::value))
+                        })
+                    }))
+                });
+
+        E::Relation::iter().fold(object, |object, relation| {
+            let def = relation.def();
+
+            let related = match entities.get(&table_ref_name(&def.to_tbl)) {
+                Some(related) => related.clone(),
+                None => return object,
+            };
+
+            let related_type = related.table_name().to_upper_camel_case();
+            let is_many = matches!(def.rel_type, RelationType::HasMany);
+            let field_name = format!("{:?}", relation).to_lower_camel_case();
+
+            // 與 derive 的 has-many 欄位相同為可為空的列表
+            let type_ref = if is_many {
+                TypeRef::named_nn_list(&related_type)
+            } else {
+                TypeRef::named(&related_type)
+            };
+
+            let keys: Vec<(String, String)> = def
+                .from_col
+                .into_iter()
+                .zip(def.to_col)
+                .map(|(from, to)| (from.to_string(), to.to_string()))
+                .collect();
+
+            let object = object.field(
+                Field::new(&field_name, type_ref, {
+                    let related = related.clone();
+                    let keys = keys.clone();
+
+                    move |ctx| {
+                        let related = related.clone();
+                        let keys = keys.clone();
+
+                        FieldFuture::new(async move {
+                            let loader = ctx.data::<DataLoader<RelationLoader>>()?;
+                            let model = ctx.parent_value.try_downcast_ref::<E::Model>()?;
+
+                            let (columns, values) = match Self::relation_values(model, &keys)? {
+                                Some(key) => key,
+                                None if is_many => {
+                                    return Ok(Some(FieldValue::list(Vec::<FieldValue>::new())))
+                                }
+                                None => return Ok(None),
+                            };
+
+                            let key = RelationKey {
+                                entity: related.clone(),
+                                columns,
+                                values,
+                                guard: GuardCondition(related.guard(&ctx, with_deleted(&ctx)?)?),
+                            };
+
+                            let mut data: Vec<FieldValue> = loader
+                                .load_one(key)
+                                .await?
+                                .unwrap_or_default()
+                                .iter()
+                                .filter_map(|row| related.field_value(row))
+                                .collect();
+
+                            Ok(if is_many {
+                                Some(FieldValue::list(data))
+                            } else if data.is_empty() {
+                                None
+                            } else {
+                                Some(data.swap_remove(0))
+                            })
+                        })
+                    }
+                })
+                .argument(InputValue::new(
+                    "withDeleted",
+                    TypeRef::named(TypeRef::BOOLEAN),
+                )),
+            );
+
+            if !is_many {
+                return object;
+            }
+
+            // has-many 關聯的聚合欄位，以外鍵條件加上子實體的過濾與守衛條件計算；
+            // 同一批次中相同條件的父資料列合併為一條 GROUP BY 查詢
+            object.field(
+                Field::new(
+                    format!("{}Aggregate", field_name),
+                    TypeRef::named_nn(format!("{}Aggregate", related_type)),
+                    move |ctx| {
+                        let related = related.clone();
+                        let keys = keys.clone();
+
+                        FieldFuture::new(async move {
+                            let loader = ctx.data::<DataLoader<RelationLoader>>()?;
+                            let model = ctx.parent_value.try_downcast_ref::<E::Model>()?;
+
+                            let (columns, values) = match Self::relation_values(model, &keys)? {
+                                Some(key) => key,
+                                None => {
+                                    return Ok(Some(
+                                        FieldValue::owned_any(AggregateData::default()),
+                                    ))
+                                }
+                            };
+
+                            let condition = related.condition(
+                                &ctx,
+                                object_arg(&ctx, "filters")?,
+                                with_deleted(&ctx)?,
+                                loader.loader().db.get_database_backend(),
+                            )?;
+
+                            let key = AggregateKey(RelationKey {
+                                entity: related.clone(),
+                                columns,
+                                values,
+                                guard: GuardCondition(condition),
+                            });
+
+                            Ok(Some(FieldValue::owned_any(
+                                loader.load_one(key).await?.unwrap_or_default(),
+                            )))
+                        })
+                    },
+                )
+                .argument(InputValue::new(
+                    "filters",
+                    TypeRef::named(format!("{}Filter", related_type)),
+                ))
+                .argument(InputValue::new(
+                    "withDeleted",
+                    TypeRef::named(TypeRef::BOOLEAN),
+                )),
+            )
+        })
+    }
+
+    /// 關聯在 `model` 中的欄位值及關聯實體中對應的欄位，任一值為 NULL 時回傳 `None`
+    #[allow(clippy::type_complexity)]
+    fn relation_values(
+        model: &E::Model,
+        keys: &[(String, String)],
+    ) -> async_graphql::Result<Option<(Vec<String>, Vec<RelationKeyStruct<(), ()>>)>> {
+        let mut columns = Vec::new();
+        let mut values = Vec::new();
+
+        for (from, to) in keys {
+            let column = E::Column::from_str(from.to_snake_case().as_str()).map_err(|_| {
+                async_graphql::Error::new(format!("unknown relation column `{}`", from))
+            })?;
+
+            let value = model.get(column);
+
+            if is_null_value(&value) {
+                return Ok(None);
+            }
+
+            columns.push(to.clone());
+            values.push(RelationKeyStruct(value, (), ()));
+        }
+
+        Ok(Some((columns, values)))
+    }
+
+    fn filter_input(types: &mut TypeMap) -> InputObject {
+        let filter_name = format!("{}Filter", Self::type_name());
+
+        Self::columns().into_iter().fold(
+            InputObject::new(&filter_name)
+                .field(InputValue::new("or", TypeRef::named_nn_list(&filter_name)))
+                .field(InputValue::new("and", TypeRef::named_nn_list(&filter_name))),
+            |filter, info| match info.scalar.register_filter(types) {
+                Some(column_filter) => {
+                    filter.field(InputValue::new(info.name, TypeRef::named(column_filter)))
+                }
+                None => filter,
+            },
+        )
+    }
+
+    fn order_by_input(entities: &EntityMap) -> InputObject {
+        let type_name = Self::type_name();
+        let mut order_by = Self::columns().into_iter().fold(
+            InputObject::new(format!("{}OrderBy", type_name)),
+            |order_by, info| {
+                order_by.field(InputValue::new(
+                    info.name,
+                    TypeRef::named(order_by_enum_name()),
+                ))
+            },
+        );
+
+        // 與 derive 相同，有欄位名為 field 或 direction 時不提供 `{ field, direction }` 形式
+        if Self::has_item_form() {
+            order_by = order_by
+                .field(InputValue::new(
+                    "field",
+                    TypeRef::named(format!("{}Column", type_name)),
+                ))
+                .field(InputValue::new(
+                    "direction",
+                    TypeRef::named(order_by_enum_name()),
+                ));
+        }
+
+        Self::belongs_to(entities)
+            .into_iter()
+            .fold(order_by, |order_by, (relation, related)| {
+                order_by.field(InputValue::new(
+                    format!("{:?}", relation).to_lower_camel_case(),
+                    TypeRef::named(format!(
+                        "{}OrderBy",
+                        related.table_name().to_upper_camel_case()
+                    )),
+                ))
+            })
+    }
+
+    fn has_item_form() -> bool {
+        Self::columns()
+            .iter()
+            .all(|info| info.name != "field" && info.name != "direction")
+    }
+
+    /// `{Table}Column` 枚舉，對應 derive 的 `QueryColumn`
+    fn column_enum() -> Enum {
+        Self::columns().into_iter().fold(
+            Enum::new(format!("{}Column", Self::type_name())),
+            |columns, info| columns.item(enum_item_name(&format!("{:?}", info.column))),
+        )
+    }
+
+    /// 已註冊目標實體的 belongs-to 關聯
+    fn belongs_to(entities: &EntityMap) -> Vec<(E::Relation, Arc<dyn DynamicEntity>)> {
+        E::Relation::iter()
+            .filter_map(|relation| {
+                let def = relation.def();
+
+                if !matches!(def.rel_type, RelationType::HasOne) || def.is_owner {
+                    return None;
+                }
+
+                entities
+                    .get(&table_ref_name(&def.to_tbl))
+                    .map(|related| (relation, related.clone()))
+            })
+            .collect()
+    }
+
+    fn mutant_input(&self, types: &mut TypeMap) -> Option<InputObject> {
+        let columns: Vec<ColumnInfo<E::Column>> = Self::columns()
+            .into_iter()
+            .filter(|info| !self.is_immutable_column(info.column))
+            .collect();
+
+        // 沒有可更新欄位時不生成，避免出現沒有欄位的 InputObject
+        if columns.is_empty() {
+            return None;
+        }
+
+        Some(columns.into_iter().fold(
+            InputObject::new(format!("{}Mutant", Self::type_name())),
+            |mutant, info| {
+                let type_ref = info.scalar.mutant_type_ref(types);
+
+                mutant.field(InputValue::new(info.name, type_ref))
+            },
+        ))
+    }
+
+    fn numeric_mutant_input(&self, types: &mut TypeMap) -> Option<InputObject> {
+        let columns: Vec<(ColumnInfo<E::Column>, String)> = Self::columns()
+            .into_iter()
+            .filter(|info| !self.is_immutable_column(info.column))
+            .filter_map(|info| {
+                let mutation = info.scalar.register_numeric_mutation(types)?;
+
+                Some((info, mutation))
+            })
+            .collect();
+
+        if columns.is_empty() {
+            return None;
+        }
+
+        Some(columns.into_iter().fold(
+            InputObject::new(format!("{}NumericMutant", Self::type_name())),
+            |mutant, (info, mutation)| {
+                mutant.field(InputValue::new(info.name, TypeRef::named(mutation)))
+            },
+        ))
+    }
+
+    fn connection_types() -> (Object, Object) {
+        let type_name = Self::type_name();
+        let edge_name = format!("{}Edge", type_name);
+
+        let edge = Object::new(&edge_name)
+            .field(Field::new(
+                "cursor",
+                TypeRef::named_nn(TypeRef::STRING),
+                |ctx| {
+                    FieldFuture::new(async move {
+                        let (cursor, _) =
+                            ctx.parent_value.try_downcast_ref::<(String, E::Model)>()?;
+
+                        Ok(Some(FieldValue::value(cursor.clone())))
+                    })
+                },
+            ))
+            .field(Field::new("node", TypeRef::named_nn(&type_name), |ctx| {
+                FieldFuture::new(async move {
+                    let (_, model) = ctx.parent_value.try_downcast_ref::<(String, E::Model)>()?;
+
+                    Ok(Some(FieldValue::owned_any(model.clone())))
+                })
+            }));
+
+        let connection = Object::new(format!("{}Connection", type_name))
+            .field(Field::new(
+                "nodes",
+                TypeRef::named_nn_list_nn(&type_name),
+                |ctx| {
+                    FieldFuture::new(async move {
+                        let data = ctx
+                            .parent_value
+                            .try_downcast_ref::<ConnectionData<E::Model>>()?;
+
+                        Ok(Some(FieldValue::list(
+                            data.nodes
+                                .iter()
+                                .map(|(_, model)| FieldValue::owned_any(model.clone())),
+                        )))
+                    })
+                },
+            ))
+            .field(Field::new(
+                "edges",
+                TypeRef::named_nn_list_nn(&edge_name),
+                |ctx| {
+                    FieldFuture::new(async move {
+                        let data = ctx
+                            .parent_value
+                            .try_downcast_ref::<ConnectionData<E::Model>>()?;
+
+                        Ok(Some(FieldValue::list(
+                            data.nodes.iter().cloned().map(FieldValue::owned_any),
+                        )))
+                    })
+                },
+            ))
+            .field(Field::new(
+                "pageInfo",
+                TypeRef::named_nn("PageInfo"),
+                |ctx| {
+                    FieldFuture::new(async move {
+                        let data = ctx
+                            .parent_value
+                            .try_downcast_ref::<ConnectionData<E::Model>>()?;
+
+                        Ok(Some(FieldValue::owned_any(data.page_info.clone())))
+                    })
+                },
+            ))
+            .field(Field::new("pages", TypeRef::named(TypeRef::INT), |ctx| {
+                FieldFuture::new(async move {
+                    let data = ctx
+                        .parent_value
+                        .try_downcast_ref::<ConnectionData<E::Model>>()?;
+
+                    Ok(data.pages.map(FieldValue::value))
+                })
+            }))
+            .field(Field::new("current", TypeRef::named(TypeRef::INT), |ctx| {
+                FieldFuture::new(async move {
+                    let data = ctx
+                        .parent_value
+                        .try_downcast_ref::<ConnectionData<E::Model>>()?;
+
+                    Ok(data.current.map(FieldValue::value))
+                })
+            }))
+            .field(Field::new(
+                "aggregate",
+                TypeRef::named_nn(format!("{}Aggregate", type_name)),
+                |ctx| {
+                    FieldFuture::new(async move {
+                        let db = &ctx.data::<DataLoader<RelationLoader>>()?.loader().db;
+                        let data = ctx
+                            .parent_value
+                            .try_downcast_ref::<ConnectionData<E::Model>>()?;
+
+                        let (_, aggregate) =
+                            Self::group_by_rows(db, &[], data.condition.clone(), None)
+                                .await?
+                                .pop()
+                                .ok_or_else(|| {
+                                    sea_orm::DbErr::RecordNotFound(
+                                        "aggregate returned no row".into(),
+                                    )
+                                })?;
+
+                        Ok(Some(FieldValue::owned_any(aggregate)))
+                    })
+                },
+            ))
+            .field(
+                Field::new(
+                    "groupBy",
+                    TypeRef::named_nn_list_nn(format!("{}GroupByRow", type_name)),
+                    |ctx| {
+                        FieldFuture::new(async move {
+                            let db = &ctx.data::<DataLoader<RelationLoader>>()?.loader().db;
+                            let data = ctx
+                                .parent_value
+                                .try_downcast_ref::<ConnectionData<E::Model>>()?;
+
+                            let by = ctx
+                                .args
+                                .try_get("by")?
+                                .list()?
+                                .iter()
+                                .map(|value| Self::column_of_item(value.enum_name()?))
+                                .collect::<async_graphql::Result<Vec<_>>>()?;
+                            let having = object_arg(&ctx, "having")?
+                                .map(|having| {
+                                    Self::having_condition(having, db.get_database_backend())
+                                })
+                                .transpose()?;
+
+                            let rows = Self::group_by_rows(db, &by, data.condition.clone(), having)
+                                .await?;
+
+                            Ok(Some(FieldValue::list(rows.into_iter().map(
+                                |(values, aggregate)| {
+                                    let key = by
+                                        .iter()
+                                        .map(|column| Self::column_name(*column))
+                                        .zip(values)
+                                        .collect();
+
+                                    FieldValue::owned_any(GroupByRowData { key, aggregate })
+                                },
+                            ))))
+                        })
+                    },
+                )
+                .argument(InputValue::new(
+                    "by",
+                    TypeRef::named_nn_list_nn(format!("{}Column", type_name)),
+                ))
+                .argument(InputValue::new(
+                    "having",
+                    TypeRef::named(format!("{}AggregateFilter", type_name)),
+                )),
+            );
+
+        (edge, connection)
+    }
+
+    /// 聚合與分組的類型，名稱與欄位與 derive 產生的相同；沒有數值欄位時只有 `count`
+    fn register_aggregate_types(types: &mut TypeMap) {
+        let type_name = Self::type_name();
+        let aggregate_name = format!("{}Aggregate", type_name);
+        let aggregate_filter_name = format!("{}AggregateFilter", type_name);
+        let sum_fields_name = format!("{}AggregateSumFields", type_name);
+        let min_max_fields_name = format!("{}AggregateMinMaxFields", type_name);
+        let sum_filter_name = format!("{}AggregateSumFilter", type_name);
+        let min_max_filter_name = format!("{}AggregateMinMaxFilter", type_name);
+        let group_by_key_name = format!("{}GroupByKey", type_name);
+        let group_by_row_name = format!("{}GroupByRow", type_name);
+        let numeric_columns = Self::numeric_columns();
+
+        let count_filter = ScalarKind::BigInteger.register_filter(types);
+
+        let mut aggregate = Object::new(&aggregate_name).field(Field::new(
+            "count",
+            TypeRef::named_nn(TypeRef::INT),
+            |ctx| {
+                FieldFuture::new(async move {
+                    let data = ctx.parent_value.try_downcast_ref::<AggregateData>()?;
+
+                    Ok(Some(FieldValue::value(data.count)))
+                })
+            },
+        ));
+        let mut aggregate_filter = InputObject::new(&aggregate_filter_name).field(InputValue::new(
+            "count",
+            TypeRef::named(count_filter.unwrap_or_default()),
+        ));
+
+        if !numeric_columns.is_empty() {
+            let mut sum_fields = Object::new(&sum_fields_name);
+            let mut min_max_fields = Object::new(&min_max_fields_name);
+            let mut sum_filter = InputObject::new(&sum_filter_name);
+            let mut min_max_filter = InputObject::new(&min_max_filter_name);
+
+            for info in numeric_columns.iter() {
+                let sum_kind = info.scalar.sum_kind();
+
+                sum_fields = sum_fields.field(column_value_field(&info.name, sum_kind.clone()));
+                min_max_fields =
+                    min_max_fields.field(column_value_field(&info.name, info.scalar.clone()));
+
+                if let Some(filter) = sum_kind.register_filter(types) {
+                    sum_filter =
+                        sum_filter.field(InputValue::new(&info.name, TypeRef::named(filter)));
+                }
+
+                if let Some(filter) = info.scalar.register_filter(types) {
+                    min_max_filter =
+                        min_max_filter.field(InputValue::new(&info.name, TypeRef::named(filter)));
+                }
+            }
+
+            for (func, name) in AGGREGATE_FUNCS {
+                let (fields_name, filter_name) = match func {
+                    AggregateFunc::Sum | AggregateFunc::Avg => (&sum_fields_name, &sum_filter_name),
+                    AggregateFunc::Min | AggregateFunc::Max => {
+                        (&min_max_fields_name, &min_max_filter_name)
+                    }
+                };
+
+                aggregate = aggregate.field(Field::new(
+                    name,
+                    TypeRef::named_nn(fields_name),
+                    move |ctx| {
+                        FieldFuture::new(async move {
+                            let data = ctx.parent_value.try_downcast_ref::<AggregateData>()?;
+
+                            Ok(Some(FieldValue::owned_any(data.values(func).clone())))
+                        })
+                    },
+                ));
+                aggregate_filter =
+                    aggregate_filter.field(InputValue::new(name, TypeRef::named(filter_name)));
+            }
+
+            types.insert_with(&sum_fields_name, || sum_fields);
+            types.insert_with(&min_max_fields_name, || min_max_fields);
+            types.insert_with(&sum_filter_name, || sum_filter);
+            types.insert_with(&min_max_filter_name, || min_max_filter);
+        }
+
+        let group_by_key = Self::columns()
+            .into_iter()
+            .fold(Object::new(&group_by_key_name), |key, info| {
+                key.field(column_value_field(&info.name, info.scalar))
+            });
+
+        let group_by_row = Object::new(&group_by_row_name)
+            .field(Field::new(
+                "key",
+                TypeRef::named_nn(&group_by_key_name),
+                |ctx| {
+                    FieldFuture::new(async move {
+                        let row = ctx.parent_value.try_downcast_ref::<GroupByRowData>()?;
+
+                        Ok(Some(FieldValue::owned_any(row.key.clone())))
+                    })
+                },
+            ))
+            .field(Field::new(
+                "aggregate",
+                TypeRef::named_nn(&aggregate_name),
+                |ctx| {
+                    FieldFuture::new(async move {
+                        let row = ctx.parent_value.try_downcast_ref::<GroupByRowData>()?;
+
+                        Ok(Some(FieldValue::owned_any(row.aggregate.clone())))
+                    })
+                },
+            ));
+
+        types.insert_with(&aggregate_name, || aggregate);
+        types.insert_with(&aggregate_filter_name, || aggregate_filter);
+        types.insert_with(&group_by_key_name, || group_by_key);
+        types.insert_with(&group_by_row_name, || group_by_row);
+    }
+
+    fn filter_condition(
+        filter: ObjectAccessor<'_>,
+        backend: sea_orm::DbBackend,
+    ) -> async_graphql::Result<Condition> {
+        let mut condition = Condition::all();
+
+        if let Some(or_filters) = filter.get("or").filter(|value| !value.is_null()) {
+            let mut or_condition = Condition::any();
+
+            for or_filter in or_filters.list()?.iter() {
+                or_condition =
+                    or_condition.add(Self::filter_condition(or_filter.object()?, backend)?);
+            }
+
+            condition = condition.add(or_condition);
+        }
+
+        if let Some(and_filters) = filter.get("and").filter(|value| !value.is_null()) {
+            let mut and_condition = Condition::all();
+
+            for and_filter in and_filters.list()?.iter() {
+                and_condition =
+                    and_condition.add(Self::filter_condition(and_filter.object()?, backend)?);
+            }
+
+            condition = condition.add(and_condition);
+        }
+
+        for info in Self::columns() {
+            if let Some(value) = filter.get(&info.name).filter(|value| !value.is_null()) {
+                condition = condition.add(info.scalar.filter_condition(
+                    info.column,
+                    Expr::col((E::default(), info.column)).into(),
+                    &value,
+                    backend,
+                )?);
+            }
+        }
+
+        Ok(condition)
+    }
+
+    /// `{Table}Column` 枚舉值對應的欄位
+    fn column_of_item(item: &str) -> async_graphql::Result<E::Column> {
+        Self::columns()
+            .into_iter()
+            .find(|info| enum_item_name(&format!("{:?}", info.column)) == item)
+            .map(|info| info.column)
+            .ok_or_else(|| async_graphql::Error::new(format!("unknown column `{}`", item)))
+    }
+
+    /// `key` 為本身欄位或 `field` 時回傳其排序項目，值為 null 時項目為 `None`；
+    /// 不是本身欄位時回傳 `None`
+    #[allow(clippy::type_complexity)]
+    fn order_item(
+        order_by: &ObjectAccessor<'_>,
+        key: &str,
+        value: &ValueAccessor<'_>,
+    ) -> async_graphql::Result<Option<Option<(E::Column, OrderByEnum)>>> {
+        if Self::has_item_form() && key == "field" {
+            let direction = order_by.get("direction").filter(|value| !value.is_null());
+
+            return match (value.is_null(), direction) {
+                (true, None) => Ok(Some(None)),
+                (false, Some(direction)) => Ok(Some(Some((
+                    Self::column_of_item(value.enum_name()?)?,
+                    parse_enum::<OrderByEnum>(direction.enum_name()?)?,
+                )))),
+                _ => Err(async_graphql::Error::new(
+                    "orderBy `field` and `direction` must be given together",
+                )),
+            };
+        }
+
+        if Self::has_item_form() && key == "direction" {
+            // 先寫 direction 時在 field 處理
+            let has_field = order_by
+                .get("field")
+                .filter(|value| !value.is_null())
+                .is_some();
+
+            if has_field || value.is_null() {
+                return Ok(Some(None));
+            }
+
+            return Err(async_graphql::Error::new(
+                "orderBy `field` and `direction` must be given together",
+            ));
+        }
+
+        match Self::columns().into_iter().find(|info| info.name == key) {
+            Some(_) if value.is_null() => Ok(Some(None)),
+            Some(info) => Ok(Some(Some((
+                info.column,
+                parse_enum::<OrderByEnum>(value.enum_name()?)?,
+            )))),
+            None => Ok(None),
+        }
+    }
+
+    /// `key` 為本身欄位或 `field` 時將其排序運算式加入 `exprs`，不是本身欄位時回傳 `false`
+    fn push_order_expr<T>(
+        table: T,
+        order_by: &ObjectAccessor<'_>,
+        key: &str,
+        value: &ValueAccessor<'_>,
+        exprs: &mut Vec<OrderExpr>,
+    ) -> async_graphql::Result<bool>
+    where
+        T: IntoIden + Clone + 'static,
+    {
+        match Self::order_item(order_by, key, value)? {
+            Some(Some((column, direction))) => {
+                exprs.push((
+                    Expr::col((table, column)).into(),
+                    direction.order(),
+                    direction.nulls(),
+                ));
+
+                Ok(true)
+            }
+            Some(None) => Ok(true),
+            None => Ok(false),
+        }
+    }
+
+    /// 依書寫順序列出排序運算式，`alias` 為本實體 JOIN 時的別名，根查詢為 `None`
+    ///
+    /// belongs-to 關聯以 `{alias}_{關聯}` 別名 LEFT JOIN 並遞迴處理，同一別名只加入 `joins` 一次。
+    fn order_by_exprs_in(
+        alias: Option<&str>,
+        order_by: &ObjectAccessor<'_>,
+        entities: &EntityMap,
+        joins: &mut Vec<(RelationDef, String)>,
+    ) -> async_graphql::Result<Vec<OrderExpr>> {
+        let table: DynIden = match alias {
+            Some(alias) => SeaRc::new(Alias::new(alias)),
+            None => SeaRc::new(E::default()),
+        };
+        let belongs_to = Self::belongs_to(entities);
+        let mut exprs = Vec::new();
+
+        for (key, value) in order_by.iter() {
+            if Self::push_order_expr(table.clone(), order_by, key, &value, &mut exprs)?
+                || value.is_null()
+            {
+                continue;
+            }
+
+            let related = belongs_to.iter().find(|(relation, _)| {
+                format!("{:?}", relation).to_lower_camel_case() == key.as_str()
+            });
+
+            if let Some((relation, related)) = related {
+                let join_alias = format!(
+                    "{}_{}",
+                    alias.unwrap_or("order_by"),
+                    format!("{:?}", relation).to_snake_case()
+                );
+
+                if !joins.iter().any(|(_, joined)| joined == &join_alias) {
+                    let mut def = relation.def();
+
+                    if let Some(alias) = alias {
+                        def.from_tbl = def.from_tbl.alias(Alias::new(alias));
+                    }
+
+                    joins.push((def, join_alias.clone()));
+                }
+
+                exprs.extend(related.order_by_exprs(
+                    &join_alias,
+                    &value.object()?,
+                    entities,
+                    joins,
+                )?);
+            }
+        }
+
+        Ok(exprs)
+    }
+
+    /// 依列表順序、同一物件中依書寫順序套用排序，belongs-to 關聯欄位以 LEFT JOIN 排序
+    fn apply_order_by(
+        stmt: sea_orm::Select<E>,
+        order_by: ValueAccessor<'_>,
+        entities: &EntityMap,
+    ) -> async_graphql::Result<sea_orm::Select<E>> {
+        // 單一物件視為只有一項的列表
+        let list = order_by.list().ok();
+        let order_by_list = match &list {
+            Some(list) => list
+                .iter()
+                .map(|value| value.object())
+                .collect::<async_graphql::Result<Vec<_>>>()?,
+            None => vec![order_by.object()?],
+        };
+
+        let mut exprs = Vec::new();
+        let mut joins = Vec::new();
+
+        for order_by in order_by_list.iter() {
+            exprs.extend(Self::order_by_exprs_in(
+                None, order_by, entities, &mut joins,
+            )?);
+        }
+
+        let stmt = joins.into_iter().fold(stmt, |stmt, (def, alias)| {
+            stmt.join_as(JoinType::LeftJoin, def, Alias::new(alias))
+        });
+
+        Ok(apply_order_exprs(stmt, exprs))
+    }
+
+    /// `distinctOn` 的排序項目，取自 `orderBy` 中本身的欄位
+    ///
+    /// 與 derive 的 `distinct_on_guarded` 相同，belongs-to 關聯欄位不能排在 `distinctOn`
+    /// 對應的排序項目之前，之後的關聯欄位不參與排名。
+    fn distinct_on_items(
+        order_by: Option<&ValueAccessor<'_>>,
+        columns: usize,
+        entities: &EntityMap,
+    ) -> async_graphql::Result<Vec<(E::Column, OrderByEnum)>> {
+        let order_by = match order_by {
+            Some(order_by) => order_by,
+            None => return Ok(Vec::new()),
+        };
+
+        let list = order_by.list().ok();
+        let order_by_list = match &list {
+            Some(list) => list
+                .iter()
+                .map(|value| value.object())
+                .collect::<async_graphql::Result<Vec<_>>>()?,
+            None => vec![order_by.object()?],
+        };
+
+        let belongs_to = Self::belongs_to(entities);
+        let mut items = Vec::new();
+
+        for order_by in order_by_list.iter() {
+            for (key, value) in order_by.iter() {
+                if let Some(item) = Self::order_item(order_by, key, &value)? {
+                    items.extend(item);
+                    continue;
+                }
+
+                let is_related = belongs_to.iter().any(|(relation, _)| {
+                    format!("{:?}", relation).to_lower_camel_case() == key.as_str()
+                });
+
+                if is_related && !value.is_null() && items.len() < columns {
+                    return Err(async_graphql::Error::new(format!(
+                        "distinctOn columns must match the leading orderBy columns, got related `{}`",
+                        key,
+                    )));
+                }
+            }
+        }
+
+        Ok(items)
+    }
+
+    /// 以欄位運算式更新，與 derive 的 `update_many_with_mutant`、`update_many_with_numeric_mutant` 相同
+    fn apply_mutant(
+        stmt: sea_orm::UpdateMany<E>,
+        mutant: Option<ObjectAccessor<'_>>,
+        numeric_mutant: Option<ObjectAccessor<'_>>,
+        backend: sea_orm::DbBackend,
+    ) -> async_graphql::Result<sea_orm::UpdateMany<E>> {
+        let mut stmt = stmt;
+
+        for info in Self::columns() {
+            if is_ignored_mutant_column(info.column) {
+                continue;
+            }
+
+            if let Some(value) = mutant
+                .as_ref()
+                .and_then(|mutant| mutant.get(&info.name))
+                .filter(|value| !value.is_null())
+            {
+                stmt = stmt.col_expr(
+                    info.column,
+                    info.scalar.mutant_expr(info.column, &value, backend)?,
+                );
+            }
+
+            if let Some(value) = numeric_mutant
+                .as_ref()
+                .and_then(|numeric_mutant| numeric_mutant.get(&info.name))
+                .filter(|value| !value.is_null())
+            {
+                if let Some(expr) = info.scalar.numeric_expr(info.column, &value)? {
+                    stmt = stmt.col_expr(info.column, expr);
+                }
+            }
+        }
+
+        Ok(stmt)
+    }
+
+    fn relation_columns(columns: &[String]) -> async_graphql::Result<Vec<E::Column>> {
+        columns
+            .iter()
+            .map(|name| {
+                E::Column::from_str(name.to_snake_case().as_str()).map_err(|_| {
+                    async_graphql::Error::new(format!("unknown relation column `{}`", name))
+                })
+            })
+            .collect()
+    }
+
+    fn primary_key_columns() -> Vec<E::Column> {
+        E::PrimaryKey::iter()
+            .map(|primary_key| primary_key.into_column())
+            .collect()
+    }
+
+    fn cursor_of(model: &E::Model) -> String {
+        let values: Vec<sea_orm::Value> = Self::primary_key_columns()
+            .into_iter()
+            .map(|column| model.get(column))
+            .collect();
+
+        // CursorValues 只能編碼整數、字串與 UUID 主鍵
+        if values.iter().all(is_cursor_value) {
+            CursorValues(values).encode_cursor()
+        } else {
+            String::new()
+        }
+    }
+
+    fn numeric_columns() -> Vec<ColumnInfo<E::Column>> {
+        Self::columns()
+            .into_iter()
+            .filter(|info| info.scalar.is_numeric())
+            .collect()
+    }
+
+    /// 在查詢中加入聚合欄位，與 derive 的 `aggregate_select` 相同
+    fn aggregate_select(
+        stmt: sea_orm::Select<E>,
+        backend: sea_orm::DbBackend,
+    ) -> sea_orm::Select<E> {
+        let stmt = stmt.column_as(Expr::col(Asterisk).count(), "count");
+
+        Self::numeric_columns()
+            .into_iter()
+            .fold(stmt, |stmt, info| {
+                let as_double = matches!(info.scalar.sum_kind(), ScalarKind::Double);
+
+                AGGREGATE_FUNCS
+                    .into_iter()
+                    .fold(stmt, |stmt, (func, prefix)| {
+                        stmt.column_as(
+                            aggregate_expr(
+                                func,
+                                info.column.into_simple_expr(),
+                                backend,
+                                as_double,
+                            ),
+                            format!("{}_{}", prefix, info.column.as_str()),
+                        )
+                    })
+            })
+    }
+
+    /// 讀取 `aggregate_select` 加入的聚合欄位
+    fn aggregate_from_result(
+        result: &sea_orm::QueryResult,
+    ) -> Result<AggregateData, sea_orm::DbErr> {
+        let mut data = AggregateData {
+            count: result.try_get("", "count")?,
+            ..Default::default()
+        };
+
+        for info in Self::numeric_columns() {
+            for (func, prefix) in AGGREGATE_FUNCS {
+                let scalar = match func {
+                    AggregateFunc::Sum | AggregateFunc::Avg => info.scalar.sum_kind(),
+                    AggregateFunc::Min | AggregateFunc::Max => info.scalar.clone(),
+                };
+                let value =
+                    scalar.try_get(result, &format!("{}_{}", prefix, info.column.as_str()))?;
+
+                data.values_mut(func).insert(info.name.clone(), value);
+            }
+        }
+
+        Ok(data)
+    }
+
+    /// 分組查詢的 HAVING 條件，與 derive 的 `AggregateFilter::to_condition` 相同
+    fn having_condition(
+        having: ObjectAccessor<'_>,
+        backend: sea_orm::DbBackend,
+    ) -> async_graphql::Result<Condition> {
+        let mut condition = Condition::all();
+
+        if let Some(count) = having.get("count").filter(|value| !value.is_null()) {
+            condition = condition.add(
+                parse_input::<TypeFilter<i64>>(&count)?.to_condition(Expr::col(Asterisk).count()),
+            );
+        }
+
+        for (func, prefix) in AGGREGATE_FUNCS {
+            let filter = match having.get(prefix).filter(|value| !value.is_null()) {
+                Some(filter) => filter.object()?,
+                None => continue,
+            };
+
+            for info in Self::numeric_columns() {
+                if let Some(value) = filter.get(&info.name).filter(|value| !value.is_null()) {
+                    let sum_kind = info.scalar.sum_kind();
+                    let scalar = match func {
+                        AggregateFunc::Sum | AggregateFunc::Avg => &sum_kind,
+                        AggregateFunc::Min | AggregateFunc::Max => &info.scalar,
+                    };
+                    let expr = aggregate_expr(
+                        func,
+                        info.column.into_simple_expr(),
+                        backend,
+                        matches!(sum_kind, ScalarKind::Double),
+                    );
+
+                    condition = condition.add(scalar.filter_condition(
+                        info.column,
+                        expr,
+                        &value,
+                        backend,
+                    )?);
+                }
+            }
+        }
+
+        Ok(condition)
+    }
+
+    /// 依 `by` 分組計算聚合結果並回傳各組 `by` 欄位的值，`by` 為空時只有一組；
+    /// `condition` 作為 WHERE、`having` 作為 HAVING 條件
+    async fn group_by_rows(
+        db: &DatabaseConnection,
+        by: &[E::Column],
+        condition: Condition,
+        having: Option<Condition>,
+    ) -> async_graphql::Result<GroupByRows> {
+        let backend = db.get_database_backend();
+        let columns = Self::columns();
+        let mut keys = Vec::new();
+        let mut stmt = E::find().filter(condition).select_only();
+
+        for column in by.iter() {
+            let info = columns
+                .iter()
+                .find(|info| info.column.as_str() == column.as_str())
+                .ok_or_else(|| {
+                    async_graphql::Error::new(format!("unknown column `{}`", column.as_str()))
+                })?;
+            let alias = format!("key_{:?}", column);
+
+            // select_as 會將 Postgres 枚舉轉為 text，與 Model 讀取方式一致
+            stmt = stmt
+                .column_as(
+                    column.select_as(Expr::col((E::default(), *column))),
+                    alias.as_str(),
+                )
+                .group_by(*column);
+
+            keys.push((info.scalar.clone(), alias));
+        }
+
+        let mut stmt = Self::aggregate_select(stmt, backend);
+
+        if let Some(having) = having {
+            stmt = stmt.having(having);
+        }
+
+        let rows = db
+            .query_all(stmt.build(backend))
+            .await?
+            .iter()
+            .map(|result| {
+                let values = keys
+                    .iter()
+                    .map(|(scalar, alias)| scalar.try_get(result, alias))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                Ok((values, Self::aggregate_from_result(result)?))
+            })
+            .collect::<Result<Vec<_>, sea_orm::DbErr>>()?;
+
+        Ok(rows)
+    }
+
+    /// 以 `keys` 欄位查詢單筆資料的欄位，同一請求中的多次查詢經由 DataLoader 合併為一條 SQL
+    fn lookup_field(
+        name: String,
+        keys: Vec<&ColumnInfo<E::Column>>,
+        entities: Arc<EntityMap>,
+    ) -> Field {
+        let args: Vec<(String, String, ScalarKind)> = keys
+            .iter()
+            .map(|info| {
+                (
+                    info.name.clone(),
+                    info.column.as_str().to_owned(),
+                    info.scalar.clone(),
+                )
+            })
+            .collect();
+
+        let field = Field::new(name, TypeRef::named(Self::type_name()), {
+            let args = args.clone();
+
+            move |ctx| {
+                let entities = entities.clone();
+                let args = args.clone();
+
+                FieldFuture::new(async move {
+                    let loader = ctx.data::<DataLoader<RelationLoader>>()?;
+                    let entity = entities
+                        .get(E::default().table_name())
+                        .cloned()
+                        .ok_or_else(|| async_graphql::Error::new("entity is not registered"))?;
+
+                    let mut columns = Vec::new();
+                    let mut values = Vec::new();
+
+                    for (name, column, scalar) in args.iter() {
+                        columns.push(column.clone());
+                        values.push(RelationKeyStruct(
+                            scalar.parse_value(&ctx.args.try_get(name)?)?,
+                            (),
+                            (),
+                        ));
+                    }
+
+                    let key = RelationKey {
+                        entity: entity.clone(),
+                        columns,
+                        values,
+                        guard: GuardCondition(entity.guard(&ctx, None)?),
+                    };
+
+                    Ok(loader
+                        .load_one(key)
+                        .await?
+                        .unwrap_or_default()
+                        .first()
+                        .and_then(|row| entity.field_value(row)))
+                })
+            }
+        });
+
+        args.into_iter().fold(field, |field, (name, _, scalar)| {
+            field.argument(InputValue::new(
+                name,
+                TypeRef::NonNull(Box::new(scalar.type_ref())),
+            ))
+        })
+    }
+
+    async fn query(
+        db: &DatabaseConnection,
+        stmt: sea_orm::Select<E>,
+        condition: Condition,
+        pagination: Option<ObjectAccessor<'_>>,
+    ) -> async_graphql::Result<ConnectionData<E::Model>> {
+        let pages = pagination
+            .as_ref()
+            .and_then(|pagination| pagination.get("pages"))
+            .filter(|value| !value.is_null());
+        let cursor = pagination
+            .as_ref()
+            .and_then(|pagination| pagination.get("cursor"))
+            .filter(|value| !value.is_null());
+
+        let (models, has_previous_page, has_next_page, pages, current) = if let Some(pages) = pages
+        {
+            let pages = pages.object()?;
+            let limit = pages.try_get("limit")?.u64()?;
+            let page = pages.try_get("page")?.u64()?;
+
+            if limit == 0 {
+                return Err(async_graphql::Error::new(
+                    "pagination limit must be positive",
+                ));
+            }
+
+            let paginator = stmt.paginate(db, limit);
+            let total = paginator.num_pages().await?;
+            let models = paginator.fetch_page(page).await?;
+
+            (models, page > 0, page + 1 < total, Some(total), Some(page))
+        } else if let Some(cursor) = cursor {
+            let cursor = cursor.object()?;
+            let limit = cursor.try_get("limit")?.u64()?;
+            let primary_keys = Self::primary_key_columns();
+
+            let mut stmt = stmt;
+            let after = cursor
+                .get("cursor")
+                .filter(|value| !value.is_null())
+                .map(|value| value.string())
+                .transpose()?;
+
+            if let Some(after) = after {
+                let values =
+                    CursorValues::decode_cursor(after).map_err(async_graphql::Error::new)?;
+
+                stmt = stmt.filter(
+                    Expr::tuple(
+                        primary_keys
+                            .iter()
+                            .map(|column| Expr::col((E::default(), *column)).into()),
+                    )
+                    .gt(Expr::tuple(
+                        values.0.into_iter().map(|value| Expr::val(value).into()),
+                    )),
+                );
+            }
+
+            let stmt = primary_keys
+                .iter()
+                .fold(stmt, |stmt, column| stmt.order_by_asc(*column));
+
+            let mut models = stmt.limit(limit + 1).all(db).await?;
+            let has_next_page = models.len() as u64 > limit;
+            models.truncate(limit as usize);
+
+            (models, after.is_some(), has_next_page, None, None)
+        } else {
+            (stmt.all(db).await?, false, false, None, None)
+        };
+
+        let nodes: Vec<(String, E::Model)> = models
+            .into_iter()
+            .map(|model| (Self::cursor_of(&model), model))
+            .collect();
+
+        Ok(ConnectionData {
+            page_info: PageInfoData {
+                has_previous_page,
+                has_next_page,
+                start_cursor: nodes.first().map(|(cursor, _)| cursor.clone()),
+                end_cursor: nodes.last().map(|(cursor, _)| cursor.clone()),
+            },
+            nodes,
+            pages,
+            current,
+            condition,
+        })
+    }
+}
+
+impl<E> DynamicEntity for EntityMeta<E>
+where
+    E: EntityOutput,
+    E::Model: Sync,
+{
+    fn table_name(&self) -> String {
+        E::default().table_name().to_owned()
+    }
+
+    fn guard(
+        &self,
+        ctx: &async_graphql::Context<'_>,
+        with_deleted: Option<bool>,
+    ) -> async_graphql::Result<Condition> {
+        (self.guard)(ctx, with_deleted)
+    }
+
+    fn register_types(&self, entities: &EntityMap, types: &mut TypeMap) {
+        register_shared_types(types);
+
+        let type_name = Self::type_name();
+
+        let object = Self::object(entities);
+        let mutant = self.mutant_input(types);
+        let filter = Self::filter_input(types);
+        let order_by = Self::order_by_input(entities);
+        let columns = Self::column_enum();
+        let (edge, connection) = Self::connection_types();
+
+        types.insert_with(&type_name, || object);
+        types.insert_with(&format!("{}Filter", type_name), || filter);
+        types.insert_with(&format!("{}OrderBy", type_name), || order_by);
+        types.insert_with(&format!("{}Column", type_name), || columns);
+        types.insert_with(&format!("{}Edge", type_name), || edge);
+        types.insert_with(&format!("{}Connection", type_name), || connection);
+
+        if let Some(mutant) = mutant {
+            types.insert_with(&format!("{}Mutant", type_name), || mutant);
+        }
+
+        Self::register_aggregate_types(types);
+
+        if let Some(numeric_mutant) = self.numeric_mutant_input(types) {
+            types.insert_with(&format!("{}NumericMutant", type_name), || numeric_mutant);
+        }
+
+        for info in Self::columns() {
+            info.scalar.register_output(types);
+        }
+    }
+
+    fn condition(
+        &self,
+        ctx: &async_graphql::Context<'_>,
+        filters: Option<ObjectAccessor<'_>>,
+        with_deleted: Option<bool>,
+        backend: sea_orm::DbBackend,
+    ) -> async_graphql::Result<Condition> {
+        let condition = (self.guard)(ctx, with_deleted)?;
+
+        match filters {
+            Some(filters) => Ok(condition.add(Self::filter_condition(filters, backend)?)),
+            None => Ok(condition),
+        }
+    }
+
+    fn query_fields(&self, entities: Arc<EntityMap>) -> Vec<Field> {
+        let type_name = Self::type_name();
+        let table_name = E::default().table_name().to_owned();
+        let guard = self.guard;
+
+        let connection = Field::new(
+            table_name.to_lower_camel_case(),
+            TypeRef::named_nn(format!("{}Connection", type_name)),
+            {
+                let entities = entities.clone();
+
+                move |ctx| {
+                    let entities = entities.clone();
+
+                    FieldFuture::new(async move {
+                        let db = &ctx.data::<DataLoader<RelationLoader>>()?.loader().db;
+                        let backend = db.get_database_backend();
+
+                        let mut condition = guard(&ctx, with_deleted(&ctx)?)?;
+
+                        if let Some(filters) = object_arg(&ctx, "filters")? {
+                            condition = condition.add(Self::filter_condition(filters, backend)?);
+                        }
+
+                        let mut stmt = E::find().filter(condition.clone());
+                        let order_by = ctx.args.get("orderBy").filter(|value| !value.is_null());
+
+                        if let Some(distinct) =
+                            ctx.args.get("distinctOn").filter(|value| !value.is_null())
+                        {
+                            let columns = distinct
+                                .list()?
+                                .iter()
+                                .map(|value| Self::column_of_item(value.enum_name()?))
+                                .collect::<async_graphql::Result<Vec<_>>>()?;
+                            let items = Self::distinct_on_items(
+                                order_by.as_ref(),
+                                columns.len(),
+                                &entities,
+                            )?;
+
+                            stmt = distinct_on(stmt, columns, condition.clone(), &items, backend)?;
+                        }
+
+                        if let Some(order_by) = order_by {
+                            stmt = Self::apply_order_by(stmt, order_by, &entities)?;
+                        }
+
+                        let data =
+                            Self::query(db, stmt, condition, object_arg(&ctx, "pagination")?)
+                                .await?;
+
+                        Ok(Some(FieldValue::owned_any(data)))
+                    })
+                }
+            },
+        )
+        .argument(InputValue::new(
+            "filters",
+            TypeRef::named(format!("{}Filter", type_name)),
+        ))
+        .argument(InputValue::new("pagination", TypeRef::named("Pagination")))
+        .argument(InputValue::new(
+            "orderBy",
+            TypeRef::named_nn_list(format!("{}OrderBy", type_name)),
+        ))
+        .argument(InputValue::new(
+            "distinctOn",
+            TypeRef::named_nn_list(format!("{}Column", type_name)),
+        ))
+        .argument(InputValue::new(
+            "withDeleted",
+            TypeRef::named(TypeRef::BOOLEAN),
+        ));
+
+        // 與 derive 的 `by_id`、`by_{column}` 相同，浮點數無法作為 DataLoader 的鍵
+        let columns: Vec<ColumnInfo<E::Column>> = Self::columns()
+            .into_iter()
+            .filter(|info| !matches!(info.scalar, ScalarKind::Float | ScalarKind::Double))
+            .collect();
+        let primary_keys = Self::primary_key_columns();
+        let key_of = |column: &E::Column| {
+            columns
+                .iter()
+                .find(|info| info.column.as_str() == column.as_str())
+        };
+
+        let mut lookups: Vec<(String, Vec<&ColumnInfo<E::Column>>)> = Vec::new();
+
+        if let Some(keys) = primary_keys
+            .iter()
+            .map(key_of)
+            .collect::<Option<Vec<_>>>()
+            .filter(|keys| !keys.is_empty())
+        {
+            lookups.push((format!("{}_by_id", table_name), keys));
+        }
+
+        for info in columns.iter() {
+            if info.column.def().is_unique()
+                && !primary_keys
+                    .iter()
+                    .any(|column| column.as_str() == info.column.as_str())
+            {
+                lookups.push((
+                    format!("{}_by_{}", table_name, info.column.as_str()),
+                    vec![info],
+                ));
+            }
+        }
+
+        let mut fields = vec![connection];
+
+        for (name, keys) in lookups {
+            fields.push(Self::lookup_field(
+                name.to_lower_camel_case(),
+                keys,
+                entities.clone(),
+            ));
+        }
+
+        fields
+    }
+
+    fn mutation_field(&self) -> Option<Field> {
+        let type_name = Self::type_name();
+        let guard = self.guard;
+        let set_updated_at = self.set_updated_at;
+        let version_column = self.version_column;
+        let has_mutant = self.mutant_input(&mut TypeMap::default()).is_some();
+        let has_numeric_mutant = self.numeric_mutant_input(&mut TypeMap::default()).is_some();
+
+        if !has_mutant && !has_numeric_mutant {
+            return None;
+        }
+
+        let mut field = Field::new(
+            format!(
+                "{}UpdateMany",
+                E::default().table_name().to_lower_camel_case()
+            ),
+            TypeRef::named_nn(TypeRef::INT),
+            move |ctx| {
+                FieldFuture::new(async move {
+                    let db = &ctx.data::<DataLoader<RelationLoader>>()?.loader().db;
+                    let backend = db.get_database_backend();
+
+                    let mut stmt = Self::apply_mutant(
+                        E::update_many(),
+                        object_arg(&ctx, "mutant")?,
+                        object_arg(&ctx, "numericMutant")?,
+                        backend,
+                    )?;
+
+                    let mut condition = guard(&ctx, None)?;
+
+                    if let Some(filters) = object_arg(&ctx, "filters")? {
+                        condition = condition.add(Self::filter_condition(filters, backend)?);
+                    }
+
+                    // 只更新版本仍相符的資料列並將版本加一
+                    if let Some(column) = version_column {
+                        let expected_version = ctx.args.try_get("expectedVersion")?.i64()?;
+
+                        stmt = stmt
+                            .col_expr(column, Expr::col((E::default(), column)).add(1))
+                            .filter(column.eq(expected_version));
+                    }
+
+                    let result = set_updated_at(stmt)
+                        .filter(condition.clone())
+                        .exec(db)
+                        .await?;
+
+                    // 沒有更新任何資料時，只有資料仍存在但版本不符才是 CONFLICT
+                    if version_column.is_some()
+                        && result.rows_affected == 0
+                        && E::find().filter(condition).count(db).await? > 0
+                    {
+                        return Err(version_conflict());
+                    }
+
+                    Ok(Some(FieldValue::value(result.rows_affected)))
+                })
+            },
+        )
+        .argument(InputValue::new(
+            "filters",
+            TypeRef::named(format!("{}Filter", type_name)),
+        ));
+
+        if has_mutant {
+            field = field.argument(InputValue::new(
+                "mutant",
+                TypeRef::named_nn(format!("{}Mutant", type_name)),
+            ));
+        }
+
+        if has_numeric_mutant {
+            field = field.argument(InputValue::new(
+                "numericMutant",
+                TypeRef::named(format!("{}NumericMutant", type_name)),
+            ));
+        }
+
+        if self.version_column.is_some() {
+            field = field.argument(InputValue::new(
+                "expectedVersion",
+                TypeRef::named_nn(TypeRef::INT),
+            ));
+        }
+
+        Some(field)
+    }
+
+    fn order_by_exprs(
+        &self,
+        alias: &str,
+        order_by: &ObjectAccessor<'_>,
+        entities: &EntityMap,
+        joins: &mut Vec<(RelationDef, String)>,
+    ) -> async_graphql::Result<Vec<OrderExpr>> {
+        Self::order_by_exprs_in(Some(alias), order_by, entities, joins)
+    }
+
+    fn find_by_keys<'a>(
+        &'a self,
+        db: &'a DatabaseConnection,
+        columns: &'a [String],
+        keys: Vec<Vec<sea_orm::Value>>,
+        guard: Condition,
+    ) -> BoxFuture<'a, async_graphql::Result<RelatedRows>> {
+        Box::pin(async move {
+            let columns = Self::relation_columns(columns)?;
+
+            Ok(E::find()
+                .filter(guard)
+                .filter(keys_condition(&columns, keys))
+                .all(db)
+                .await?
+                .into_iter()
+                .map(|model| {
+                    let values = columns.iter().map(|column| model.get(*column)).collect();
+
+                    (values, Arc::new(model) as RelatedRow)
+                })
+                .collect())
+        })
+    }
+
+    fn field_value(&self, row: &RelatedRow) -> Option<FieldValue<'static>> {
+        row.downcast_ref::<E::Model>()
+            .cloned()
+            .map(FieldValue::owned_any)
+    }
+
+    fn aggregate_by_keys<'a>(
+        &'a self,
+        db: &'a DatabaseConnection,
+        columns: &'a [String],
+        keys: Vec<Vec<sea_orm::Value>>,
+        condition: Condition,
+    ) -> BoxFuture<'a, async_graphql::Result<GroupByRows>> {
+        Box::pin(async move {
+            let columns = Self::relation_columns(columns)?;
+
+            Self::group_by_rows(
+                db,
+                &columns,
+                condition.add(keys_condition(&columns, keys)),
+                None,
+            )
+            .await
+        })
+    }
+}
+
+fn register_shared_types(types: &mut TypeMap) {
+    types.insert_with(&order_by_enum_name(), || {
+        OrderByEnum::items()
+            .iter()
+            .fold(Enum::new(order_by_enum_name()), |enumeration, item| {
+                enumeration.item(item.name)
+            })
+    });
+
+    types.insert_with("PageInput", || {
+        InputObject::new("PageInput")
+            .field(InputValue::new("limit", TypeRef::named_nn(TypeRef::INT)))
+            .field(InputValue::new("page", TypeRef::named_nn(TypeRef::INT)))
+    });
+
+    types.insert_with("CursorInput", || {
+        InputObject::new("CursorInput")
+            .field(InputValue::new("cursor", TypeRef::named(TypeRef::STRING)))
+            .field(InputValue::new("limit", TypeRef::named_nn(TypeRef::INT)))
+    });
+
+    types.insert_with("Pagination", || {
+        InputObject::new("Pagination")
+            .field(InputValue::new("pages", TypeRef::named("PageInput")))
+            .field(InputValue::new("cursor", TypeRef::named("CursorInput")))
+            .oneof()
+    });
+
+    types.insert_with("PageInfo", || {
+        Object::new("PageInfo")
+            .field(Field::new(
+                "hasPreviousPage",
+                TypeRef::named_nn(TypeRef::BOOLEAN),
+                |ctx| {
+                    FieldFuture::new(async move {
+                        let data = ctx.parent_value.try_downcast_ref::<PageInfoData>()?;
+
+                        Ok(Some(FieldValue::value(data.has_previous_page)))
+                    })
+                },
+            ))
+            .field(Field::new(
+                "hasNextPage",
+                TypeRef::named_nn(TypeRef::BOOLEAN),
+                |ctx| {
+                    FieldFuture::new(async move {
+                        let data = ctx.parent_value.try_downcast_ref::<PageInfoData>()?;
+
+                        Ok(Some(FieldValue::value(data.has_next_page)))
+                    })
+                },
+            ))
+            .field(Field::new(
+                "startCursor",
+                TypeRef::named(TypeRef::STRING),
+                |ctx| {
+                    FieldFuture::new(async move {
+                        let data = ctx.parent_value.try_downcast_ref::<PageInfoData>()?;
+
+                        Ok(data.start_cursor.clone().map(FieldValue::value))
+                    })
+                },
+            ))
+            .field(Field::new(
+                "endCursor",
+                TypeRef::named(TypeRef::STRING),
+                |ctx| {
+                    FieldFuture::new(async move {
+                        let data = ctx.parent_value.try_downcast_ref::<PageInfoData>()?;
+
+                        Ok(data.end_cursor.clone().map(FieldValue::value))
+                    })
+                },
+            ))
+    });
+}
+
+/// 欄位的 GraphQL 類型，名稱與 derive 產生的靜態類型一致
+#[derive(Debug, Clone)]
+enum ScalarKind {
+    TinyInteger,
+    SmallInteger,
+    Integer,
+    BigInteger,
+    TinyUnsigned,
+    SmallUnsigned,
+    Unsigned,
+    BigUnsigned,
+    Float,
+    Double,
+    String,
+    Boolean,
+    Binary,
+    #[cfg(feature = "with-decimal")]
+    Decimal,
+    #[cfg(feature = "with-chrono")]
+    Date,
+    #[cfg(feature = "with-chrono")]
+    Time,
+    #[cfg(feature = "with-chrono")]
+    DateTime,
+    #[cfg(feature = "with-chrono")]
+    DateTimeWithTimeZone,
+    #[cfg(feature = "with-json")]
+    Json,
+    #[cfg(feature = "with-uuid")]
+    Uuid,
+    Enum {
+        name: String,
+        variants: Vec<String>,
+    },
+    /// 元素類型見 `is_array_element`
+    #[cfg(feature = "with-array")]
+    Array(Box<ScalarKind>),
+}
+
+/// 以數組元素對應的 Rust 類型 `$T` 展開 `$body`，元素類型須通過 `is_array_element`
+#[cfg(feature = "with-array")]
+macro_rules! with_array_element {
+    ($element:expr, $T:ident => $body:expr) => {
+        match $element {
+            ScalarKind::TinyInteger => {
+                type $T = i8;
+                $body
+            }
+            ScalarKind::SmallInteger => {
+                type $T = i16;
+                $body
+            }
+            ScalarKind::Integer => {
+                type $T = i32;
+                $body
+            }
+            ScalarKind::BigInteger => {
+                type $T = i64;
+                $body
+            }
+            ScalarKind::Unsigned => {
+                type $T = u32;
+                $body
+            }
+            ScalarKind::Float => {
+                type $T = f32;
+                $body
+            }
+            ScalarKind::Double => {
+                type $T = f64;
+                $body
+            }
+            ScalarKind::Boolean => {
+                type $T = bool;
+                $body
+            }
+            ScalarKind::String => {
+                type $T = String;
+                $body
+            }
+            #[cfg(feature = "with-decimal")]
+            ScalarKind::Decimal => {
+                type $T = sea_orm::prelude::Decimal;
+                $body
+            }
+            #[cfg(feature = "with-chrono")]
+            ScalarKind::Date => {
+                type $T = sea_orm::prelude::Date;
+                $body
+            }
+            #[cfg(feature = "with-chrono")]
+            ScalarKind::DateTime => {
+                type $T = sea_orm::prelude::DateTime;
+                $body
+            }
+            #[cfg(feature = "with-chrono")]
+            ScalarKind::DateTimeWithTimeZone => {
+                type $T = sea_orm::prelude::DateTimeWithTimeZone;
+                $body
+            }
+            #[cfg(feature = "with-uuid")]
+            ScalarKind::Uuid => {
+                type $T = sea_orm::prelude::Uuid;
+                $body
+            }
+            element => unreachable!("unsupported array element {:?}", element),
+        }
+    };
+}
+
+impl ScalarKind {
+    /// 不支援的欄位類型回傳 `None`，不會出現在 schema 中：
+    /// `Interval`、網路位址、位元串、`Year`、`Vector`、`LTree` 與 `Custom`，
+    /// 未啟用對應 feature 的 decimal、chrono、json、uuid 與數組類型，
+    /// 以及 `is_array_element` 之外的數組元素類型
+    fn from_column_type(column_type: &ColumnType) -> Option<Self> {
+        Some(match column_type {
+            ColumnType::TinyInteger => Self::TinyInteger,
+            ColumnType::SmallInteger => Self::SmallInteger,
+            ColumnType::Integer => Self::Integer,
+            ColumnType::BigInteger => Self::BigInteger,
+            ColumnType::TinyUnsigned => Self::TinyUnsigned,
+            ColumnType::SmallUnsigned => Self::SmallUnsigned,
+            ColumnType::Unsigned => Self::Unsigned,
+            ColumnType::BigUnsigned => Self::BigUnsigned,
+            ColumnType::Float => Self::Float,
+            ColumnType::Double => Self::Double,
+            ColumnType::Char(_) | ColumnType::String(_) | ColumnType::Text => Self::String,
+            ColumnType::Boolean => Self::Boolean,
+            ColumnType::Binary(_) | ColumnType::VarBinary(_) | ColumnType::Blob => Self::Binary,
+            #[cfg(feature = "with-decimal")]
+            ColumnType::Decimal(_) | ColumnType::Money(_) => Self::Decimal,
+            #[cfg(feature = "with-chrono")]
+            ColumnType::Date => Self::Date,
+            #[cfg(feature = "with-chrono")]
+            ColumnType::Time => Self::Time,
+            #[cfg(feature = "with-chrono")]
+            ColumnType::DateTime | ColumnType::Timestamp => Self::DateTime,
+            #[cfg(feature = "with-chrono")]
+            ColumnType::TimestampWithTimeZone => Self::DateTimeWithTimeZone,
+            #[cfg(feature = "with-json")]
+            ColumnType::Json | ColumnType::JsonBinary => Self::Json,
+            #[cfg(feature = "with-uuid")]
+            ColumnType::Uuid => Self::Uuid,
+            ColumnType::Enum { name, variants } => Self::Enum {
+                name: name.to_string().to_upper_camel_case(),
+                variants: variants.iter().map(|variant| variant.to_string()).collect(),
+            },
+            #[cfg(feature = "with-array")]
+            ColumnType::Array(element) => {
+                let element = Self::from_column_type(element)?;
+
+                if !element.is_array_element() {
+                    return None;
+                }
+
+                Self::Array(Box::new(element))
+            }
+            _ => return None,
+        })
+    }
+
+    /// 有對應 `ArrayFilter` 與 `ArrayMutation` 的數組元素類型；
+    /// `Vec<u8>` 為二進位而非數組，枚舉數組的過濾類型由 `EnumFilter` 生成；
+    /// SeaORM 無法從資料庫讀取 `Vec<u16>` 與 `Vec<u64>`，故不列入
+    #[cfg(feature = "with-array")]
+    fn is_array_element(&self) -> bool {
+        #[cfg(feature = "with-decimal")]
+        if matches!(self, Self::Decimal) {
+            return true;
+        }
+
+        #[cfg(feature = "with-chrono")]
+        if matches!(
+            self,
+            Self::Date | Self::DateTime | Self::DateTimeWithTimeZone
+        ) {
+            return true;
+        }
+
+        #[cfg(feature = "with-uuid")]
+        if matches!(self, Self::Uuid) {
+            return true;
+        }
+
+        matches!(
+            self,
+            Self::TinyInteger
+                | Self::SmallInteger
+                | Self::Integer
+                | Self::BigInteger
+                | Self::Unsigned
+                | Self::Float
+                | Self::Double
+                | Self::Boolean
+                | Self::String
+        )
+    }
+
+    fn type_ref(&self) -> TypeRef {
+        match self {
+            Self::Binary => TypeRef::named_nn_list(TypeRef::INT),
+            #[cfg(feature = "with-array")]
+            Self::Array(element) => {
+                TypeRef::List(Box::new(TypeRef::NonNull(Box::new(element.type_ref()))))
+            }
+            _ => TypeRef::named(self.type_name()),
+        }
+    }
+
+    /// `Mutant` 中的類型，數組欄位以 `ArrayMutation` 修改
+    fn mutant_type_ref(&self, types: &mut TypeMap) -> TypeRef {
+        #[cfg(feature = "with-array")]
+        if let Self::Array(element) = self {
+            let mutation_name = with_array_element!(element.as_ref(), T => {
+                input_type_name::<crate::ArrayMutation<T>>()
+            });
+            let list = self.type_ref();
+
+            types.insert_with(&mutation_name, || {
+                ["set", "push", "remove", "addToSet"]
+                    .into_iter()
+                    .fold(InputObject::new(&mutation_name), |mutation, operator| {
+                        mutation.field(InputValue::new(operator, list.clone()))
+                    })
+            });
+
+            return TypeRef::named(mutation_name);
+        }
+
+        self.register_output(types);
+
+        self.type_ref()
+    }
+
+    /// `sum` 與 `avg` 的結果類型，與 derive 相同 Decimal 保留 Decimal，其餘為 f64
+    fn sum_kind(&self) -> Self {
+        #[cfg(feature = "with-decimal")]
+        if matches!(self, Self::Decimal) {
+            return Self::Decimal;
+        }
+
+        Self::Double
+    }
+
+    fn type_name(&self) -> String {
+        match self {
+            Self::TinyInteger
+            | Self::SmallInteger
+            | Self::Integer
+            | Self::BigInteger
+            | Self::TinyUnsigned
+            | Self::SmallUnsigned
+            | Self::Unsigned
+            | Self::BigUnsigned
+            | Self::Binary => TypeRef::INT.into(),
+            Self::Float | Self::Double => TypeRef::FLOAT.into(),
+            Self::String => TypeRef::STRING.into(),
+            Self::Boolean => TypeRef::BOOLEAN.into(),
+            #[cfg(feature = "with-decimal")]
+            Self::Decimal => input_type_name::<sea_orm::prelude::Decimal>(),
+            #[cfg(feature = "with-chrono")]
+            Self::Date => input_type_name::<sea_orm::prelude::Date>(),
+            #[cfg(feature = "with-chrono")]
+            Self::Time => input_type_name::<sea_orm::prelude::Time>(),
+            #[cfg(feature = "with-chrono")]
+            Self::DateTime => input_type_name::<sea_orm::prelude::DateTime>(),
+            #[cfg(feature = "with-chrono")]
+            Self::DateTimeWithTimeZone => {
+                input_type_name::<sea_orm::prelude::DateTimeWithTimeZone>()
+            }
+            #[cfg(feature = "with-json")]
+            Self::Json => input_type_name::<sea_orm::prelude::Json>(),
+            #[cfg(feature = "with-uuid")]
+            Self::Uuid => input_type_name::<sea_orm::prelude::Uuid>(),
+            Self::Enum { name, .. } => name.clone(),
+            #[cfg(feature = "with-array")]
+            Self::Array(element) => element.type_name(),
+        }
+    }
+
+    /// 過濾類型的名稱前綴，對應 `TypeFilter`、`TemporalFilter` 等的 concrete 名稱
+    fn filter_prefix(&self) -> Option<String> {
+        Some(
+            match self {
+                Self::TinyInteger => "TinyInteger",
+                Self::SmallInteger => "SmallInteger",
+                Self::Integer => "Integer",
+                Self::BigInteger => "BigInteger",
+                Self::TinyUnsigned => "TinyUnsigned",
+                Self::SmallUnsigned => "SmallUnsigned",
+                Self::Unsigned => "Unsigned",
+                Self::BigUnsigned => "BigUnsigned",
+                Self::Float => "Float",
+                Self::Double => "Double",
+                Self::String => "String",
+                Self::Boolean => "Boolean",
+                Self::Binary => "Binary",
+                #[cfg(feature = "with-decimal")]
+                Self::Decimal => "Decimal",
+                #[cfg(feature = "with-chrono")]
+                Self::Date => "Date",
+                #[cfg(feature = "with-chrono")]
+                Self::Time => "Time",
+                #[cfg(feature = "with-chrono")]
+                Self::DateTime => "DateTime",
+                #[cfg(feature = "with-chrono")]
+                Self::DateTimeWithTimeZone => "DateTimeWithTimeZone",
+                #[cfg(feature = "with-json")]
+                Self::Json => "Json",
+                #[cfg(feature = "with-uuid")]
+                Self::Uuid => "Uuid",
+                Self::Enum { name, .. } => return Some(format!("{}Enum", name)),
+                #[cfg(feature = "with-array")]
+                Self::Array(_) => return None,
+            }
+            .to_owned(),
+        )
+    }
+
+    /// 有 `year`、`month`、`dayOfWeek` 日期部分運算的類型，`Time` 沒有日期部分
+    fn is_temporal(&self) -> bool {
+        #[cfg(feature = "with-chrono")]
+        if matches!(
+            self,
+            Self::Date | Self::DateTime | Self::DateTimeWithTimeZone
+        ) {
+            return true;
+        }
+
+        false
+    }
+
+    /// 註冊輸出所需的自訂標量與枚舉類型
+    fn register_output(&self, types: &mut TypeMap) {
+        let type_name = self.type_name();
+
+        match self {
+            #[cfg(feature = "with-array")]
+            Self::Array(element) => element.register_output(types),
+            Self::Enum { variants, .. } => types.insert_with(&type_name, || {
+                variants
+                    .iter()
+                    .fold(Enum::new(&type_name), |enumeration, variant| {
+                        enumeration.item(enum_item_name(variant))
+                    })
+            }),
+            _ if [
+                TypeRef::INT,
+                TypeRef::FLOAT,
+                TypeRef::STRING,
+                TypeRef::BOOLEAN,
+            ]
+            .contains(&type_name.as_str()) => {}
+            _ => types.insert_with(&type_name, || Scalar::new(&type_name)),
+        }
+    }
+
+    /// 註冊欄位的過濾類型並回傳其名稱
+    fn register_filter(&self, types: &mut TypeMap) -> Option<String> {
+        #[cfg(feature = "with-json")]
+        if matches!(self, Self::Json) {
+            return Some(register_json_filter(types));
+        }
+
+        #[cfg(feature = "with-array")]
+        if let Self::Array(element) = self {
+            return Some(register_array_filter(element, types));
+        }
+
+        let prefix = self.filter_prefix()?;
+        let filter_name = format!("{}Filter", prefix);
+        let range_name = format!("{}Range", prefix);
+        let value_type = self.type_ref();
+        // 枚舉、布林與二進位沒有意義的順序，不提供區間運算
+        let has_range = !matches!(self, Self::Enum { .. } | Self::Boolean | Self::Binary);
+
+        self.register_output(types);
+
+        let non_null = |type_ref: &TypeRef| TypeRef::NonNull(Box::new(type_ref.clone()));
+        let list = |type_ref: &TypeRef| TypeRef::List(Box::new(non_null(type_ref)));
+
+        if has_range {
+            types.insert_with(&range_name, || {
+                InputObject::new(&range_name)
+                    .field(InputValue::new("from", non_null(&value_type)))
+                    .field(InputValue::new("to", non_null(&value_type)))
+            });
+        }
+
+        if self.is_temporal() {
+            ScalarKind::Integer.register_filter(types);
+        }
+
+        types.insert_with(&filter_name, || {
+            let mut filter = InputObject::new(&filter_name);
+
+            if matches!(self, Self::String) {
+                filter = filter.field(InputValue::new("like", value_type.clone()));
+            }
+
+            for operator in ["eq", "ne", "gt", "gte", "lt", "lte"] {
+                filter = filter.field(InputValue::new(operator, value_type.clone()));
+            }
+
+            filter = filter
+                .field(InputValue::new("isIn", list(&value_type)))
+                .field(InputValue::new("isNotIn", list(&value_type)))
+                .field(InputValue::new("isNull", TypeRef::named(TypeRef::BOOLEAN)));
+
+            if has_range {
+                filter = filter
+                    .field(InputValue::new("between", TypeRef::named(&range_name)))
+                    .field(InputValue::new("notBetween", TypeRef::named(&range_name)));
+            }
+
+            if self.is_temporal() {
+                for part in ["year", "month", "dayOfWeek"] {
+                    filter = filter.field(InputValue::new(part, TypeRef::named("IntegerFilter")));
+                }
+            }
+
+            filter
+        });
+
+        Some(filter_name)
+    }
+
+    /// 解析過濾輸入並轉換為條件，與 derive 產生的 `try_filter_recursive` 使用相同的過濾器實作
+    fn filter_condition<C: ColumnTrait>(
+        &self,
+        column: C,
+        expr: SimpleExpr,
+        value: &ValueAccessor<'_>,
+        #[allow(unused_variables)] backend: sea_orm::DbBackend,
+    ) -> async_graphql::Result<Condition> {
+        Ok(match self {
+            Self::TinyInteger => parse_input::<TypeFilter<i8>>(value)?.to_condition(expr),
+            Self::SmallInteger => parse_input::<TypeFilter<i16>>(value)?.to_condition(expr),
+            Self::Integer => parse_input::<TypeFilter<i32>>(value)?.to_condition(expr),
+            Self::BigInteger => parse_input::<TypeFilter<i64>>(value)?.to_condition(expr),
+            Self::TinyUnsigned => parse_input::<TypeFilter<u8>>(value)?.to_condition(expr),
+            Self::SmallUnsigned => parse_input::<TypeFilter<u16>>(value)?.to_condition(expr),
+            Self::Unsigned => parse_input::<TypeFilter<u32>>(value)?.to_condition(expr),
+            Self::BigUnsigned => parse_input::<TypeFilter<u64>>(value)?.to_condition(expr),
+            Self::Float => parse_input::<TypeFilter<f32>>(value)?.to_condition(expr),
+            Self::Double => parse_input::<TypeFilter<f64>>(value)?.to_condition(expr),
+            Self::String => parse_input::<StringFilter<String>>(value)?.to_condition(expr),
+            Self::Boolean => parse_input::<BasicFilter<bool>>(value)?.to_condition(expr),
+            Self::Binary => {
+                parse_input::<BasicFilter<crate::BinaryVector>>(value)?.to_condition(expr)
+            }
+            #[cfg(feature = "with-decimal")]
+            Self::Decimal => {
+                parse_input::<TypeFilter<sea_orm::prelude::Decimal>>(value)?.to_condition(expr)
+            }
+            #[cfg(feature = "with-chrono")]
+            Self::Date => parse_input::<crate::DateFilter>(value)?.to_condition(expr, backend),
+            #[cfg(feature = "with-chrono")]
+            Self::Time => parse_input::<crate::TimeFilter>(value)?.to_condition(expr),
+            #[cfg(feature = "with-chrono")]
+            Self::DateTime => {
+                parse_input::<crate::DateTimeFilter>(value)?.to_condition(expr, backend)
+            }
+            #[cfg(feature = "with-chrono")]
+            Self::DateTimeWithTimeZone => {
+                parse_input::<crate::DateTimeWithTimeZoneFilter>(value)?.to_condition(expr, backend)
+            }
+            #[cfg(feature = "with-json")]
+            Self::Json => parse_input::<crate::JsonFilter>(value)?.to_condition(expr, backend)?,
+            #[cfg(feature = "with-uuid")]
+            Self::Uuid => {
+                parse_input::<TypeFilter<sea_orm::prelude::Uuid>>(value)?.to_condition(expr)
+            }
+            #[cfg(feature = "with-array")]
+            Self::Array(element) => with_array_element!(element.as_ref(), T => {
+                parse_input::<crate::ArrayFilter<T>>(value)?.to_condition(column, backend)?
+            }),
+            Self::Enum { .. } => {
+                let filter = value.object()?;
+                let mut condition = Condition::all();
+
+                // 透過欄位方法比較，Postgres 會自動將值轉型為資料庫枚舉類型
+                let parse = |value: &ValueAccessor<'_>| self.parse_value(value);
+
+                for (operator, value) in filter.iter() {
+                    if value.is_null() {
+                        continue;
+                    }
+
+                    let operator_condition = match operator.as_str() {
+                        "eq" => column.eq(parse(&value)?),
+                        "ne" => column.ne(parse(&value)?),
+                        "gt" => column.gt(parse(&value)?),
+                        "gte" => column.gte(parse(&value)?),
+                        "lt" => column.lt(parse(&value)?),
+                        "lte" => column.lte(parse(&value)?),
+                        "isIn" => column.is_in(
+                            value
+                                .list()?
+                                .iter()
+                                .map(|value| parse(&value))
+                                .collect::<Result<Vec<_>, _>>()?,
+                        ),
+                        "isNotIn" => column.is_not_in(
+                            value
+                                .list()?
+                                .iter()
+                                .map(|value| parse(&value))
+                                .collect::<Result<Vec<_>, _>>()?,
+                        ),
+                        "isNull" if value.boolean()? => column.is_null(),
+                        _ => continue,
+                    };
+
+                    condition = condition.add(operator_condition);
+                }
+
+                condition
+            }
+        })
+    }
+
+    /// 解析單一輸入值，用於 `Mutant` 與枚舉過濾
+    fn parse_value(&self, value: &ValueAccessor<'_>) -> async_graphql::Result<sea_orm::Value> {
+        Ok(match self {
+            Self::TinyInteger => parse_input::<i8>(value)?.into(),
+            Self::SmallInteger => parse_input::<i16>(value)?.into(),
+            Self::Integer => parse_input::<i32>(value)?.into(),
+            Self::BigInteger => parse_input::<i64>(value)?.into(),
+            Self::TinyUnsigned => parse_input::<u8>(value)?.into(),
+            Self::SmallUnsigned => parse_input::<u16>(value)?.into(),
+            Self::Unsigned => parse_input::<u32>(value)?.into(),
+            Self::BigUnsigned => parse_input::<u64>(value)?.into(),
+            Self::Float => parse_input::<f32>(value)?.into(),
+            Self::Double => parse_input::<f64>(value)?.into(),
+            Self::String => parse_input::<String>(value)?.into(),
+            Self::Boolean => parse_input::<bool>(value)?.into(),
+            Self::Binary => parse_input::<crate::BinaryVector>(value)?.into(),
+            #[cfg(feature = "with-decimal")]
+            Self::Decimal => parse_input::<sea_orm::prelude::Decimal>(value)?.into(),
+            #[cfg(feature = "with-chrono")]
+            Self::Date => parse_input::<sea_orm::prelude::Date>(value)?.into(),
+            #[cfg(feature = "with-chrono")]
+            Self::Time => parse_input::<sea_orm::prelude::Time>(value)?.into(),
+            #[cfg(feature = "with-chrono")]
+            Self::DateTime => parse_input::<sea_orm::prelude::DateTime>(value)?.into(),
+            #[cfg(feature = "with-chrono")]
+            Self::DateTimeWithTimeZone => {
+                parse_input::<sea_orm::prelude::DateTimeWithTimeZone>(value)?.into()
+            }
+            #[cfg(feature = "with-json")]
+            Self::Json => parse_input::<sea_orm::prelude::Json>(value)?.into(),
+            #[cfg(feature = "with-uuid")]
+            Self::Uuid => parse_input::<sea_orm::prelude::Uuid>(value)?.into(),
+            #[cfg(feature = "with-array")]
+            Self::Array(element) => with_array_element!(element.as_ref(), T => {
+                parse_input::<Vec<T>>(value)?.into()
+            }),
+            Self::Enum { variants, .. } => {
+                let item = value.enum_name()?;
+
+                variants
+                    .iter()
+                    .find(|variant| enum_item_name(variant) == item)
+                    .map(|variant| sea_orm::Value::String(Some(Box::new(variant.clone()))))
+                    .ok_or_else(|| {
+                        async_graphql::Error::new(format!("unknown enum item `{}`", item))
+                    })?
+            }
+        })
+    }
+
+    fn is_numeric(&self) -> bool {
+        #[cfg(feature = "with-decimal")]
+        if matches!(self, Self::Decimal) {
+            return true;
+        }
+
+        matches!(
+            self,
+            Self::TinyInteger
+                | Self::SmallInteger
+                | Self::Integer
+                | Self::BigInteger
+                | Self::TinyUnsigned
+                | Self::SmallUnsigned
+                | Self::Unsigned
+                | Self::BigUnsigned
+                | Self::Float
+                | Self::Double
+        )
+    }
+
+    /// 註冊數值欄位的 `NumericMutation` 類型並回傳其名稱
+    fn register_numeric_mutation(&self, types: &mut TypeMap) -> Option<String> {
+        if !self.is_numeric() {
+            return None;
+        }
+
+        let mutation_name = format!("{}Mutation", self.filter_prefix()?);
+        let value_type = self.type_ref();
+
+        self.register_output(types);
+
+        types.insert_with(&mutation_name, || {
+            ["increment", "decrement", "multiply"]
+                .into_iter()
+                .fold(InputObject::new(&mutation_name), |mutation, operator| {
+                    mutation.field(InputValue::new(operator, value_type.clone()))
+                })
+        });
+
+        Some(mutation_name)
+    }
+
+    fn numeric_expr<C: ColumnTrait>(
+        &self,
+        column: C,
+        value: &ValueAccessor<'_>,
+    ) -> async_graphql::Result<Option<SimpleExpr>> {
+        Ok(match self {
+            Self::TinyInteger => parse_input::<NumericMutation<i8>>(value)?.to_expr(column),
+            Self::SmallInteger => parse_input::<NumericMutation<i16>>(value)?.to_expr(column),
+            Self::Integer => parse_input::<NumericMutation<i32>>(value)?.to_expr(column),
+            Self::BigInteger => parse_input::<NumericMutation<i64>>(value)?.to_expr(column),
+            Self::TinyUnsigned => parse_input::<NumericMutation<u8>>(value)?.to_expr(column),
+            Self::SmallUnsigned => parse_input::<NumericMutation<u16>>(value)?.to_expr(column),
+            Self::Unsigned => parse_input::<NumericMutation<u32>>(value)?.to_expr(column),
+            Self::BigUnsigned => parse_input::<NumericMutation<u64>>(value)?.to_expr(column),
+            Self::Float => parse_input::<NumericMutation<f32>>(value)?.to_expr(column),
+            Self::Double => parse_input::<NumericMutation<f64>>(value)?.to_expr(column),
+            #[cfg(feature = "with-decimal")]
+            Self::Decimal => {
+                parse_input::<NumericMutation<sea_orm::prelude::Decimal>>(value)?.to_expr(column)
+            }
+            _ => None,
+        })
+    }
+
+    /// `Mutant` 欄位的更新運算式，數組欄位只有 Postgres 支援
+    fn mutant_expr<C: ColumnTrait>(
+        &self,
+        column: C,
+        value: &ValueAccessor<'_>,
+        #[allow(unused_variables)] backend: sea_orm::DbBackend,
+    ) -> async_graphql::Result<SimpleExpr> {
+        #[cfg(feature = "with-array")]
+        if let Self::Array(element) = self {
+            if backend != sea_orm::DbBackend::Postgres {
+                return Err(async_graphql::Error::new(format!(
+                    "array mutation on column `{}` is only supported on Postgres, got {:?}",
+                    column.as_str(),
+                    backend,
+                )));
+            }
+
+            return Ok(with_array_element!(element.as_ref(), T => {
+                parse_input::<crate::ArrayMutation<T>>(value)?.to_expr(column)
+            }));
+        }
+
+        Ok(column.save_as(Expr::val(self.parse_value(value)?)))
+    }
+
+    /// 讀取查詢結果中 `alias` 欄位的值，用於聚合與分組鍵
+    fn try_get(
+        &self,
+        result: &sea_orm::QueryResult,
+        alias: &str,
+    ) -> Result<sea_orm::Value, sea_orm::DbErr> {
+        Ok(match self {
+            Self::TinyInteger => result.try_get::<Option<i8>>("", alias)?.into(),
+            Self::SmallInteger => result.try_get::<Option<i16>>("", alias)?.into(),
+            Self::Integer => result.try_get::<Option<i32>>("", alias)?.into(),
+            Self::BigInteger => result.try_get::<Option<i64>>("", alias)?.into(),
+            Self::TinyUnsigned => result.try_get::<Option<u8>>("", alias)?.into(),
+            Self::SmallUnsigned => result.try_get::<Option<u16>>("", alias)?.into(),
+            Self::Unsigned => result.try_get::<Option<u32>>("", alias)?.into(),
+            Self::BigUnsigned => result.try_get::<Option<u64>>("", alias)?.into(),
+            Self::Float => result.try_get::<Option<f32>>("", alias)?.into(),
+            Self::Double => result.try_get::<Option<f64>>("", alias)?.into(),
+            // 枚舉以 select_as 轉為文字讀取
+            Self::String | Self::Enum { .. } => result.try_get::<Option<String>>("", alias)?.into(),
+            Self::Boolean => result.try_get::<Option<bool>>("", alias)?.into(),
+            Self::Binary => result.try_get::<Option<Vec<u8>>>("", alias)?.into(),
+            #[cfg(feature = "with-decimal")]
+            Self::Decimal => result
+                .try_get::<Option<sea_orm::prelude::Decimal>>("", alias)?
+                .into(),
+            #[cfg(feature = "with-chrono")]
+            Self::Date => result
+                .try_get::<Option<sea_orm::prelude::Date>>("", alias)?
+                .into(),
+            #[cfg(feature = "with-chrono")]
+            Self::Time => result
+                .try_get::<Option<sea_orm::prelude::Time>>("", alias)?
+                .into(),
+            #[cfg(feature = "with-chrono")]
+            Self::DateTime => result
+                .try_get::<Option<sea_orm::prelude::DateTime>>("", alias)?
+                .into(),
+            #[cfg(feature = "with-chrono")]
+            Self::DateTimeWithTimeZone => result
+                .try_get::<Option<sea_orm::prelude::DateTimeWithTimeZone>>("", alias)?
+                .into(),
+            #[cfg(feature = "with-json")]
+            Self::Json => result
+                .try_get::<Option<sea_orm::prelude::Json>>("", alias)?
+                .into(),
+            #[cfg(feature = "with-uuid")]
+            Self::Uuid => result
+                .try_get::<Option<sea_orm::prelude::Uuid>>("", alias)?
+                .into(),
+            #[cfg(feature = "with-array")]
+            Self::Array(element) => with_array_element!(element.as_ref(), T => {
+                result.try_get::<Option<Vec<T>>>("", alias)?.into()
+            }),
+        })
+    }
+
+    fn to_graphql_value(&self, value: sea_orm::Value) -> Option<async_graphql::Value> {
+        use async_graphql::Value;
+
+        Some(match value {
+            sea_orm::Value::Bool(Some(value)) => Value::Boolean(value),
+            sea_orm::Value::TinyInt(Some(value)) => Value::from(value),
+            sea_orm::Value::SmallInt(Some(value)) => Value::from(value),
+            sea_orm::Value::Int(Some(value)) => Value::from(value),
+            sea_orm::Value::BigInt(Some(value)) => Value::from(value),
+            sea_orm::Value::TinyUnsigned(Some(value)) => Value::from(value),
+            sea_orm::Value::SmallUnsigned(Some(value)) => Value::from(value),
+            sea_orm::Value::Unsigned(Some(value)) => Value::from(value),
+            sea_orm::Value::BigUnsigned(Some(value)) => Value::from(value),
+            sea_orm::Value::Float(Some(value)) => Value::from(value),
+            sea_orm::Value::Double(Some(value)) => Value::from(value),
+            sea_orm::Value::String(Some(value)) => match self {
+                Self::Enum { .. } => Value::Enum(async_graphql::Name::new(enum_item_name(&value))),
+                _ => Value::String(*value),
+            },
+            sea_orm::Value::Char(Some(value)) => Value::String(value.to_string()),
+            sea_orm::Value::Bytes(Some(value)) => {
+                Value::List(value.into_iter().map(Value::from).collect())
+            }
+            #[cfg(feature = "with-json")]
+            sea_orm::Value::Json(Some(value)) => Value::from_json(*value).ok()?,
+            #[cfg(feature = "with-chrono")]
+            sea_orm::Value::ChronoDate(Some(value)) => {
+                async_graphql::ScalarType::to_value(value.as_ref())
+            }
+            #[cfg(feature = "with-chrono")]
+            sea_orm::Value::ChronoTime(Some(value)) => {
+                async_graphql::ScalarType::to_value(value.as_ref())
+            }
+            #[cfg(feature = "with-chrono")]
+            sea_orm::Value::ChronoDateTime(Some(value)) => {
+                async_graphql::ScalarType::to_value(value.as_ref())
+            }
+            #[cfg(feature = "with-chrono")]
+            sea_orm::Value::ChronoDateTimeUtc(Some(value)) => {
+                async_graphql::ScalarType::to_value(value.as_ref())
+            }
+            #[cfg(feature = "with-chrono")]
+            sea_orm::Value::ChronoDateTimeLocal(Some(value)) => {
+                async_graphql::ScalarType::to_value(value.as_ref())
+            }
+            #[cfg(feature = "with-chrono")]
+            sea_orm::Value::ChronoDateTimeWithTimeZone(Some(value)) => {
+                async_graphql::ScalarType::to_value(value.as_ref())
+            }
+            #[cfg(feature = "with-decimal")]
+            sea_orm::Value::Decimal(Some(value)) => {
+                async_graphql::ScalarType::to_value(value.as_ref())
+            }
+            #[cfg(feature = "with-uuid")]
+            sea_orm::Value::Uuid(Some(value)) => {
+                async_graphql::ScalarType::to_value(value.as_ref())
+            }
+            #[cfg(feature = "with-array")]
+            sea_orm::Value::Array(_, Some(values)) => match self {
+                Self::Array(element) => Value::List(
+                    values
+                        .into_iter()
+                        .map(|value| element.to_graphql_value(value).unwrap_or(Value::Null))
+                        .collect(),
+                ),
+                _ => return None,
+            },
+            _ => return None,
+        })
+    }
+}
+
+/// 與 derive 的 `Mutant` 相同，不允許更新的欄位
+fn is_ignored_mutant_column<C: IdenStatic>(column: C) -> bool {
+    ["id", "created_at"].contains(&column.as_str())
+}
+
+fn apply_order_exprs<E: EntityTrait>(
+    stmt: sea_orm::Select<E>,
+    exprs: Vec<OrderExpr>,
+) -> sea_orm::Select<E> {
+    exprs
+        .into_iter()
+        .fold(stmt, |stmt, (expr, order, nulls)| match nulls {
+            Some(nulls) => stmt.order_by_with_nulls(expr, order, nulls),
+            None => stmt.order_by(expr, order),
+        })
+}
+
+#[cfg(feature = "with-json")]
+fn register_json_filter(types: &mut TypeMap) -> String {
+    let json = input_type_name::<sea_orm::prelude::Json>();
+    let filter_name = input_type_name::<crate::JsonFilter>();
+
+    types.insert_with(&json, || Scalar::new(&json));
+
+    types.insert_with(&filter_name, || {
+        let filter = InputObject::new(&filter_name).field(InputValue::new(
+            "path",
+            TypeRef::named_nn_list(TypeRef::STRING),
+        ));
+
+        ["eq", "ne", "gt", "gte", "lt", "lte"]
+            .into_iter()
+            .fold(filter, |filter, operator| {
+                filter.field(InputValue::new(operator, TypeRef::named(&json)))
+            })
+            .field(InputValue::new("isIn", TypeRef::named_nn_list(&json)))
+            .field(InputValue::new("isNotIn", TypeRef::named_nn_list(&json)))
+            .field(InputValue::new("isNull", TypeRef::named(TypeRef::BOOLEAN)))
+            .field(InputValue::new("hasKey", TypeRef::named(TypeRef::STRING)))
+            .field(InputValue::new(
+                "hasKeys",
+                TypeRef::named_nn_list(TypeRef::STRING),
+            ))
+            .field(InputValue::new("contains", TypeRef::named(&json)))
+            .field(InputValue::new("containedBy", TypeRef::named(&json)))
+    });
+
+    filter_name
+}
+
+/// 註冊數組元素的 `ArrayFilter` 並回傳其名稱
+#[cfg(feature = "with-array")]
+fn register_array_filter(element: &ScalarKind, types: &mut TypeMap) -> String {
+    let filter_name = with_array_element!(element, T => {
+        input_type_name::<crate::ArrayFilter<T>>()
+    });
+    let list = TypeRef::List(Box::new(TypeRef::NonNull(Box::new(element.type_ref()))));
+
+    element.register_output(types);
+    ScalarKind::Integer.register_filter(types);
+
+    types.insert_with(&filter_name, || {
+        InputObject::new(&filter_name)
+            .field(InputValue::new("eq", list.clone()))
+            .field(InputValue::new("ne", list.clone()))
+            .field(InputValue::new("isNull", TypeRef::named(TypeRef::BOOLEAN)))
+            .field(InputValue::new("contains", list.clone()))
+            .field(InputValue::new("containsAny", list.clone()))
+            .field(InputValue::new("containedBy", list))
+            .field(InputValue::new("length", TypeRef::named("IntegerFilter")))
+            .field(InputValue::new("isEmpty", TypeRef::named(TypeRef::BOOLEAN)))
+    });
+
+    filter_name
+}
+
+/// `columns` 的值為 `keys` 之一的條件，單一欄位以 IN 查詢，複合鍵以 OR 串接各鍵的條件
+fn keys_condition<C: ColumnTrait>(columns: &[C], keys: Vec<Vec<sea_orm::Value>>) -> Condition {
+    match columns {
+        [column] => {
+            Condition::all().add(column.is_in(keys.into_iter().filter_map(|mut key| key.pop())))
+        }
+        _ => keys.into_iter().fold(Condition::any(), |condition, key| {
+            condition.add(
+                columns
+                    .iter()
+                    .zip(key)
+                    .fold(Condition::all(), |condition, (column, value)| {
+                        condition.add(column.eq(value))
+                    }),
+            )
+        }),
+    }
+}
+
+/// 讀取 `ColumnValues` 中 `name` 的欄位，用於聚合結果與分組鍵
+fn column_value_field(name: &str, scalar: ScalarKind) -> Field {
+    let key = name.to_owned();
+
+    Field::new(name, scalar.type_ref(), move |ctx| {
+        let key = key.clone();
+        let scalar = scalar.clone();
+
+        FieldFuture::new(async move {
+            let values = ctx.parent_value.try_downcast_ref::<ColumnValues>()?;
+
+            Ok(values
+                .get(&key)
+                .cloned()
+                .and_then(|value| scalar.to_graphql_value(value))
+                .map(FieldValue::value))
+        })
+    })
+}
+
+/// `withDeleted` 參數，未提供時為 `None`
+fn with_deleted(ctx: &ResolverContext<'_>) -> async_graphql::Result<Option<bool>> {
+    ctx.args
+        .get("withDeleted")
+        .filter(|value| !value.is_null())
+        .map(|value| value.boolean())
+        .transpose()
+}
+
+/// 輸入物件參數，未提供或為 null 時為 `None`
+fn object_arg<'a>(
+    ctx: &'a ResolverContext<'_>,
+    name: &str,
+) -> async_graphql::Result<Option<ObjectAccessor<'a>>> {
+    ctx.args
+        .get(name)
+        .filter(|value| !value.is_null())
+        .map(|value| value.object())
+        .transpose()
+}
+
+fn order_by_enum_name() -> String {
+    <OrderByEnum as async_graphql::InputType>::type_name().into_owned()
+}
+
+/// 枚舉值在 GraphQL 中的名稱，與 async-graphql 對 derive 枚舉的命名規則一致
+fn enum_item_name(variant: &str) -> String {
+    variant.to_upper_camel_case().to_shouty_snake_case()
+}
+
+#[allow(dead_code)]
+fn input_type_name<T: async_graphql::InputType>() -> String {
+    T::type_name().into_owned()
+}
+
+fn parse_input<T: async_graphql::InputType>(value: &ValueAccessor<'_>) -> async_graphql::Result<T> {
+    T::parse(Some(value.as_value().clone()))
+        .map_err(|err| async_graphql::Error::new(err.into_server_error(Default::default()).message))
+}
+
+fn parse_enum<T: EnumType>(name: &str) -> async_graphql::Result<T> {
+    T::items()
+        .iter()
+        .find(|item| item.name == name)
+        .map(|item| item.value)
+        .ok_or_else(|| async_graphql::Error::new(format!("unknown enum item `{}`", name)))
+}
+
+fn table_ref_name(table: &TableRef) -> String {
+    match table {
+        TableRef::Table(table)
+        | TableRef::SchemaTable(_, table)
+        | TableRef::DatabaseSchemaTable(_, _, table)
+        | TableRef::TableAlias(table, _)
+        | TableRef::SchemaTableAlias(_, table, _)
+        | TableRef::DatabaseSchemaTableAlias(_, _, table, _) => table.to_string(),
+        TableRef::SubQuery(_, alias)
+        | TableRef::ValuesList(_, alias)
+        | TableRef::FunctionCall(_, alias) => alias.to_string(),
+    }
+}
+
+fn is_null_value(value: &sea_orm::Value) -> bool {
+    value == &value.as_null()
+}
+
+fn is_cursor_value(value: &sea_orm::Value) -> bool {
+    match value {
+        sea_orm::Value::TinyInt(_)
+        | sea_orm::Value::SmallInt(_)
+        | sea_orm::Value::Int(_)
+        | sea_orm::Value::BigInt(_)
+        | sea_orm::Value::TinyUnsigned(_)
+        | sea_orm::Value::SmallUnsigned(_)
+        | sea_orm::Value::Unsigned(_)
+        | sea_orm::Value::BigUnsigned(_)
+        | sea_orm::Value::String(_) => true,
+        #[cfg(feature = "with-uuid")]
+        sea_orm::Value::Uuid(_) => true,
+        _ => false,
+    }
+}
@@ -0,0 +1,26 @@
+// Derived from Seaography (github.com/SeaQL/seaography)
+// Modifications Copyright (c) 2025 Stephen J. Li
+
+use sea_orm::{ColumnTrait, Condition};
+
+/// 以可為空的時間欄位標記已刪除的資料列
+///
+/// `Filter` derive 依 `Model` 上是否有 `#[graphql_template(soft_delete)]` 欄位產生實作，
+/// 沒有軟刪除欄位時不限制。
+pub trait EntitySoftDelete: sea_orm::EntityTrait {
+    /// 軟刪除欄位，`Mutant` 不包含此欄位，只能經由 `delete_many` 與 `restore_many` 修改
+    fn soft_delete_column() -> Option<Self::Column> {
+        None
+    }
+}
+
+/// 排除已軟刪除的資料列，`with_deleted` 為 `Some(true)` 時不限制
+pub fn soft_delete_condition<E>(with_deleted: Option<bool>) -> Condition
+where
+    E: EntitySoftDelete,
+{
+    match E::soft_delete_column() {
+        Some(column) if !with_deleted.unwrap_or(false) => Condition::all().add(column.is_null()),
+        _ => Condition::all(),
+    }
+}
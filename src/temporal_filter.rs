@@ -0,0 +1,132 @@
+// Derived from Seaography (github.com/SeaQL/seaography)
+// Modifications Copyright (c) 2025 Stephen J. Li
+
+use sea_orm::sea_query::{Expr, SimpleExpr};
+use sea_orm::{Condition, DbBackend, IntoSimpleExpr};
+
+use crate::{RangeInput, TypeFilter};
+
+/// 日期與日期時間過濾器
+///
+/// 除了一般比較與 `between` 區間之外，支持 `year`、`month`、`dayOfWeek` 日期部分比較。
+/// 只有時間的欄位沒有日期部分，使用 `TimeFilter`。
+/// `dayOfWeek` 一律以 0 表示星期日、6 表示星期六。
+#[derive(Debug, Clone, async_graphql::InputObject)]
+#[graphql(concrete(name = "DateFilter", params(sea_orm::prelude::Date)))]
+#[graphql(concrete(name = "DateTimeFilter", params(sea_orm::prelude::DateTime)))]
+#[graphql(concrete(name = "DateTimeUtcFilter", params(sea_orm::prelude::DateTimeUtc)))]
+#[graphql(concrete(
+    name = "DateTimeWithTimeZoneFilter",
+    params(sea_orm::prelude::DateTimeWithTimeZone)
+))]
+pub struct TemporalFilter<T: async_graphql::InputType>
+where
+    RangeInput<T>: async_graphql::InputType,
+{
+    pub eq: Option<T>,
+    pub ne: Option<T>,
+    pub gt: Option<T>,
+    pub gte: Option<T>,
+    pub lt: Option<T>,
+    pub lte: Option<T>,
+    pub is_in: Option<Vec<T>>,
+    pub is_not_in: Option<Vec<T>>,
+    pub is_null: Option<bool>,
+
+    /// 介於 `from` 與 `to` 之間（包含兩端）
+    pub between: Option<RangeInput<T>>,
+    /// 不在 `from` 與 `to` 之間
+    pub not_between: Option<RangeInput<T>>,
+    /// 年份比較
+    pub year: Option<TypeFilter<i32>>,
+    /// 月份比較（1 - 12）
+    pub month: Option<TypeFilter<i32>>,
+    /// 星期比較（0 為星期日）
+    pub day_of_week: Option<TypeFilter<i32>>,
+}
+
+pub type DateFilter = TemporalFilter<sea_orm::prelude::Date>;
+/// 時間欄位只提供比較與區間運算，`EXTRACT(YEAR FROM time)` 在 Postgres 上是錯誤
+pub type TimeFilter = TypeFilter<sea_orm::prelude::Time>;
+pub type DateTimeFilter = TemporalFilter<sea_orm::prelude::DateTime>;
+pub type DateTimeUtcFilter = TemporalFilter<sea_orm::prelude::DateTimeUtc>;
+pub type DateTimeWithTimeZoneFilter = TemporalFilter<sea_orm::prelude::DateTimeWithTimeZone>;
+// SeaORM 將 timestamp / timestamptz 欄位映射為 DateTime / DateTimeWithTimeZone
+pub type TimestampFilter = DateTimeFilter;
+pub type TimestampWithTimeZoneFilter = DateTimeWithTimeZoneFilter;
+
+impl<T> TemporalFilter<T>
+where
+    T: async_graphql::InputType + Into<sea_orm::Value>,
+    RangeInput<T>: async_graphql::InputType,
+{
+    pub fn to_condition<C>(self, column: C, backend: DbBackend) -> Condition
+    where
+        C: IntoSimpleExpr,
+    {
+        let column = column.into_simple_expr();
+
+        let mut condition = TypeFilter {
+            eq: self.eq,
+            ne: self.ne,
+            gt: self.gt,
+            gte: self.gte,
+            lt: self.lt,
+            lte: self.lte,
+            is_in: self.is_in,
+            is_not_in: self.is_not_in,
+            is_null: self.is_null,
+            between: self.between,
+            not_between: self.not_between,
+        }
+        .to_condition(column.clone());
+
+        if let Some(year) = self.year {
+            condition =
+                condition.add(year.to_condition(date_part(DatePart::Year, column.clone(), backend)))
+        }
+
+        if let Some(month) = self.month {
+            condition = condition.add(month.to_condition(date_part(
+                DatePart::Month,
+                column.clone(),
+                backend,
+            )))
+        }
+
+        if let Some(day_of_week) = self.day_of_week {
+            condition = condition.add(day_of_week.to_condition(date_part(
+                DatePart::DayOfWeek,
+                column,
+                backend,
+            )))
+        }
+
+        condition
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DatePart {
+    Year,
+    Month,
+    DayOfWeek,
+}
+
+/// 依資料庫後端取出日期部分，結果皆為整數
+pub fn date_part(part: DatePart, column: SimpleExpr, backend: DbBackend) -> SimpleExpr {
+    let template = match (backend, part) {
+        (DbBackend::Postgres, DatePart::Year) => "CAST(EXTRACT(YEAR FROM $1) AS integer)",
+        (DbBackend::Postgres, DatePart::Month) => "CAST(EXTRACT(MONTH FROM $1) AS integer)",
+        (DbBackend::Postgres, DatePart::DayOfWeek) => "CAST(EXTRACT(DOW FROM $1) AS integer)",
+        (DbBackend::MySql, DatePart::Year) => "YEAR(?)",
+        (DbBackend::MySql, DatePart::Month) => "MONTH(?)",
+        // MySQL 的 DAYOFWEEK 以 1 表示星期日
+        (DbBackend::MySql, DatePart::DayOfWeek) => "(DAYOFWEEK(?) - 1)",
+        (DbBackend::Sqlite, DatePart::Year) => "CAST(strftime('%Y', ?) AS INTEGER)",
+        (DbBackend::Sqlite, DatePart::Month) => "CAST(strftime('%m', ?) AS INTEGER)",
+        (DbBackend::Sqlite, DatePart::DayOfWeek) => "CAST(strftime('%w', ?) AS INTEGER)",
+    };
+
+    Expr::cust_with_exprs(template, [column])
+}
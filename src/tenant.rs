@@ -0,0 +1,38 @@
+// Derived from Seaography (github.com/SeaQL/seaography)
+// Modifications Copyright (c) 2025 Stephen J. Li
+
+use sea_orm::Condition;
+
+use crate::EntityGuard;
+
+/// 目前請求的租戶，以 `Schema::build(..).data(..)` 或 `Request::data` 放入 `Context`
+///
+/// 類型需與 `#[graphql_template(tenant)]` 欄位去掉 `Option` 後的類型相同，
+/// 缺少時查詢與修改都會回傳錯誤，不會退回不限制租戶。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Tenant<T>(pub T);
+
+/// 以租戶欄位限制實體可存取的資料列
+///
+/// `Filter` derive 依 `Model` 上是否有 `#[graphql_template(tenant)]` 欄位產生實作，
+/// 沒有租戶欄位時不限制。
+pub trait EntityTenant: sea_orm::EntityTrait {
+    /// 租戶欄位，`Mutant` 不包含此欄位，已存在的資料列無法移到其他租戶
+    fn tenant_column() -> Option<Self::Column> {
+        None
+    }
+
+    fn tenant(ctx: &async_graphql::Context<'_>) -> async_graphql::Result<Condition> {
+        let _ = ctx;
+
+        Ok(Condition::all())
+    }
+}
+
+/// 租戶與 `EntityGuard` 的條件，兩者皆需符合
+pub fn row_condition<E>(ctx: &async_graphql::Context<'_>) -> async_graphql::Result<Condition>
+where
+    E: EntityGuard + EntityTenant,
+{
+    Ok(Condition::all().add(E::tenant(ctx)?).add(E::guard(ctx)?))
+}
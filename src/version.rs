@@ -0,0 +1,20 @@
+// Derived from Seaography (github.com/SeaQL/seaography)
+// Modifications Copyright (c) 2025 Stephen J. Li
+
+use async_graphql::ErrorExtensions;
+
+/// 以整數版本欄位實作樂觀鎖
+///
+/// `Filter` derive 依 `Model` 上是否有 `#[graphql_template(version)]` 欄位產生實作。
+/// 有版本欄位時更新需帶入預期版本，只更新版本相符的資料列並將版本加一。
+pub trait EntityVersion: sea_orm::EntityTrait {
+    fn version_column() -> Option<Self::Column> {
+        None
+    }
+}
+
+/// 沒有資料列符合預期版本時的錯誤，`extensions.code` 為 `CONFLICT`
+pub fn version_conflict() -> async_graphql::Error {
+    async_graphql::Error::new("no row matches the expected version")
+        .extend_with(|_, extensions| extensions.set("code", "CONFLICT"))
+}
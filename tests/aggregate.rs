@@ -0,0 +1,161 @@
+extern crate async_graphql_template as seaography;
+
+mod common;
+mod entities;
+
+use std::collections::BTreeMap;
+
+use async_graphql::{value, InputType};
+use sea_orm::{DatabaseBackend, EntityTrait, MockDatabase, QuerySelect, QueryTrait, Value};
+
+use common::schema;
+pub use common::OrmDataloader;
+use entities::{author, post};
+
+/// `post` 的聚合結果列，`id`、`author_id` 與 `likes` 都是數值欄位
+fn aggregate_row(count: i64, likes: Option<i32>) -> BTreeMap<String, Value> {
+    let mut row = BTreeMap::new();
+
+    row.insert("count".to_owned(), Value::BigInt(Some(count)));
+
+    for column in ["id", "author_id", "likes"] {
+        row.insert(
+            format!("sum_{}", column),
+            Value::Double(likes.map(f64::from)),
+        );
+        row.insert(
+            format!("avg_{}", column),
+            Value::Double(likes.map(f64::from)),
+        );
+        row.insert(format!("min_{}", column), Value::Int(likes));
+        row.insert(format!("max_{}", column), Value::Int(likes));
+    }
+
+    row
+}
+
+#[test]
+fn postgres_sum_and_avg_are_cast_to_double() {
+    let stmt = post::aggregate_select(
+        post::Entity::find().select_only(),
+        DatabaseBackend::Postgres,
+    );
+    let sql = stmt.build(DatabaseBackend::Postgres).to_string();
+
+    assert!(
+        sql.contains(r#"CAST(SUM("post"."likes") AS double precision) AS "sum_likes""#),
+        "{}",
+        sql
+    );
+    assert!(
+        sql.contains(r#"CAST(AVG("post"."likes") AS double precision) AS "avg_likes""#),
+        "{}",
+        sql
+    );
+    assert!(
+        sql.contains(r#"MIN("post"."likes") AS "min_likes""#),
+        "{}",
+        sql
+    );
+    assert!(!sql.contains('?'), "{}", sql);
+}
+
+#[tokio::test]
+async fn postgres_aggregate_runs_sum_and_avg() {
+    let db = MockDatabase::new(DatabaseBackend::Postgres)
+        .append_query_results([[aggregate_row(2, Some(12))]])
+        .into_connection();
+
+    let aggregate = post::aggregate(&db, None).await.unwrap();
+
+    assert_eq!(aggregate.count, 2);
+    assert_eq!(aggregate.sum.likes, Some(12.0));
+    assert_eq!(aggregate.avg.likes, Some(12.0));
+    assert_eq!(aggregate.max.likes, Some(12));
+
+    let log = db.into_transaction_log();
+    let sql = &log[0].statements()[0].sql;
+
+    assert!(
+        sql.contains(r#"CAST(SUM("post"."likes") AS double precision)"#),
+        "{}",
+        sql
+    );
+    assert!(!sql.contains('?'), "{}", sql);
+}
+
+#[tokio::test]
+async fn postgres_group_by_having_binds_aggregate() {
+    let mut row = aggregate_row(2, Some(12));
+    row.insert("key_AuthorId".to_owned(), Value::Int(Some(1)));
+
+    let db = MockDatabase::new(DatabaseBackend::Postgres)
+        .append_query_results([[row]])
+        .into_connection();
+
+    let having = post::AggregateFilter::parse(Some(value!({
+        "sum": { "likes": { "gt": 10.0 } },
+    })))
+    .unwrap();
+
+    let rows = post::group_by(&db, vec![post::QueryColumn::AuthorId], None, Some(having))
+        .await
+        .unwrap();
+
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].key.author_id, Some(1));
+    assert_eq!(rows[0].aggregate.sum.likes, Some(12.0));
+
+    let log = db.into_transaction_log();
+    let statement = &log[0].statements()[0];
+
+    assert!(
+        statement
+            .sql
+            .contains(r#"HAVING (CAST(SUM("post"."likes") AS double precision)) > $1"#),
+        "{}",
+        statement.sql
+    );
+    assert_eq!(
+        statement.values.as_ref().unwrap().0,
+        vec![Value::Double(Some(10.0))]
+    );
+}
+
+#[tokio::test]
+async fn has_many_aggregate_is_batched() {
+    let authors: Vec<author::Model> = (1..=3)
+        .map(|id| author::Model {
+            id,
+            name: format!("author {}", id),
+        })
+        .collect();
+
+    let mut first = aggregate_row(2, Some(7));
+    first.insert("group_key".to_owned(), Value::Int(Some(1)));
+
+    let mut third = aggregate_row(1, Some(3));
+    third.insert("group_key".to_owned(), Value::Int(Some(3)));
+
+    // 只排入兩次查詢的結果，逐列查詢時第三次查詢會失敗
+    let db = MockDatabase::new(DatabaseBackend::Postgres)
+        .append_query_results([authors])
+        .append_query_results([[first, third]])
+        .into_connection();
+
+    let response = schema(db)
+        .execute("{ authors { id postAggregate { count sum { likes } } } }")
+        .await;
+
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        response.data,
+        value!({
+            "authors": [
+                { "id": 1, "postAggregate": { "count": 2, "sum": { "likes": 7.0 } } },
+                { "id": 2, "postAggregate": { "count": 0, "sum": { "likes": null } } },
+                { "id": 3, "postAggregate": { "count": 1, "sum": { "likes": 3.0 } } },
+            ],
+        })
+    );
+}
@@ -0,0 +1,95 @@
+extern crate async_graphql_template as seaography;
+
+mod common;
+mod entities;
+
+use async_graphql::{value, InputType};
+use sea_orm::{DatabaseBackend, DbErr, EntityTrait, QueryFilter, QueryTrait};
+
+pub use common::OrmDataloader;
+use entities::article;
+
+fn filter(input: async_graphql::Value) -> article::Filter {
+    article::Filter::parse(Some(input)).unwrap()
+}
+
+fn sql(filter: article::Filter, backend: DatabaseBackend) -> Result<String, DbErr> {
+    let condition = article::try_filter_recursive(Some(filter), backend)?;
+    let sql = article::Entity::find()
+        .filter(condition)
+        .build(backend)
+        .to_string();
+
+    Ok(sql.split_once(" WHERE ").unwrap().1.to_owned())
+}
+
+#[test]
+fn array_operators_on_postgres() {
+    let sql = |input| sql(filter(input), DatabaseBackend::Postgres).unwrap();
+
+    assert_eq!(
+        sql(value!({ "tags": { "contains": ["a", "b"] } })),
+        r#""article"."tags" @> ARRAY ['a','b']"#
+    );
+    assert_eq!(
+        sql(value!({ "tags": { "containsAny": ["a"] } })),
+        r#""article"."tags" && ARRAY ['a']"#
+    );
+    assert_eq!(
+        sql(value!({ "tags": { "containedBy": ["a"] } })),
+        r#""article"."tags" <@ ARRAY ['a']"#
+    );
+    assert_eq!(
+        sql(value!({ "tags": { "length": { "gte": 2 } } })),
+        r#"cardinality("article"."tags") >= 2"#
+    );
+    assert_eq!(
+        sql(value!({ "tags": { "isEmpty": true } })),
+        r#"cardinality("article"."tags") = 0"#
+    );
+}
+
+#[test]
+fn array_operators_are_rejected_elsewhere() {
+    for backend in [DatabaseBackend::MySql, DatabaseBackend::Sqlite] {
+        assert_eq!(
+            sql(filter(value!({ "tags": { "contains": ["a"] } })), backend).unwrap_err(),
+            DbErr::Custom(format!(
+                "array filter on column `tags` is only supported on Postgres, got {:?}",
+                backend
+            ))
+        );
+    }
+
+    // 不含數組欄位的過濾條件在其他後端照常轉換
+    assert_eq!(
+        sql(
+            filter(value!({ "likes": { "gt": 1 } })),
+            DatabaseBackend::Sqlite
+        )
+        .unwrap(),
+        r#""article"."likes" > 1"#
+    );
+}
+
+#[test]
+fn array_filter_on_integer_elements() {
+    assert_eq!(
+        sql(
+            filter(value!({ "scores": { "contains": [1, 2], "length": { "lt": 5 } } })),
+            DatabaseBackend::Postgres
+        )
+        .unwrap(),
+        r#""article"."scores" @> ARRAY [1,2] AND cardinality("article"."scores") < 5"#
+    );
+    assert_eq!(
+        sql(
+            filter(value!({ "scores": { "isEmpty": false } })),
+            DatabaseBackend::MySql
+        )
+        .unwrap_err(),
+        DbErr::Custom(
+            "array filter on column `scores` is only supported on Postgres, got MySql".into()
+        )
+    );
+}
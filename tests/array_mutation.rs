@@ -0,0 +1,92 @@
+extern crate async_graphql_template as seaography;
+
+mod common;
+mod entities;
+
+use async_graphql::{value, InputType};
+use sea_orm::{DatabaseBackend, DbErr, EntityTrait, QueryTrait};
+
+pub use common::OrmDataloader;
+use entities::article;
+
+fn mutant(input: async_graphql::Value) -> article::Mutant {
+    article::Mutant::parse(Some(input)).unwrap()
+}
+
+fn sql(mutant: article::Mutant, backend: DatabaseBackend) -> Result<String, DbErr> {
+    let stmt = article::update_many_with_mutant(article::Entity::update_many(), mutant, backend)?;
+
+    Ok(stmt.build(backend).to_string())
+}
+
+#[test]
+fn array_mutation_runs_in_the_database_on_postgres() {
+    let sql = sql(
+        mutant(value!({ "tags": { "remove": ["a"], "push": ["b"] } })),
+        DatabaseBackend::Postgres,
+    )
+    .unwrap();
+
+    assert_eq!(
+        sql,
+        r#"UPDATE "article" SET "tags" = array_cat(array_remove("article"."tags", 'a'), ARRAY ['b'])"#
+    );
+}
+
+#[test]
+fn add_to_set_skips_existing_elements() {
+    let sql = sql(
+        mutant(value!({ "scores": { "addToSet": [1, 1, 2] } })),
+        DatabaseBackend::Postgres,
+    )
+    .unwrap();
+
+    assert_eq!(
+        sql,
+        r#"UPDATE "article" SET "scores" = array_cat("article"."scores", ARRAY(SELECT item FROM unnest(ARRAY [1,2]) AS item WHERE NOT (item = ANY(COALESCE("article"."scores", '{}')))))"#
+    );
+}
+
+#[test]
+fn array_mutation_is_rejected_elsewhere() {
+    for backend in [DatabaseBackend::MySql, DatabaseBackend::Sqlite] {
+        assert_eq!(
+            sql(mutant(value!({ "tags": { "set": [] } })), backend).unwrap_err(),
+            DbErr::Custom(format!(
+                "array mutation on column `tags` is only supported on Postgres, got {:?}",
+                backend
+            ))
+        );
+    }
+}
+
+#[test]
+fn active_model_needs_the_loaded_array() {
+    use sea_orm::ActiveValue::{NotSet, Set};
+
+    let mut active_model = article::ActiveModel {
+        tags: Set(vec!["a".into(), "b".into()]),
+        ..Default::default()
+    };
+
+    active_model
+        .recursive_set_value(mutant(
+            value!({ "tags": { "remove": ["a"], "addToSet": ["b", "c"] } }),
+        ))
+        .unwrap();
+    assert_eq!(active_model.tags, Set(vec!["b".into(), "c".into()]));
+
+    let mut active_model = article::ActiveModel {
+        tags: NotSet,
+        ..Default::default()
+    };
+
+    assert_eq!(
+        active_model
+            .recursive_set_value(mutant(value!({ "tags": { "push": ["a"] } })))
+            .unwrap_err(),
+        DbErr::Custom(
+            "array mutation on column `tags` needs the current value; load the model, use `set` or `update_many`".into()
+        )
+    );
+}
@@ -0,0 +1,203 @@
+extern crate async_graphql_template as seaography;
+
+mod common;
+mod entities;
+
+use async_graphql::{value, Request};
+use sea_orm::{DatabaseBackend, MockDatabase, Value};
+
+pub use common::OrmDataloader;
+use common::{note_schema, share_mock, Viewer};
+use entities::note;
+use seaography::{Actor, Tenant};
+
+const CREATE: &str = r#"mutation { noteCreateOne(mutant: { userId: 3, slug: "a", title: "t" }) { id createdBy version } }"#;
+
+#[tokio::test]
+async fn create_one_fills_created_by_tenant_and_version() {
+    let db = MockDatabase::new(DatabaseBackend::Postgres)
+        .append_query_results([vec![note::Model {
+            id: 1,
+            org_id: 7,
+            user_id: 3,
+            slug: "a".into(),
+            title: "t".into(),
+            secret: String::new(),
+            deleted_at: None,
+            created_by: Some(5),
+            version: 0,
+        }]])
+        .into_connection();
+
+    let response = note_schema(share_mock(&db))
+        .execute(
+            Request::new(CREATE)
+                .data(Tenant(7))
+                .data(Viewer(3))
+                .data(Actor(5)),
+        )
+        .await;
+
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        response.data,
+        value!({ "noteCreateOne": { "id": 1, "createdBy": 5, "version": 0 } })
+    );
+
+    let log = db.into_transaction_log();
+    let statement = &log[0].statements()[0];
+
+    assert!(
+        statement.sql.starts_with(
+            r#"INSERT INTO "note" ("org_id", "user_id", "slug", "title", "created_by", "version") VALUES ($1, $2, $3, $4, $5, $6)"#
+        ),
+        "{}",
+        statement.sql
+    );
+    assert_eq!(
+        statement.values.as_ref().unwrap().0,
+        [
+            Value::Int(Some(7)),
+            Value::Int(Some(3)),
+            Value::String(Some(Box::new("a".into()))),
+            Value::String(Some(Box::new("t".into()))),
+            Value::Int(Some(5)),
+            Value::Int(Some(0)),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn create_one_needs_an_actor() {
+    let db = MockDatabase::new(DatabaseBackend::Postgres).into_connection();
+
+    let response = note_schema(share_mock(&db))
+        .execute(Request::new(CREATE).data(Tenant(7)).data(Viewer(3)))
+        .await;
+
+    assert_eq!(response.errors.len(), 1, "{:?}", response.errors);
+    assert!(db.into_transaction_log().is_empty());
+}
+
+#[test]
+fn audit_columns_are_left_out_of_mutant() {
+    let sdl = note_schema(MockDatabase::new(DatabaseBackend::Postgres).into_connection()).sdl();
+    let mutant = sdl
+        .split("input NoteMutant {")
+        .nth(1)
+        .and_then(|rest| rest.split('}').next())
+        .unwrap();
+
+    assert!(!mutant.contains("createdBy"), "{}", mutant);
+}
+
+#[cfg(feature = "with-chrono")]
+mod timestamps {
+    use async_graphql::dataloader::DataLoader;
+    use async_graphql::{Context, EmptySubscription, Object, Schema};
+    use sea_orm::{DatabaseBackend, MockDatabase, MockExecResult, Value};
+
+    use crate::common::{share_mock, Query};
+    use crate::entities::event;
+    use crate::OrmDataloader;
+
+    struct Mutation;
+
+    #[Object]
+    impl Mutation {
+        async fn event_create_one(
+            &self,
+            ctx: &Context<'_>,
+            mutant: event::Mutant,
+        ) -> async_graphql::Result<event::Model> {
+            event::create_one(ctx, mutant).await
+        }
+
+        async fn event_update_many(
+            &self,
+            ctx: &Context<'_>,
+            filters: Option<event::Filter>,
+            mutant: event::Mutant,
+        ) -> async_graphql::Result<u64> {
+            event::update_many(ctx, filters, mutant).await
+        }
+    }
+
+    fn schema(db: sea_orm::DatabaseConnection) -> Schema<Query, Mutation, EmptySubscription> {
+        Schema::build(Query, Mutation, EmptySubscription)
+            .data(DataLoader::new(OrmDataloader { db }, tokio::spawn))
+            .finish()
+    }
+
+    #[tokio::test]
+    async fn create_one_sets_both_timestamps_to_the_same_time() {
+        let now = sea_orm::prelude::DateTime::default();
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([vec![event::Model {
+                id: 1,
+                title: "t".into(),
+                created_at: now,
+                updated_at: now,
+            }]])
+            .into_connection();
+
+        let response = schema(share_mock(&db))
+            .execute(r#"mutation { eventCreateOne(mutant: { title: "t" }) { id } }"#)
+            .await;
+
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+        let log = db.into_transaction_log();
+        let statement = &log[0].statements()[0];
+        let values = &statement.values.as_ref().unwrap().0;
+
+        assert!(
+            statement.sql.starts_with(
+                r#"INSERT INTO "event" ("title", "created_at", "updated_at") VALUES ($1, $2, $3)"#
+            ),
+            "{}",
+            statement.sql
+        );
+        assert!(
+            matches!(values[1], Value::ChronoDateTime(Some(_))),
+            "{:?}",
+            values
+        );
+        assert_eq!(values[1], values[2]);
+    }
+
+    #[tokio::test]
+    async fn update_many_sets_updated_at() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }])
+            .into_connection();
+
+        let response = schema(share_mock(&db))
+            .execute(r#"mutation { eventUpdateMany(mutant: { title: "t" }) }"#)
+            .await;
+
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+        let log = db.into_transaction_log();
+        let statement = &log[0].statements()[0];
+
+        assert!(
+            statement
+                .sql
+                .starts_with(r#"UPDATE "event" SET "title" = $1, "updated_at" = $2"#),
+            "{}",
+            statement.sql
+        );
+        assert!(
+            matches!(
+                statement.values.as_ref().unwrap().0[1],
+                Value::ChronoDateTime(Some(_))
+            ),
+            "{:?}",
+            statement.values
+        );
+    }
+}
@@ -0,0 +1,41 @@
+extern crate async_graphql_template as seaography;
+
+mod common;
+mod entities;
+
+use async_graphql::{value, InputType};
+use sea_orm::{DatabaseBackend, EntityTrait, QueryFilter, QueryTrait};
+
+pub use common::OrmDataloader;
+use entities::article;
+
+fn sql(input: async_graphql::Value) -> String {
+    let filter = article::Filter::parse(Some(input)).unwrap();
+    let condition = article::try_filter_recursive(Some(filter), DatabaseBackend::Sqlite).unwrap();
+    let sql = article::Entity::find()
+        .filter(condition)
+        .build(DatabaseBackend::Sqlite)
+        .to_string();
+
+    sql.split_once(" WHERE ").unwrap().1.to_owned()
+}
+
+#[test]
+fn between_on_numbers_and_strings() {
+    assert_eq!(
+        sql(value!({ "likes": { "between": { "from": 1, "to": 10 } } })),
+        r#""article"."likes" BETWEEN 1 AND 10"#
+    );
+    assert_eq!(
+        sql(value!({ "title": { "notBetween": { "from": "a", "to": "m" } } })),
+        r#""article"."title" NOT BETWEEN 'a' AND 'm'"#
+    );
+}
+
+#[test]
+fn between_on_nullable_column() {
+    assert_eq!(
+        sql(value!({ "rating": { "between": { "from": 1, "to": 5 }, "isNull": false } })),
+        r#""article"."rating" BETWEEN 1 AND 5"#
+    );
+}
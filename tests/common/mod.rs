@@ -0,0 +1,194 @@
+#![allow(dead_code)]
+
+use async_graphql::dataloader::DataLoader;
+use async_graphql::types::connection::{Connection, EmptyFields};
+use async_graphql::{Context, EmptyMutation, EmptySubscription, MergedObject, Object, Schema};
+use sea_orm::{ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter};
+
+use crate::entities::{author, note, post};
+
+pub struct OrmDataloader {
+    pub db: DatabaseConnection,
+}
+
+/// 請求帶有 `Staff` 時才通過的欄位守衛
+pub struct Staff;
+
+pub fn staff_only(ctx: &Context<'_>) -> async_graphql::Result<()> {
+    ctx.data_opt::<Staff>()
+        .map(|_| ())
+        .ok_or_else(|| "staff only".into())
+}
+
+/// 目前請求的使用者，`note` 的 `EntityGuard` 只允許存取自己的資料
+pub struct Viewer(pub i32);
+
+/// 共用同一個 MockDatabase 的連線，交給 schema 後仍可讀取執行過的 SQL
+pub fn share_mock(db: &DatabaseConnection) -> DatabaseConnection {
+    match db {
+        DatabaseConnection::MockDatabaseConnection(mock) => {
+            DatabaseConnection::MockDatabaseConnection(mock.clone())
+        }
+        _ => unreachable!("not a mock connection"),
+    }
+}
+
+pub struct Query;
+
+#[Object]
+impl Query {
+    /// 與 CLI 產生的根查詢相同
+    async fn post(
+        &self,
+        ctx: &Context<'_>,
+        filters: Option<post::Filter>,
+        pagination: Option<seaography::Pagination>,
+        order_by: Option<Vec<seaography::Ordered<post::OrderBy>>>,
+        distinct_on: Option<Vec<post::QueryColumn>>,
+    ) -> async_graphql::Result<Connection<String, post::Model, post::ConnectionFields, EmptyFields>>
+    {
+        let db = &ctx.data::<DataLoader<OrmDataloader>>()?.loader().db;
+        let backend = db.get_database_backend();
+
+        let condition = post::try_filter_with_deleted(ctx, filters.clone(), None, backend)?;
+        let stmt = post::distinct_on_guarded(
+            ctx,
+            post::Entity::find().filter(condition.clone()),
+            distinct_on,
+            condition,
+            order_by.as_deref(),
+            backend,
+        )?;
+        let stmt = post::order_by_guarded(ctx, stmt, order_by)?;
+        let columns = post::selected_connection_columns(&ctx.field());
+
+        seaography::fetch_connection_with_columns::<_, post::PartialModel, _>(
+            db,
+            stmt,
+            Some(columns),
+            pagination,
+            |pagination| post::ConnectionFields {
+                pagination,
+                filters,
+                with_deleted: None,
+            },
+        )
+        .await
+    }
+
+    async fn authors(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<author::Model>> {
+        let db = &ctx.data::<DataLoader<OrmDataloader>>()?.loader().db;
+
+        Ok(author::Entity::find().all(db).await?)
+    }
+}
+
+pub fn schema(db: DatabaseConnection) -> Schema<Query, EmptyMutation, EmptySubscription> {
+    Schema::build(Query, EmptyMutation, EmptySubscription)
+        .data(DataLoader::new(OrmDataloader { db }, tokio::spawn))
+        .finish()
+}
+
+/// 與 CLI 產生的根查詢相同，加上 `note` 的查詢與修改
+#[derive(Default)]
+pub struct NoteQuery;
+
+#[Object]
+impl NoteQuery {
+    async fn note(
+        &self,
+        ctx: &Context<'_>,
+        filters: Option<note::Filter>,
+        pagination: Option<seaography::Pagination>,
+        order_by: Option<Vec<seaography::Ordered<note::OrderBy>>>,
+        #[graphql(visible = "note::has_soft_delete")] with_deleted: Option<bool>,
+    ) -> async_graphql::Result<Connection<String, note::Model, note::ConnectionFields, EmptyFields>>
+    {
+        let db = &ctx.data::<DataLoader<OrmDataloader>>()?.loader().db;
+        let backend = db.get_database_backend();
+
+        let condition = note::try_filter_with_deleted(ctx, filters.clone(), with_deleted, backend)?;
+        let stmt = note::order_by_guarded(ctx, note::Entity::find().filter(condition), order_by)?;
+
+        seaography::fetch_connection_with_columns::<_, note::PartialModel, _>(
+            db,
+            stmt,
+            None,
+            pagination,
+            |pagination| note::ConnectionFields {
+                pagination,
+                filters,
+                with_deleted,
+            },
+        )
+        .await
+    }
+
+    async fn note_by_id(
+        &self,
+        ctx: &Context<'_>,
+        id: i32,
+    ) -> async_graphql::Result<Option<note::Model>> {
+        note::by_id(ctx, id).await
+    }
+
+    async fn note_by_slug(
+        &self,
+        ctx: &Context<'_>,
+        slug: String,
+    ) -> async_graphql::Result<Option<note::Model>> {
+        note::by_slug(ctx, slug).await
+    }
+}
+
+#[derive(MergedObject, Default)]
+pub struct NoteRoot(NoteQuery, note::EntityQuery);
+
+#[derive(Default)]
+pub struct NoteMutation;
+
+#[Object]
+impl NoteMutation {
+    async fn note_create_one(
+        &self,
+        ctx: &Context<'_>,
+        mutant: note::Mutant,
+    ) -> async_graphql::Result<note::Model> {
+        note::create_one(ctx, mutant).await
+    }
+
+    async fn note_update_many(
+        &self,
+        ctx: &Context<'_>,
+        filters: Option<note::Filter>,
+        mutant: note::Mutant,
+        numeric_mutant: Option<note::NumericMutant>,
+        expected_version: i32,
+    ) -> async_graphql::Result<u64> {
+        note::update_many(ctx, filters, mutant, numeric_mutant, expected_version).await
+    }
+
+    async fn note_delete_many(
+        &self,
+        ctx: &Context<'_>,
+        filters: Option<note::Filter>,
+    ) -> async_graphql::Result<u64> {
+        note::delete_many(ctx, filters).await
+    }
+
+    async fn note_restore_many(
+        &self,
+        ctx: &Context<'_>,
+        filters: Option<note::Filter>,
+    ) -> async_graphql::Result<u64> {
+        note::restore_many(ctx, filters).await
+    }
+}
+
+/// `note` 的 federation schema，請求需自行加入 `Tenant`、`Viewer` 或 `Staff`
+pub fn note_schema(db: DatabaseConnection) -> Schema<NoteRoot, NoteMutation, EmptySubscription> {
+    Schema::build(NoteRoot::default(), NoteMutation, EmptySubscription)
+        .data(DataLoader::new(OrmDataloader { db }, tokio::spawn))
+        .enable_federation()
+        .finish()
+}
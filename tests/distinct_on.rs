@@ -0,0 +1,121 @@
+extern crate async_graphql_template as seaography;
+
+mod common;
+mod entities;
+
+use sea_orm::{DatabaseBackend, EntityTrait, MockDatabase};
+
+pub use common::OrmDataloader;
+use common::{schema, Staff};
+use entities::post;
+
+fn mock(posts: Vec<post::Model>) -> sea_orm::DatabaseConnection {
+    MockDatabase::new(DatabaseBackend::Postgres)
+        .append_query_results([posts])
+        .into_connection()
+}
+
+#[tokio::test]
+async fn distinct_on_checks_column_guards() {
+    let response = schema(mock(Vec::<post::Model>::new()))
+        .execute("{ post(distinctOn: [TITLE]) { nodes { id } } }")
+        .await;
+
+    assert_eq!(response.errors.len(), 1, "{:?}", response.errors);
+    assert_eq!(response.errors[0].message, "staff only");
+}
+
+#[tokio::test]
+async fn distinct_on_uses_leading_order_by() {
+    let posts = vec![post::Model {
+        id: 1,
+        author_id: 1,
+        title: "first".into(),
+        likes: 3,
+    }];
+
+    let response = schema(mock(posts))
+        .execute(
+            async_graphql::Request::new(
+                "{ post(distinctOn: [AUTHOR_ID, TITLE], orderBy: [{ authorId: ASC, title: ASC }, { likes: DESC }]) { nodes { id } } }",
+            )
+            .data(Staff),
+        )
+        .await;
+
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+    let response = schema(mock(Vec::<post::Model>::new()))
+        .execute(
+            async_graphql::Request::new(
+                "{ post(distinctOn: [AUTHOR_ID], orderBy: { likes: DESC }) { nodes { id } } }",
+            )
+            .data(Staff),
+        )
+        .await;
+
+    assert_eq!(
+        response.errors[0].message,
+        "Custom Error: distinctOn columns must match the leading orderBy columns, got Likes"
+    );
+}
+
+#[tokio::test]
+async fn distinct_on_rejects_leading_related_order_by() {
+    let response = schema(mock(Vec::<post::Model>::new()))
+        .execute(
+            "{ post(distinctOn: [AUTHOR_ID], orderBy: [{ author: { name: ASC } }, { authorId: ASC }]) { nodes { id } } }",
+        )
+        .await;
+
+    assert_eq!(
+        response.errors[0].message,
+        "Custom Error: distinctOn columns must match the leading orderBy columns, got related `author`"
+    );
+
+    // distinctOn 欄位之後的關聯排序不影響比對
+    let response = schema(mock(Vec::<post::Model>::new()))
+        .execute(
+            "{ post(distinctOn: [AUTHOR_ID], orderBy: { authorId: ASC, author: { name: ASC } }) { nodes { id } } }",
+        )
+        .await;
+
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+}
+
+#[test]
+fn distinct_on_sql_per_backend() {
+    use sea_orm::{Condition, QueryTrait};
+
+    let items = [post::OrderByItem {
+        field: post::QueryColumn::AuthorId,
+        direction: seaography::OrderByEnum::Asc,
+    }];
+
+    let sql = |backend| {
+        post::distinct_on(
+            post::Entity::find(),
+            vec![post::QueryColumn::AuthorId],
+            Condition::all(),
+            &items,
+            backend,
+        )
+        .unwrap()
+        .build(backend)
+        .to_string()
+    };
+
+    let postgres = sql(DatabaseBackend::Postgres);
+    assert!(
+        postgres.starts_with(r#"SELECT DISTINCT ON ("post"."author_id")"#),
+        "{}",
+        postgres
+    );
+
+    let sqlite = sql(DatabaseBackend::Sqlite);
+    assert!(
+        sqlite.contains(r#"ROW_NUMBER() OVER ( PARTITION BY "post"."author_id" ORDER BY "post"."author_id" ASC )"#),
+        "{}",
+        sqlite
+    );
+}
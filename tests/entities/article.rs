@@ -0,0 +1,47 @@
+use sea_orm::entity::prelude::*;
+
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    DeriveEntityModel,
+    async_graphql::SimpleObject,
+    async_graphql_template::macros::Filter,
+    async_graphql_template::macros::Mutant,
+)]
+#[sea_orm(table_name = "article")]
+#[graphql(complex)]
+#[graphql(name = "Article")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub post_id: i32,
+    pub title: String,
+    /// 數組欄位只有 Postgres 支援
+    pub tags: Vec<String>,
+    pub scores: Vec<i32>,
+    pub likes: i32,
+    pub rating: Option<i32>,
+}
+
+#[derive(
+    Copy, Clone, Debug, EnumIter, DeriveRelation, async_graphql_template::macros::RelationsCompact,
+)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::post::Entity",
+        from = "Column::PostId",
+        to = "super::post::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Post,
+}
+
+impl Related<super::post::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Post.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
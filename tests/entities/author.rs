@@ -0,0 +1,37 @@
+use sea_orm::entity::prelude::*;
+
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    DeriveEntityModel,
+    async_graphql::SimpleObject,
+    async_graphql_template::macros::Filter,
+    async_graphql_template::macros::Mutant,
+)]
+#[sea_orm(table_name = "author")]
+#[graphql(complex)]
+#[graphql(name = "Author")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    /// 改名的欄位無法由名稱對應到資料庫欄位
+    #[graphql(name = "displayName")]
+    pub name: String,
+}
+
+#[derive(
+    Copy, Clone, Debug, EnumIter, DeriveRelation, async_graphql_template::macros::RelationsCompact,
+)]
+pub enum Relation {
+    #[sea_orm(has_many = "super::post::Entity")]
+    Post,
+}
+
+impl Related<super::post::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Post.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
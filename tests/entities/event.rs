@@ -0,0 +1,31 @@
+use sea_orm::entity::prelude::*;
+
+/// 由時鐘填入的稽核時間欄位
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    DeriveEntityModel,
+    async_graphql::SimpleObject,
+    async_graphql_template::macros::Filter,
+    async_graphql_template::macros::Mutant,
+)]
+#[sea_orm(table_name = "event")]
+#[graphql(complex)]
+#[graphql(name = "Event")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub title: String,
+    #[graphql_template(created_at)]
+    pub created_at: DateTime,
+    #[graphql_template(updated_at)]
+    pub updated_at: DateTime,
+}
+
+#[derive(
+    Copy, Clone, Debug, EnumIter, DeriveRelation, async_graphql_template::macros::RelationsCompact,
+)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
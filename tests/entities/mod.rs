@@ -0,0 +1,6 @@
+pub mod article;
+pub mod author;
+#[cfg(feature = "with-chrono")]
+pub mod event;
+pub mod note;
+pub mod post;
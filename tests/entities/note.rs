@@ -0,0 +1,58 @@
+use sea_orm::entity::prelude::*;
+
+use crate::common::{Staff, Viewer};
+
+/// 依租戶、使用者與欄位守衛限制存取，並有軟刪除、`created_by` 與版本欄位
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    DeriveEntityModel,
+    async_graphql::SimpleObject,
+    async_graphql_template::macros::Filter,
+    async_graphql_template::macros::Mutant,
+)]
+#[sea_orm(table_name = "note")]
+#[graphql(complex)]
+#[graphql(name = "Note")]
+#[graphql_template(guard)]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    #[graphql_template(tenant)]
+    pub org_id: i32,
+    /// 不是租戶欄位，可經由 `Mutant` 修改
+    pub user_id: i32,
+    #[sea_orm(unique)]
+    pub slug: String,
+    pub title: String,
+    #[graphql_template(guard = "crate::common::staff_only")]
+    #[graphql(guard = "crate::common::staff_only")]
+    pub secret: String,
+    #[graphql_template(soft_delete)]
+    pub deleted_at: Option<String>,
+    #[graphql_template(created_by)]
+    pub created_by: Option<i32>,
+    #[graphql_template(version)]
+    pub version: i32,
+}
+
+#[derive(
+    Copy, Clone, Debug, EnumIter, DeriveRelation, async_graphql_template::macros::RelationsCompact,
+)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl async_graphql_template::EntityGuard for Entity {
+    /// 職員可存取所有資料，其他人只能存取自己的
+    fn guard(ctx: &async_graphql::Context<'_>) -> async_graphql::Result<sea_orm::Condition> {
+        if ctx.data_opt::<Staff>().is_some() {
+            return Ok(sea_orm::Condition::all());
+        }
+
+        let viewer = ctx.data::<Viewer>()?;
+
+        Ok(sea_orm::Condition::all().add(Column::UserId.eq(viewer.0)))
+    }
+}
@@ -0,0 +1,45 @@
+use sea_orm::entity::prelude::*;
+
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    DeriveEntityModel,
+    async_graphql::SimpleObject,
+    async_graphql_template::macros::Filter,
+    async_graphql_template::macros::Mutant,
+)]
+#[sea_orm(table_name = "post")]
+#[graphql(complex)]
+#[graphql(name = "Post")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub author_id: i32,
+    #[graphql_template(guard = "crate::common::staff_only")]
+    #[graphql(guard = "crate::common::staff_only")]
+    pub title: String,
+    pub likes: i32,
+}
+
+#[derive(
+    Copy, Clone, Debug, EnumIter, DeriveRelation, async_graphql_template::macros::RelationsCompact,
+)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::author::Entity",
+        from = "Column::AuthorId",
+        to = "super::author::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Author,
+}
+
+impl Related<super::author::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Author.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,129 @@
+extern crate async_graphql_template as seaography;
+
+mod common;
+mod entities;
+
+use async_graphql::{value, Request};
+use sea_orm::{DatabaseBackend, MockDatabase, MockExecResult, Value};
+
+pub use common::OrmDataloader;
+use common::{note_schema, share_mock, Staff, Viewer};
+use entities::note;
+use seaography::Tenant;
+
+fn note(id: i32, user_id: i32) -> note::Model {
+    note::Model {
+        id,
+        org_id: 7,
+        user_id,
+        slug: format!("note-{}", id),
+        title: format!("note {}", id),
+        secret: "s".into(),
+        deleted_at: None,
+        created_by: None,
+        version: 0,
+    }
+}
+
+#[tokio::test]
+async fn rows_of_other_users_are_filtered_out() {
+    let db = MockDatabase::new(DatabaseBackend::Postgres)
+        .append_query_results([vec![note(1, 3)]])
+        .into_connection();
+
+    let response = note_schema(share_mock(&db))
+        .execute(
+            Request::new("{ note { nodes { id } } }")
+                .data(Tenant(7))
+                .data(Viewer(3)),
+        )
+        .await;
+
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        response.data,
+        value!({ "note": { "nodes": [{ "id": 1 }] } })
+    );
+
+    let log = db.into_transaction_log();
+    let statement = &log[0].statements()[0];
+
+    assert!(
+        statement.sql.contains(r#""note"."user_id" = $2"#),
+        "{}",
+        statement.sql
+    );
+    assert_eq!(statement.values.as_ref().unwrap().0[1], Value::Int(Some(3)));
+}
+
+#[tokio::test]
+async fn unrestricted_guard_adds_no_condition() {
+    let db = MockDatabase::new(DatabaseBackend::Postgres)
+        .append_query_results([vec![note(1, 3), note(2, 4)]])
+        .into_connection();
+
+    let response = note_schema(share_mock(&db))
+        .execute(
+            Request::new("{ note { nodes { id } } }")
+                .data(Tenant(7))
+                .data(Staff),
+        )
+        .await;
+
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+    let log = db.into_transaction_log();
+    let sql = &log[0].statements()[0].sql;
+
+    assert!(!sql.contains("user_id\" ="), "{}", sql);
+}
+
+#[tokio::test]
+async fn guard_errors_deny_the_request() {
+    let db = MockDatabase::new(DatabaseBackend::Postgres).into_connection();
+    let schema = note_schema(share_mock(&db));
+
+    for query in [
+        "{ note { nodes { id } } }",
+        "{ noteById(id: 1) { id } }",
+        "mutation { noteDeleteMany(filters: { id: { eq: 1 } }) }",
+        r#"mutation { noteUpdateMany(mutant: { title: "t" }, expectedVersion: 0) }"#,
+    ] {
+        // 沒有 `Viewer` 也不是職員時守衛回傳錯誤，不會執行不受限制的查詢
+        let response = schema.execute(Request::new(query).data(Tenant(7))).await;
+
+        assert_eq!(response.errors.len(), 1, "{}: {:?}", query, response.errors);
+    }
+
+    assert!(db.into_transaction_log().is_empty());
+}
+
+#[tokio::test]
+async fn writes_only_touch_rows_passing_the_guard() {
+    let db = MockDatabase::new(DatabaseBackend::Postgres)
+        .append_exec_results([MockExecResult {
+            last_insert_id: 0,
+            rows_affected: 1,
+        }])
+        .into_connection();
+
+    let response = note_schema(share_mock(&db))
+        .execute(
+            Request::new("mutation { noteDeleteMany }")
+                .data(Tenant(7))
+                .data(Viewer(3)),
+        )
+        .await;
+
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+    let log = db.into_transaction_log();
+    let sql = &log[0].statements()[0].sql;
+
+    assert!(
+        sql.starts_with(r#"UPDATE "note" SET "deleted_at" = CURRENT_TIMESTAMP WHERE"#),
+        "{}",
+        sql
+    );
+    assert!(sql.contains(r#""note"."user_id" = $"#), "{}", sql);
+}
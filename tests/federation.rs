@@ -0,0 +1,84 @@
+extern crate async_graphql_template as seaography;
+
+mod common;
+mod entities;
+
+use async_graphql::{value, Request};
+use sea_orm::{DatabaseBackend, MockDatabase};
+
+pub use common::OrmDataloader;
+use common::{note_schema, share_mock, Viewer};
+use entities::note;
+use seaography::Tenant;
+
+fn note(id: i32) -> note::Model {
+    note::Model {
+        id,
+        org_id: 7,
+        user_id: 3,
+        slug: format!("note-{}", id),
+        title: format!("note {}", id),
+        secret: "s".into(),
+        deleted_at: None,
+        created_by: None,
+        version: 0,
+    }
+}
+
+#[tokio::test]
+async fn entities_resolve_through_one_guarded_query() {
+    let db = MockDatabase::new(DatabaseBackend::Postgres)
+        .append_query_results([vec![note(1), note(2)]])
+        .into_connection();
+
+    let response = note_schema(share_mock(&db))
+        .execute(
+            Request::new(
+                r#"{ _entities(representations: [{ __typename: "Note", id: 1 }, { __typename: "Note", id: 2 }]) { ... on Note { title } } }"#,
+            )
+            .data(Tenant(7))
+            .data(Viewer(3)),
+        )
+        .await;
+
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        response.data,
+        value!({ "_entities": [{ "title": "note 1" }, { "title": "note 2" }] })
+    );
+
+    let log = db.into_transaction_log();
+    let sql = &log[0].statements()[0].sql;
+
+    assert_eq!(log.len(), 1);
+    assert!(
+        sql.ends_with(r#"WHERE "note"."id" IN ($1, $2) AND "note"."org_id" = $3 AND "note"."user_id" = $4 AND "note"."deleted_at" IS NULL"#),
+        "{}",
+        sql
+    );
+}
+
+#[tokio::test]
+async fn entities_need_the_request_scope() {
+    let db = MockDatabase::new(DatabaseBackend::Postgres).into_connection();
+
+    let response = note_schema(share_mock(&db))
+        .execute(r#"{ _entities(representations: [{ __typename: "Note", id: 1 }]) { ... on Note { title } } }"#)
+        .await;
+
+    assert_eq!(response.errors.len(), 1, "{:?}", response.errors);
+    assert!(db.into_transaction_log().is_empty());
+}
+
+#[tokio::test]
+async fn primary_key_is_the_entity_key() {
+    let db = MockDatabase::new(DatabaseBackend::Postgres).into_connection();
+
+    let response = note_schema(db).execute("{ _service { sdl } }").await;
+    let sdl = response.data.into_json().unwrap()["_service"]["sdl"]
+        .as_str()
+        .unwrap()
+        .to_owned();
+
+    assert!(sdl.contains(r#"type Note @key(fields: "id")"#), "{}", sdl);
+}
@@ -0,0 +1,96 @@
+extern crate async_graphql_template as seaography;
+
+mod common;
+mod entities;
+
+use async_graphql::{value, Request};
+use sea_orm::{DatabaseBackend, MockDatabase};
+
+pub use common::OrmDataloader;
+use common::{note_schema, share_mock, Staff, Viewer};
+use entities::note;
+use seaography::Tenant;
+
+fn note() -> note::Model {
+    note::Model {
+        id: 1,
+        org_id: 7,
+        user_id: 3,
+        slug: "a".into(),
+        title: "t".into(),
+        secret: "s".into(),
+        deleted_at: None,
+        created_by: None,
+        version: 0,
+    }
+}
+
+#[tokio::test]
+async fn guarded_field_is_denied_in_every_position() {
+    let db = MockDatabase::new(DatabaseBackend::Postgres).into_connection();
+    let schema = note_schema(share_mock(&db));
+
+    for query in [
+        r#"{ note(filters: { secret: { eq: "s" } }) { nodes { id } } }"#,
+        r#"{ note(filters: { or: [{ secret: { eq: "s" } }] }) { nodes { id } } }"#,
+        "{ note(orderBy: { secret: ASC }) { nodes { id } } }",
+        r#"mutation { noteUpdateMany(mutant: { secret: "s" }, expectedVersion: 0) }"#,
+        r#"mutation { noteCreateOne(mutant: { userId: 3, slug: "a", title: "t", secret: "s" }) { id } }"#,
+    ] {
+        let response = schema
+            .execute(Request::new(query).data(Tenant(7)).data(Viewer(3)))
+            .await;
+
+        assert_eq!(response.errors.len(), 1, "{}: {:?}", query, response.errors);
+        assert_eq!(response.errors[0].message, "staff only", "{}", query);
+    }
+
+    assert!(db.into_transaction_log().is_empty());
+}
+
+#[tokio::test]
+async fn guarded_output_field_is_denied() {
+    let db = MockDatabase::new(DatabaseBackend::Postgres)
+        .append_query_results([vec![note()]])
+        .into_connection();
+
+    let response = note_schema(db)
+        .execute(
+            Request::new("{ note { nodes { id secret } } }")
+                .data(Tenant(7))
+                .data(Viewer(3)),
+        )
+        .await;
+
+    assert_eq!(response.errors.len(), 1, "{:?}", response.errors);
+    assert_eq!(response.errors[0].message, "staff only");
+}
+
+#[tokio::test]
+async fn staff_pass_the_field_guard() {
+    let db = MockDatabase::new(DatabaseBackend::Postgres)
+        .append_query_results([vec![note()]])
+        .into_connection();
+
+    let response = note_schema(share_mock(&db))
+        .execute(
+            Request::new(
+                r#"{ note(filters: { secret: { eq: "s" } }, orderBy: { secret: ASC }) { nodes { secret } } }"#,
+            )
+            .data(Tenant(7))
+            .data(Staff),
+        )
+        .await;
+
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        response.data,
+        value!({ "note": { "nodes": [{ "secret": "s" }] } })
+    );
+
+    let log = db.into_transaction_log();
+    let sql = &log[0].statements()[0].sql;
+
+    assert!(sql.contains(r#""note"."secret" = $1"#), "{}", sql);
+    assert!(sql.contains(r#"ORDER BY "note"."secret" ASC"#), "{}", sql);
+}
@@ -0,0 +1,170 @@
+#![cfg(feature = "with-json")]
+
+use async_graphql::{value, InputType};
+use async_graphql_template::JsonFilter;
+use sea_orm::prelude::Json;
+use sea_orm::sea_query::{
+    Alias, Expr, MysqlQueryBuilder, PostgresQueryBuilder, Query, SqliteQueryBuilder,
+};
+use sea_orm::{DbBackend, DbErr, Value};
+
+fn filter(input: async_graphql::Value) -> JsonFilter {
+    JsonFilter::parse(Some(input)).unwrap()
+}
+
+/// 以 `backend` 轉換過濾條件，回傳 WHERE 之後的 SQL 與綁定的值
+fn build(filter: &JsonFilter, backend: DbBackend) -> Result<(String, Vec<Value>), DbErr> {
+    let condition = filter.to_condition(Expr::col(Alias::new("data")), backend)?;
+    let mut query = Query::select();
+    query
+        .column(Alias::new("id"))
+        .from(Alias::new("doc"))
+        .cond_where(condition);
+
+    let (sql, values) = match backend {
+        DbBackend::Postgres => query.build(PostgresQueryBuilder),
+        DbBackend::MySql => query.build(MysqlQueryBuilder),
+        DbBackend::Sqlite => query.build(SqliteQueryBuilder),
+    };
+
+    Ok((sql.split_once(" WHERE ").unwrap().1.to_owned(), values.0))
+}
+
+fn sql(filter: &JsonFilter, backend: DbBackend) -> String {
+    build(filter, backend).unwrap().0
+}
+
+fn json(value: Json) -> Value {
+    Value::Json(Some(Box::new(value)))
+}
+
+#[test]
+fn eq_on_path_per_backend() {
+    let filter = filter(value!({ "path": ["address", "city"], "eq": "Taipei" }));
+
+    assert_eq!(
+        build(&filter, DbBackend::Postgres).unwrap(),
+        (
+            r#"jsonb_extract_path(CAST("data" AS jsonb), $1, $2) = CAST($3 AS jsonb)"#.to_owned(),
+            vec!["address".into(), "city".into(), json("Taipei".into())],
+        )
+    );
+    assert_eq!(
+        build(&filter, DbBackend::MySql).unwrap(),
+        (
+            "JSON_EXTRACT(`data`, ?) = CAST(? AS JSON)".to_owned(),
+            vec![r#"$."address"."city""#.into(), r#""Taipei""#.into()],
+        )
+    );
+    assert_eq!(
+        build(&filter, DbBackend::Sqlite).unwrap(),
+        (
+            r#"json_extract("data", ?) = ?"#.to_owned(),
+            vec![r#"$."address"."city""#.into(), "Taipei".into()],
+        )
+    );
+}
+
+#[test]
+fn only_bracketed_segments_index_arrays() {
+    let filter = filter(value!({ "path": ["2024", "[0]"], "eq": 1 }));
+
+    let (_, values) = build(&filter, DbBackend::Sqlite).unwrap();
+    assert_eq!(values[0], r#"$."2024"[0]"#.into());
+
+    let (_, values) = build(&filter, DbBackend::MySql).unwrap();
+    assert_eq!(values[0], r#"$."2024"[0]"#.into());
+
+    // jsonb_extract_path 依值的類型決定鍵或索引，索引片段去掉括號
+    let (_, values) = build(&filter, DbBackend::Postgres).unwrap();
+    assert_eq!(values[..2], ["2024".into(), "0".into()]);
+}
+
+#[test]
+fn sqlite_compares_json_null_by_type() {
+    let mut eq = filter(value!({ "path": ["a"] }));
+    eq.eq = Some(Json::Null);
+
+    assert_eq!(
+        build(&eq, DbBackend::Sqlite).unwrap(),
+        (
+            r#"json_type("data", ?) = ?"#.to_owned(),
+            vec![r#"$."a""#.into(), "null".into()],
+        )
+    );
+
+    let mut ne = filter(value!({ "path": ["a"] }));
+    ne.ne = Some(Json::Null);
+
+    assert_eq!(sql(&ne, DbBackend::Sqlite), r#"json_type("data", ?) <> ?"#);
+
+    let is_in = filter(value!({ "path": ["a"], "isIn": [1, null] }));
+
+    assert_eq!(
+        sql(&is_in, DbBackend::Sqlite),
+        r#"json_extract("data", ?) = ? OR json_type("data", ?) = ?"#
+    );
+}
+
+#[test]
+fn contains_per_backend() {
+    let filter = filter(value!({ "contains": { "tags": ["a", null] } }));
+
+    assert_eq!(
+        sql(&filter, DbBackend::Postgres),
+        r#"CAST("data" AS jsonb) @> CAST($1 AS jsonb)"#
+    );
+    assert_eq!(
+        sql(&filter, DbBackend::MySql),
+        "JSON_CONTAINS(`data`, CAST(? AS JSON))"
+    );
+    assert_eq!(
+        sql(&filter, DbBackend::Sqlite),
+        r#"(EXISTS (SELECT 1 FROM json_each("data", ?) WHERE json_each.value = ?)) AND (EXISTS (SELECT 1 FROM json_each("data", ?) WHERE json_each.type = 'null'))"#
+    );
+}
+
+#[test]
+fn has_key_per_backend() {
+    let filter = filter(value!({ "path": ["address"], "hasKey": "city" }));
+
+    assert_eq!(
+        build(&filter, DbBackend::Postgres).unwrap(),
+        (
+            r#"jsonb_exists(jsonb_extract_path(CAST("data" AS jsonb), $1), $2)"#.to_owned(),
+            vec!["address".into(), "city".into()],
+        )
+    );
+    assert_eq!(
+        build(&filter, DbBackend::MySql).unwrap(),
+        (
+            "JSON_CONTAINS_PATH(`data`, ?, ?)".to_owned(),
+            vec!["all".into(), r#"$."address"."city""#.into()],
+        )
+    );
+    assert_eq!(
+        build(&filter, DbBackend::Sqlite).unwrap(),
+        (
+            r#"json_type("data", ?) IS NOT NULL"#.to_owned(),
+            vec![r#"$."address"."city""#.into()],
+        )
+    );
+}
+
+#[test]
+fn contained_by_is_rejected_on_sqlite() {
+    let filter = filter(value!({ "containedBy": { "a": 1 } }));
+
+    assert_eq!(
+        sql(&filter, DbBackend::Postgres),
+        r#"CAST("data" AS jsonb) <@ CAST($1 AS jsonb)"#
+    );
+    assert_eq!(
+        sql(&filter, DbBackend::MySql),
+        "JSON_CONTAINS(CAST(? AS JSON), `data`)"
+    );
+    assert_eq!(
+        build(&filter, DbBackend::Sqlite).unwrap_err(),
+        DbErr::Custom("JsonFilter.containedBy is not supported on SQLite".into())
+    );
+}
@@ -0,0 +1,125 @@
+extern crate async_graphql_template as seaography;
+
+mod common;
+mod entities;
+
+use async_graphql::{value, Request};
+use sea_orm::{DatabaseBackend, MockDatabase, Value};
+
+pub use common::OrmDataloader;
+use common::{note_schema, share_mock, Viewer};
+use entities::note;
+use seaography::Tenant;
+
+fn note(id: i32, slug: &str) -> note::Model {
+    note::Model {
+        id,
+        org_id: 7,
+        user_id: 3,
+        slug: slug.into(),
+        title: format!("note {}", id),
+        secret: "s".into(),
+        deleted_at: None,
+        created_by: None,
+        version: 0,
+    }
+}
+
+fn request(query: &str) -> Request {
+    Request::new(query).data(Tenant(7)).data(Viewer(3))
+}
+
+#[tokio::test]
+async fn lookups_in_one_request_share_a_query() {
+    // 只排入一次查詢的結果，逐筆查詢時第二次查詢會失敗
+    let db = MockDatabase::new(DatabaseBackend::Postgres)
+        .append_query_results([vec![note(1, "a"), note(2, "b")]])
+        .into_connection();
+
+    let response = note_schema(share_mock(&db))
+        .execute(request(
+            "{ a: noteById(id: 1) { title } b: noteById(id: 2) { title } c: noteById(id: 3) { title } }",
+        ))
+        .await;
+
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        response.data,
+        value!({ "a": { "title": "note 1" }, "b": { "title": "note 2" }, "c": null })
+    );
+
+    let log = db.into_transaction_log();
+    let statement = &log[0].statements()[0];
+
+    assert_eq!(log.len(), 1);
+    assert!(
+        statement.sql.contains(r#""note"."id" IN ($1, $2, $3)"#),
+        "{}",
+        statement.sql
+    );
+    // 守衛條件、租戶與軟刪除的限制與鍵一起查詢
+    assert!(
+        statement.sql.ends_with(
+            r#"AND "note"."org_id" = $4 AND "note"."user_id" = $5 AND "note"."deleted_at" IS NULL"#
+        ),
+        "{}",
+        statement.sql
+    );
+    assert_eq!(
+        statement.values.as_ref().unwrap().0[3..],
+        [Value::Int(Some(7)), Value::Int(Some(3))]
+    );
+}
+
+#[tokio::test]
+async fn unique_column_lookup() {
+    let db = MockDatabase::new(DatabaseBackend::Postgres)
+        .append_query_results([vec![note(1, "a")]])
+        .into_connection();
+
+    let response = note_schema(share_mock(&db))
+        .execute(request(
+            r#"{ a: noteBySlug(slug: "a") { id } b: noteBySlug(slug: "b") { id } }"#,
+        ))
+        .await;
+
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(response.data, value!({ "a": { "id": 1 }, "b": null }));
+
+    let log = db.into_transaction_log();
+    let sql = &log[0].statements()[0].sql;
+
+    assert_eq!(log.len(), 1);
+    assert!(sql.contains(r#""note"."slug" IN ($1, $2)"#), "{}", sql);
+}
+
+#[tokio::test]
+async fn callers_with_different_guards_are_queried_apart() {
+    let db = MockDatabase::new(DatabaseBackend::Postgres)
+        .append_query_results([vec![note(1, "a")]])
+        .append_query_results([Vec::<note::Model>::new()])
+        .into_connection();
+
+    let schema = note_schema(share_mock(&db));
+
+    // DataLoader 在請求間共用，另一位使用者不會取得快取中的資料
+    let response = schema.execute(request("{ noteById(id: 1) { id } }")).await;
+    assert_eq!(response.data, value!({ "noteById": { "id": 1 } }));
+
+    let response = schema
+        .execute(
+            Request::new("{ noteById(id: 1) { id } }")
+                .data(Tenant(7))
+                .data(Viewer(4)),
+        )
+        .await;
+    assert_eq!(response.data, value!({ "noteById": null }));
+
+    let log = db.into_transaction_log();
+
+    assert_eq!(log.len(), 2);
+    assert_eq!(
+        log[1].statements()[0].values.as_ref().unwrap().0[2],
+        Value::Int(Some(4))
+    );
+}
@@ -0,0 +1,100 @@
+extern crate async_graphql_template as seaography;
+
+mod common;
+mod entities;
+
+use async_graphql::dataloader::DataLoader;
+use async_graphql::{value, Context, EmptySubscription, InputType, Object, Schema};
+use sea_orm::{DatabaseBackend, EntityTrait, MockDatabase, MockExecResult, QueryTrait};
+
+use common::share_mock;
+pub use common::OrmDataloader;
+use entities::article;
+
+struct Mutation;
+
+#[Object]
+impl Mutation {
+    async fn article_update_many(
+        &self,
+        ctx: &Context<'_>,
+        filters: Option<article::Filter>,
+        mutant: article::Mutant,
+        numeric_mutant: Option<article::NumericMutant>,
+    ) -> async_graphql::Result<u64> {
+        article::update_many(ctx, filters, mutant, numeric_mutant).await
+    }
+}
+
+fn schema(db: sea_orm::DatabaseConnection) -> Schema<common::Query, Mutation, EmptySubscription> {
+    Schema::build(common::Query, Mutation, EmptySubscription)
+        .data(DataLoader::new(OrmDataloader { db }, tokio::spawn))
+        .finish()
+}
+
+#[test]
+fn numeric_mutation_sql() {
+    let numeric_mutant = article::NumericMutant::parse(Some(value!({
+        "likes": { "multiply": 2, "increment": 3, "decrement": 1 },
+        "rating": {},
+    })))
+    .unwrap();
+
+    let sql =
+        article::update_many_with_numeric_mutant(article::Entity::update_many(), numeric_mutant)
+            .build(DatabaseBackend::Postgres)
+            .to_string();
+
+    // 沒有任何操作的欄位不會出現在 SET 中
+    assert_eq!(
+        sql,
+        r#"UPDATE "article" SET "likes" = (("article"."likes" * 2) + 3) - 1"#
+    );
+}
+
+#[tokio::test]
+async fn update_many_applies_mutant_and_numeric_mutant_together() {
+    let db = MockDatabase::new(DatabaseBackend::Sqlite)
+        .append_exec_results([MockExecResult {
+            last_insert_id: 0,
+            rows_affected: 2,
+        }])
+        .into_connection();
+
+    let response = schema(share_mock(&db))
+        .execute(
+            r#"mutation { articleUpdateMany(filters: { postId: { eq: 1 } }, mutant: { title: "t" }, numericMutant: { likes: { increment: 1 } }) }"#,
+        )
+        .await;
+
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(response.data, value!({ "articleUpdateMany": 2 }));
+
+    let log = db.into_transaction_log();
+    let statement = &log[0].statements()[0];
+
+    assert!(
+        statement.sql.starts_with(
+            r#"UPDATE "article" SET "title" = ?, "likes" = "article"."likes" + ? WHERE "article"."post_id" = ?"#
+        ),
+        "{}",
+        statement.sql
+    );
+}
+
+#[tokio::test]
+async fn numeric_mutant_rejects_fields_set_in_mutant() {
+    let db = MockDatabase::new(DatabaseBackend::Sqlite).into_connection();
+
+    let response = schema(share_mock(&db))
+        .execute(
+            "mutation { articleUpdateMany(mutant: { likes: 1 }, numericMutant: { likes: { increment: 1 } }) }",
+        )
+        .await;
+
+    assert_eq!(
+        response.errors[0].message,
+        "field `likes` is set in both mutant and numericMutant"
+    );
+    assert!(db.into_transaction_log().is_empty());
+}
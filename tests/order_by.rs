@@ -0,0 +1,124 @@
+extern crate async_graphql_template as seaography;
+
+mod common;
+mod entities;
+
+use async_graphql::{value, InputType};
+use sea_orm::{DatabaseBackend, EntityTrait, QueryTrait};
+
+pub use common::OrmDataloader;
+use entities::article;
+
+/// 以 `backend` 轉換排序輸入，回傳 SELECT 之後的完整 SQL
+fn sql(input: async_graphql::Value, backend: DatabaseBackend) -> String {
+    let order_by = Vec::<seaography::Ordered<article::OrderBy>>::parse(Some(input)).unwrap();
+
+    article::order_by(article::Entity::find(), Some(order_by))
+        .unwrap()
+        .build(backend)
+        .to_string()
+}
+
+fn order_by_clause(input: async_graphql::Value, backend: DatabaseBackend) -> String {
+    sql(input, backend)
+        .split_once(" ORDER BY ")
+        .unwrap()
+        .1
+        .to_owned()
+}
+
+#[test]
+fn list_items_keep_their_order() {
+    assert_eq!(
+        order_by_clause(
+            value!([{ "likes": "DESC" }, { "field": "TITLE", "direction": "ASC" }]),
+            DatabaseBackend::Postgres
+        ),
+        r#""article"."likes" DESC, "article"."title" ASC"#
+    );
+}
+
+#[test]
+fn keys_in_one_object_follow_the_written_order() {
+    assert_eq!(
+        order_by_clause(
+            value!({ "title": "ASC", "likes": "DESC" }),
+            DatabaseBackend::Postgres
+        ),
+        r#""article"."title" ASC, "article"."likes" DESC"#
+    );
+    assert_eq!(
+        order_by_clause(
+            value!({ "likes": "DESC", "title": "ASC" }),
+            DatabaseBackend::Postgres
+        ),
+        r#""article"."likes" DESC, "article"."title" ASC"#
+    );
+}
+
+#[test]
+fn field_and_direction_go_together() {
+    let order_by =
+        Vec::<seaography::Ordered<article::OrderBy>>::parse(Some(value!({ "field": "TITLE" })))
+            .unwrap();
+
+    assert_eq!(
+        article::order_by(article::Entity::find(), Some(order_by)).unwrap_err(),
+        sea_orm::DbErr::Custom("orderBy `field` and `direction` must be given together".into())
+    );
+}
+
+#[test]
+fn nulls_ordering_per_backend() {
+    let input = || value!({ "rating": "DESC_NULLS_LAST" });
+
+    assert_eq!(
+        order_by_clause(input(), DatabaseBackend::Postgres),
+        r#""article"."rating" DESC NULLS LAST"#
+    );
+    assert_eq!(
+        order_by_clause(input(), DatabaseBackend::Sqlite),
+        r#""article"."rating" DESC NULLS LAST"#
+    );
+    // MySQL 沒有 NULLS LAST，先以 IS NULL 排序
+    assert_eq!(
+        order_by_clause(input(), DatabaseBackend::MySql),
+        "`article`.`rating` IS NULL ASC, `article`.`rating` DESC"
+    );
+    assert_eq!(
+        order_by_clause(
+            value!({ "rating": "ASC_NULLS_FIRST" }),
+            DatabaseBackend::MySql
+        ),
+        "`article`.`rating` IS NULL DESC, `article`.`rating` ASC"
+    );
+}
+
+#[test]
+fn belongs_to_columns_order_through_left_join() {
+    let sql = sql(
+        value!([{ "post": { "likes": "DESC" } }, { "id": "ASC" }]),
+        DatabaseBackend::Postgres,
+    );
+
+    assert!(
+        sql.ends_with(r#"FROM "article" LEFT JOIN "post" AS "order_by_post" ON "article"."post_id" = "order_by_post"."id" ORDER BY "order_by_post"."likes" DESC, "article"."id" ASC"#),
+        "{}",
+        sql
+    );
+}
+
+#[test]
+fn nested_belongs_to_joins_are_aliased_by_path() {
+    let sql = sql(
+        value!([{ "post": { "author": { "name": "ASC" } } }, { "post": { "likes": "DESC" } }]),
+        DatabaseBackend::Postgres,
+    );
+
+    // 別名依關聯路徑命名，同一路徑只 JOIN 一次
+    assert!(
+        sql.ends_with(r#"FROM "article" LEFT JOIN "post" AS "order_by_post" ON "article"."post_id" = "order_by_post"."id" LEFT JOIN "author" AS "order_by_post_author" ON "order_by_post"."author_id" = "order_by_post_author"."id" ORDER BY "order_by_post_author"."name" ASC, "order_by_post"."likes" DESC"#),
+        "{}",
+        sql
+    );
+}
@@ -0,0 +1,89 @@
+extern crate async_graphql_template as seaography;
+
+mod common;
+mod entities;
+
+use async_graphql::value;
+use sea_orm::{DatabaseBackend, DatabaseConnection, MockDatabase};
+
+pub use common::OrmDataloader;
+use common::{schema, share_mock, Staff};
+use entities::{author, post};
+
+fn post_row() -> post::Model {
+    post::Model {
+        id: 1,
+        author_id: 2,
+        title: "first".into(),
+        likes: 3,
+    }
+}
+
+/// 執行過的 SQL，只保留 WHERE 之前的部分
+fn executed_sql(db: DatabaseConnection) -> Vec<String> {
+    db.into_transaction_log()
+        .iter()
+        .flat_map(|transaction| transaction.statements().iter())
+        .map(|statement| statement.sql.split(" WHERE ").next().unwrap().to_owned())
+        .collect()
+}
+
+#[tokio::test]
+async fn root_connection_selects_requested_columns() {
+    let db = MockDatabase::new(DatabaseBackend::Postgres)
+        .append_query_results([[post_row()], [post_row()]])
+        .into_connection();
+
+    let response = schema(share_mock(&db))
+        .execute(async_graphql::Request::new("{ post { nodes { title } } }").data(Staff))
+        .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+    let response = schema(share_mock(&db))
+        .execute("{ post { edges { node { likes } } } }")
+        .await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+    assert_eq!(
+        executed_sql(db),
+        vec![
+            r#"SELECT "post"."title", "post"."id", "post"."author_id" FROM "post""#,
+            r#"SELECT "post"."likes", "post"."id", "post"."author_id" FROM "post""#,
+        ]
+    );
+}
+
+#[tokio::test]
+async fn unmapped_output_field_selects_every_column() {
+    let author = author::Model {
+        id: 2,
+        name: "writer".into(),
+    };
+
+    let db = MockDatabase::new(DatabaseBackend::Postgres)
+        .append_query_results([[post_row()]])
+        .append_query_results([[author]])
+        .into_connection();
+
+    let response = schema(share_mock(&db))
+        .execute("{ post { nodes { id author { displayName } } } }")
+        .await;
+
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        response.data,
+        value!({ "post": { "nodes": [{ "id": 1, "author": { "displayName": "writer" } }] } })
+    );
+
+    let sql = executed_sql(db);
+
+    // 關聯欄位只需要外鍵，不影響根查詢的投影
+    assert_eq!(
+        sql[0],
+        r#"SELECT "post"."id", "post"."author_id" FROM "post""#
+    );
+    assert_eq!(
+        sql[1],
+        r#"SELECT "author"."id", "author"."name" FROM "author""#
+    );
+}
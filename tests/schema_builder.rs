@@ -0,0 +1,643 @@
+extern crate async_graphql_template as seaography;
+
+mod common;
+mod entities;
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use async_graphql::dataloader::DataLoader;
+use async_graphql::types::connection::{Connection, EmptyFields};
+use async_graphql::{value, Context, EmptySubscription, MergedObject, Object, Request};
+use sea_orm::{ConnectionTrait, DatabaseBackend, EntityTrait, MockDatabase, QueryFilter};
+
+pub use common::OrmDataloader;
+use common::{share_mock, Staff};
+#[cfg(feature = "with-array")]
+use entities::article;
+#[cfg(feature = "with-chrono")]
+use entities::event;
+use entities::{author, note, post};
+use seaography::Tenant;
+
+fn post(id: i32, author_id: i32) -> post::Model {
+    post::Model {
+        id,
+        author_id,
+        title: format!("post {}", id),
+        likes: id,
+    }
+}
+
+/// 文章的數組欄位需要 `with-array`，事件的時間欄位需要 `with-chrono`；
+/// 事件有稽核欄位，以 `register_guarded` 註冊才會從 `Mutant` 排除
+fn schema(db: sea_orm::DatabaseConnection) -> async_graphql::dynamic::Schema {
+    let builder = async_graphql_template::SchemaBuilder::new()
+        .register::<author::Entity>()
+        .register::<post::Entity>()
+        .register_guarded::<note::Entity>();
+
+    #[cfg(feature = "with-array")]
+    let builder = builder.register::<article::Entity>();
+
+    #[cfg(feature = "with-chrono")]
+    let builder = builder.register_guarded::<event::Entity>();
+
+    builder.finish(db, tokio::spawn).unwrap()
+}
+
+#[tokio::test]
+async fn has_many_relation_is_batched() {
+    let authors: Vec<author::Model> = (1..=3)
+        .map(|id| author::Model {
+            id,
+            name: format!("author {}", id),
+        })
+        .collect();
+
+    // 只排入兩次查詢的結果，逐列查詢時第三次查詢會失敗
+    let db = MockDatabase::new(DatabaseBackend::Postgres)
+        .append_query_results([authors])
+        .append_query_results([vec![post(1, 1), post(2, 3), post(3, 1)]])
+        .into_connection();
+
+    let response = schema(share_mock(&db))
+        .execute("{ author { nodes { id post { id } } } }")
+        .await;
+
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        response.data,
+        value!({
+            "author": {
+                "nodes": [
+                    { "id": 1, "post": [{ "id": 1 }, { "id": 3 }] },
+                    { "id": 2, "post": [] },
+                    { "id": 3, "post": [{ "id": 2 }] },
+                ],
+            },
+        })
+    );
+
+    let log = db.into_transaction_log();
+    let sql = &log[1].statements()[0].sql;
+
+    assert_eq!(log.len(), 2);
+    assert!(
+        sql.contains(r#""post"."author_id" IN ($1, $2, $3)"#),
+        "{}",
+        sql
+    );
+}
+
+#[tokio::test]
+async fn belongs_to_relation_shares_keys() {
+    let db = MockDatabase::new(DatabaseBackend::Postgres)
+        .append_query_results([vec![post(1, 1), post(2, 2), post(3, 1)]])
+        .append_query_results([vec![
+            author::Model {
+                id: 1,
+                name: "first".into(),
+            },
+            author::Model {
+                id: 2,
+                name: "second".into(),
+            },
+        ]])
+        .into_connection();
+
+    let response = schema(share_mock(&db))
+        .execute("{ post { nodes { id author { displayName } } } }")
+        .await;
+
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        response.data,
+        value!({
+            "post": {
+                "nodes": [
+                    { "id": 1, "author": { "displayName": "first" } },
+                    { "id": 2, "author": { "displayName": "second" } },
+                    { "id": 3, "author": { "displayName": "first" } },
+                ],
+            },
+        })
+    );
+
+    let log = db.into_transaction_log();
+    let sql = &log[1].statements()[0].sql;
+
+    assert_eq!(log.len(), 2);
+    assert!(sql.contains(r#""author"."id" IN ($1, $2)"#), "{}", sql);
+}
+
+/// 與 `SchemaBuilder` 相同的根查詢：連線、主鍵與唯一欄位查詢
+macro_rules! root_query {
+    ($name:ident, $module:ident, $by_id:ident $(, $by_unique:ident => $find:ident($column:ident: $ty:ty))*) => {
+        #[derive(Default)]
+        struct $name;
+
+        #[Object]
+        impl $name {
+            async fn $module(
+                &self,
+                ctx: &Context<'_>,
+                filters: Option<$module::Filter>,
+                pagination: Option<seaography::Pagination>,
+                order_by: Option<Vec<seaography::Ordered<$module::OrderBy>>>,
+                distinct_on: Option<Vec<$module::QueryColumn>>,
+                with_deleted: Option<bool>,
+            ) -> async_graphql::Result<
+                Connection<String, $module::Model, $module::ConnectionFields, EmptyFields>,
+            > {
+                let db = &ctx.data::<DataLoader<OrmDataloader>>()?.loader().db;
+                let backend = db.get_database_backend();
+
+                let condition =
+                    $module::try_filter_with_deleted(ctx, filters.clone(), with_deleted, backend)?;
+                let stmt = $module::distinct_on_guarded(
+                    ctx,
+                    $module::Entity::find().filter(condition.clone()),
+                    distinct_on,
+                    condition,
+                    order_by.as_deref(),
+                    backend,
+                )?;
+                let stmt = $module::order_by_guarded(ctx, stmt, order_by)?;
+
+                seaography::fetch_connection_with_columns::<_, $module::PartialModel, _>(
+                    db,
+                    stmt,
+                    None,
+                    pagination,
+                    |pagination| $module::ConnectionFields {
+                        pagination,
+                        filters,
+                        with_deleted,
+                    },
+                )
+                .await
+            }
+
+            async fn $by_id(
+                &self,
+                ctx: &Context<'_>,
+                id: i32,
+            ) -> async_graphql::Result<Option<$module::Model>> {
+                $module::by_id(ctx, id).await
+            }
+
+            $(
+                async fn $by_unique(
+                    &self,
+                    ctx: &Context<'_>,
+                    $column: $ty,
+                ) -> async_graphql::Result<Option<$module::Model>> {
+                    $module::$find(ctx, $column).await
+                }
+            )*
+        }
+    };
+}
+
+root_query!(AuthorQuery, author, author_by_id);
+root_query!(PostQuery, post, post_by_id);
+root_query!(NoteQuery, note, note_by_id, note_by_slug => by_slug(slug: String));
+#[cfg(feature = "with-array")]
+root_query!(ArticleQuery, article, article_by_id);
+#[cfg(feature = "with-chrono")]
+root_query!(EventQuery, event, event_by_id);
+
+#[cfg(not(feature = "with-array"))]
+type ArticleQuery = async_graphql::EmptyMutation;
+#[cfg(not(feature = "with-chrono"))]
+type EventQuery = async_graphql::EmptyMutation;
+
+#[derive(MergedObject, Default)]
+#[graphql(name = "Query")]
+struct DeriveQuery(AuthorQuery, PostQuery, NoteQuery, ArticleQuery, EventQuery);
+
+/// 與 `SchemaBuilder` 相同的 `{table}UpdateMany` 變更
+macro_rules! update_many {
+    ($name:ident, $field:ident, $module:ident $(, $arg:ident: $ty:ty)*) => {
+        #[derive(Default)]
+        struct $name;
+
+        #[Object]
+        impl $name {
+            async fn $field(
+                &self,
+                ctx: &Context<'_>,
+                filters: Option<$module::Filter>,
+                mutant: $module::Mutant,
+                $($arg: $ty,)*
+            ) -> async_graphql::Result<u64> {
+                $module::update_many(ctx, filters, mutant $(, $arg)*).await
+            }
+        }
+    };
+}
+
+update_many!(AuthorMutation, author_update_many, author);
+update_many!(
+    PostMutation,
+    post_update_many,
+    post,
+    numeric_mutant: Option<post::NumericMutant>
+);
+update_many!(
+    NoteMutation,
+    note_update_many,
+    note,
+    numeric_mutant: Option<note::NumericMutant>,
+    expected_version: i32
+);
+#[cfg(feature = "with-array")]
+update_many!(
+    ArticleMutation,
+    article_update_many,
+    article,
+    numeric_mutant: Option<article::NumericMutant>
+);
+#[cfg(feature = "with-chrono")]
+update_many!(EventMutation, event_update_many, event);
+
+#[cfg(not(feature = "with-array"))]
+type ArticleMutation = async_graphql::EmptyMutation;
+#[cfg(not(feature = "with-chrono"))]
+type EventMutation = async_graphql::EmptyMutation;
+
+#[derive(MergedObject, Default)]
+#[graphql(name = "Mutation")]
+struct DeriveMutation(
+    AuthorMutation,
+    PostMutation,
+    NoteMutation,
+    ArticleMutation,
+    EventMutation,
+);
+
+/// SDL 中的類型與其欄位，忽略文件說明與欄位順序
+fn sdl_types(sdl: &str) -> BTreeMap<String, BTreeSet<String>> {
+    let mut types = BTreeMap::new();
+    let mut current: Option<String> = None;
+    let mut in_description = false;
+
+    for line in sdl.lines().map(str::trim) {
+        if line == r#"""""# {
+            in_description = !in_description;
+            continue;
+        }
+
+        if in_description || line.is_empty() || line.starts_with(r#"""""#) {
+            continue;
+        }
+
+        if line == "}" {
+            current = None;
+            continue;
+        }
+
+        match &current {
+            Some(name) => {
+                let fields: &mut BTreeSet<String> = types.get_mut(name).unwrap();
+                fields.insert(line.to_owned());
+            }
+            None => {
+                let name = line.trim_end_matches('{').trim_end().to_owned();
+
+                if line.ends_with('{') {
+                    current = Some(name.clone());
+                }
+
+                types.insert(name, BTreeSet::new());
+            }
+        }
+    }
+
+    types
+}
+
+#[test]
+fn dynamic_sdl_matches_derive_sdl() {
+    let db = || MockDatabase::new(DatabaseBackend::Postgres).into_connection();
+    let derive = async_graphql::Schema::build(
+        DeriveQuery::default(),
+        DeriveMutation::default(),
+        EmptySubscription,
+    )
+    .data(DataLoader::new(OrmDataloader { db: db() }, tokio::spawn))
+    .finish();
+
+    let derive = sdl_types(&derive.sdl());
+    let dynamic = sdl_types(&schema(db()).sdl());
+
+    let mut diff = String::new();
+
+    for name in derive.keys().filter(|name| !dynamic.contains_key(*name)) {
+        diff += &format!("- {}\n", name);
+    }
+
+    for name in dynamic.keys().filter(|name| !derive.contains_key(*name)) {
+        diff += &format!("+ {}\n", name);
+    }
+
+    for (name, fields) in derive.iter() {
+        let other = match dynamic.get(name) {
+            Some(other) => other,
+            None => continue,
+        };
+
+        for field in fields.difference(other) {
+            diff += &format!("- {}: {}\n", name, field);
+        }
+
+        for field in other.difference(fields) {
+            diff += &format!("+ {}: {}\n", name, field);
+        }
+    }
+
+    assert!(diff.is_empty(), "\n{}", diff);
+}
+
+/// `post` 的聚合結果列，`likes` 以外的數值欄位為 NULL
+fn post_aggregate_row(
+    author_id: Option<i32>,
+    count: i64,
+    likes: f64,
+) -> BTreeMap<String, sea_orm::Value> {
+    let mut row = BTreeMap::new();
+
+    if let Some(author_id) = author_id {
+        row.insert("key_AuthorId".to_owned(), author_id.into());
+    }
+
+    row.insert("count".to_owned(), count.into());
+
+    for column in ["id", "author_id", "likes"] {
+        let sum = Some(likes).filter(|_| column == "likes");
+
+        row.insert(format!("sum_{}", column), sum.into());
+        row.insert(
+            format!("avg_{}", column),
+            sum.map(|sum| sum / count as f64).into(),
+        );
+        row.insert(format!("min_{}", column), None::<i32>.into());
+        row.insert(format!("max_{}", column), None::<i32>.into());
+    }
+
+    row
+}
+
+#[tokio::test]
+async fn aggregate_and_group_by_use_connection_filters() {
+    let db = MockDatabase::new(DatabaseBackend::Postgres)
+        .append_query_results([vec![post(1, 1), post(2, 1), post(3, 2)]])
+        .append_query_results([vec![post_aggregate_row(None, 3, 6.0)]])
+        .append_query_results([vec![post_aggregate_row(Some(1), 2, 3.0)]])
+        .into_connection();
+
+    let response = schema(share_mock(&db))
+        .execute(
+            "{ post(filters: { likes: { gt: 0 } }) {
+                aggregate { count sum { likes } }
+                groupBy(by: [AUTHOR_ID], having: { count: { gt: 1 } }) {
+                    key { authorId }
+                    aggregate { count avg { likes } }
+                }
+            } }",
+        )
+        .await;
+
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        response.data,
+        value!({
+            "post": {
+                "aggregate": { "count": 3, "sum": { "likes": 6.0 } },
+                "groupBy": [
+                    { "key": { "authorId": 1 }, "aggregate": { "count": 2, "avg": { "likes": 1.5 } } },
+                ],
+            },
+        })
+    );
+
+    let log = db.into_transaction_log();
+    let aggregate = &log[1].statements()[0].sql;
+    let group_by = &log[2].statements()[0].sql;
+
+    assert_eq!(log.len(), 3);
+    assert!(
+        aggregate.contains(r#"COUNT(*) AS "count""#),
+        "{}",
+        aggregate
+    );
+    assert!(
+        aggregate.contains(r#""post"."likes" > $1"#),
+        "{}",
+        aggregate
+    );
+    assert!(group_by.contains(r#""post"."likes" > $1"#), "{}", group_by);
+    assert!(
+        group_by.contains(r#"GROUP BY "post"."author_id" HAVING COUNT(*) > $2"#),
+        "{}",
+        group_by
+    );
+}
+
+#[tokio::test]
+async fn relation_aggregate_is_batched() {
+    let authors: Vec<author::Model> = (1..=2)
+        .map(|id| author::Model {
+            id,
+            name: format!("author {}", id),
+        })
+        .collect();
+
+    let db = MockDatabase::new(DatabaseBackend::Postgres)
+        .append_query_results([authors])
+        .append_query_results([vec![post_aggregate_row(Some(1), 2, 3.0)]])
+        .into_connection();
+
+    let response = schema(share_mock(&db))
+        .execute("{ author { nodes { id postAggregate { count } } } }")
+        .await;
+
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        response.data,
+        value!({
+            "author": {
+                "nodes": [
+                    { "id": 1, "postAggregate": { "count": 2 } },
+                    { "id": 2, "postAggregate": { "count": 0 } },
+                ],
+            },
+        })
+    );
+
+    let log = db.into_transaction_log();
+    let sql = &log[1].statements()[0].sql;
+
+    assert_eq!(log.len(), 2);
+    assert!(sql.contains(r#""post"."author_id" IN ($1, $2)"#), "{}", sql);
+    assert!(sql.contains(r#"GROUP BY "post"."author_id""#), "{}", sql);
+}
+
+#[tokio::test]
+async fn distinct_on_follows_order_by() {
+    let db = MockDatabase::new(DatabaseBackend::Postgres)
+        .append_query_results([vec![post(3, 1), post(2, 2)]])
+        .into_connection();
+
+    let response = schema(share_mock(&db))
+        .execute(
+            "{ post(distinctOn: [AUTHOR_ID], orderBy: [{ authorId: ASC }, { likes: DESC }]) {
+                nodes { id }
+            } }",
+        )
+        .await;
+
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        response.data,
+        value!({ "post": { "nodes": [{ "id": 3 }, { "id": 2 }] } })
+    );
+
+    let log = db.into_transaction_log();
+    let sql = &log[0].statements()[0].sql;
+
+    assert!(
+        sql.starts_with(r#"SELECT DISTINCT ON ("post"."author_id")"#),
+        "{}",
+        sql
+    );
+    assert!(
+        sql.contains(r#"ORDER BY "post"."author_id" ASC, "post"."likes" DESC"#),
+        "{}",
+        sql
+    );
+}
+
+#[tokio::test]
+async fn distinct_on_rejects_related_order_by() {
+    let db = MockDatabase::new(DatabaseBackend::Postgres).into_connection();
+
+    let response = schema(share_mock(&db))
+        .execute(
+            "{ post(distinctOn: [AUTHOR_ID], orderBy: [{ author: { id: ASC } }]) {
+                nodes { id }
+            } }",
+        )
+        .await;
+
+    assert_eq!(
+        response.errors[0].message,
+        "distinctOn columns must match the leading orderBy columns, got related `author`"
+    );
+    assert!(db.into_transaction_log().is_empty());
+}
+
+#[tokio::test]
+async fn with_deleted_includes_soft_deleted_rows() {
+    let db = MockDatabase::new(DatabaseBackend::Postgres)
+        .append_query_results([Vec::<note::Model>::new()])
+        .append_query_results([Vec::<note::Model>::new()])
+        .into_connection();
+    let schema = schema(share_mock(&db));
+
+    for query in [
+        "{ note { nodes { id } } }",
+        "{ note(withDeleted: true) { nodes { id } } }",
+    ] {
+        let response = schema
+            .execute(Request::new(query).data(Tenant(7)).data(Staff))
+            .await;
+
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+    }
+
+    let log = db.into_transaction_log();
+    let hidden = &log[0].statements()[0].sql;
+    let with_deleted = &log[1].statements()[0].sql;
+
+    assert!(
+        hidden.contains(r#""note"."deleted_at" IS NULL"#),
+        "{}",
+        hidden
+    );
+    assert!(!with_deleted.contains("IS NULL"), "{}", with_deleted);
+}
+
+#[tokio::test]
+async fn lookups_are_batched() {
+    let db = MockDatabase::new(DatabaseBackend::Postgres)
+        .append_query_results([vec![post(1, 1), post(2, 1)]])
+        .into_connection();
+
+    let response = schema(share_mock(&db))
+        .execute("{ first: postById(id: 1) { id } second: postById(id: 2) { id } missing: postById(id: 3) { id } }")
+        .await;
+
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        response.data,
+        value!({ "first": { "id": 1 }, "second": { "id": 2 }, "missing": null })
+    );
+
+    let log = db.into_transaction_log();
+    let sql = &log[0].statements()[0].sql;
+
+    assert_eq!(log.len(), 1);
+    assert!(sql.contains(r#""post"."id" IN ($1, $2, $3)"#), "{}", sql);
+}
+
+#[cfg(feature = "with-array")]
+#[tokio::test]
+async fn array_columns_filter_and_mutate() {
+    let db = MockDatabase::new(DatabaseBackend::Postgres)
+        .append_query_results([vec![article::Model {
+            id: 1,
+            post_id: 1,
+            title: "article".into(),
+            tags: vec!["rust".into()],
+            scores: vec![1, 2],
+            likes: 0,
+            rating: None,
+        }]])
+        .append_exec_results([sea_orm::MockExecResult {
+            last_insert_id: 0,
+            rows_affected: 1,
+        }])
+        .into_connection();
+    let schema = schema(share_mock(&db));
+
+    let response = schema
+        .execute(
+            r#"{ article(filters: { tags: { contains: ["rust"] } }) { nodes { tags scores } } }"#,
+        )
+        .await;
+
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        response.data,
+        value!({ "article": { "nodes": [{ "tags": ["rust"], "scores": [1, 2] }] } })
+    );
+
+    let response = schema
+        .execute(r#"mutation { articleUpdateMany(mutant: { tags: { push: ["new"] } }) }"#)
+        .await;
+
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(response.data, value!({ "articleUpdateMany": 1 }));
+
+    let log = db.into_transaction_log();
+    let filter = &log[0].statements()[0].sql;
+    let update = &log[1].statements()[0].sql;
+
+    assert!(filter.contains(r#""article"."tags" @> $1"#), "{}", filter);
+    assert!(
+        update.contains(r#"SET "tags" = array_cat("article"."tags", $1)"#),
+        "{}",
+        update
+    );
+}
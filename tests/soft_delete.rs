@@ -0,0 +1,118 @@
+extern crate async_graphql_template as seaography;
+
+mod common;
+mod entities;
+
+use async_graphql::Request;
+use sea_orm::{DatabaseBackend, MockDatabase, MockExecResult};
+
+pub use common::OrmDataloader;
+use common::{note_schema, share_mock, Viewer};
+use entities::note;
+use seaography::Tenant;
+
+fn request(query: &str) -> Request {
+    Request::new(query).data(Tenant(7)).data(Viewer(3))
+}
+
+fn exec(rows_affected: u64) -> MockExecResult {
+    MockExecResult {
+        last_insert_id: 0,
+        rows_affected,
+    }
+}
+
+/// 執行 `query` 並回傳第一條 SQL
+async fn first_sql(query: &str) -> String {
+    let db = MockDatabase::new(DatabaseBackend::Postgres)
+        .append_query_results([Vec::<note::Model>::new()])
+        .append_exec_results([exec(1)])
+        .into_connection();
+
+    let response = note_schema(share_mock(&db)).execute(request(query)).await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+    let log = db.into_transaction_log();
+    let sql = log[0].statements()[0].sql.clone();
+    sql
+}
+
+#[tokio::test]
+async fn deleted_rows_are_hidden_unless_requested() {
+    let sql = first_sql("{ note { nodes { id } } }").await;
+    assert!(
+        sql.ends_with(r#"AND "note"."deleted_at" IS NULL"#),
+        "{}",
+        sql
+    );
+
+    let sql = first_sql("{ note(withDeleted: true) { nodes { id } } }").await;
+    assert!(!sql.contains("deleted_at\" IS NULL"), "{}", sql);
+
+    let sql = first_sql("{ noteById(id: 1) { id } }").await;
+    assert!(
+        sql.ends_with(r#"AND "note"."deleted_at" IS NULL"#),
+        "{}",
+        sql
+    );
+}
+
+#[tokio::test]
+async fn delete_many_sets_the_column() {
+    let sql = first_sql("mutation { noteDeleteMany(filters: { id: { eq: 1 } }) }").await;
+
+    assert!(
+        sql.starts_with(
+            r#"UPDATE "note" SET "deleted_at" = CURRENT_TIMESTAMP WHERE "note"."id" = $1"#
+        ),
+        "{}",
+        sql
+    );
+    // 已刪除的資料保留原本的刪除時間
+    assert!(
+        sql.ends_with(r#"AND "note"."deleted_at" IS NULL"#),
+        "{}",
+        sql
+    );
+}
+
+#[tokio::test]
+async fn restore_many_clears_the_column_of_deleted_rows() {
+    let sql = first_sql("mutation { noteRestoreMany(filters: { id: { eq: 1 } }) }").await;
+
+    assert!(
+        sql.starts_with(r#"UPDATE "note" SET "deleted_at" = $1 WHERE "note"."id" = $2"#),
+        "{}",
+        sql
+    );
+    assert!(sql.contains(r#""note"."org_id" = $"#), "{}", sql);
+    assert!(
+        sql.ends_with(r#"AND "note"."deleted_at" IS NOT NULL"#),
+        "{}",
+        sql
+    );
+}
+
+#[tokio::test]
+async fn with_deleted_is_only_shown_on_soft_delete_entities() {
+    // `visible` 只影響內省，匯出的 SDL 不會隱藏參數
+    let args = "{ __type(name: \"Post\") { fields { name args { name } } } }";
+    let response = common::schema(MockDatabase::new(DatabaseBackend::Postgres).into_connection())
+        .execute(args)
+        .await;
+    let fields = response.data.into_json().unwrap().to_string();
+
+    assert!(
+        fields.contains(r#"{"args":[],"name":"author"}"#),
+        "{}",
+        fields
+    );
+
+    let args = "{ __schema { queryType { fields { name args { name } } } } }";
+    let response = note_schema(MockDatabase::new(DatabaseBackend::Postgres).into_connection())
+        .execute(args)
+        .await;
+    let fields = response.data.into_json().unwrap().to_string();
+
+    assert!(fields.contains(r#"{"name":"withDeleted"}]"#), "{}", fields);
+}
@@ -0,0 +1,56 @@
+#![cfg(feature = "with-chrono")]
+
+use async_graphql::{value, InputType};
+use async_graphql_template::DateTimeFilter;
+use sea_orm::sea_query::{
+    Alias, Expr, MysqlQueryBuilder, PostgresQueryBuilder, Query, SqliteQueryBuilder,
+};
+use sea_orm::DbBackend;
+
+/// 以 `backend` 轉換過濾條件，回傳 WHERE 之後的 SQL
+fn sql(input: async_graphql::Value, backend: DbBackend) -> String {
+    let filter = DateTimeFilter::parse(Some(input)).unwrap();
+    let mut query = Query::select();
+    query
+        .column(Alias::new("id"))
+        .from(Alias::new("event"))
+        .cond_where(filter.to_condition(Expr::col(Alias::new("at")), backend));
+
+    let sql = match backend {
+        DbBackend::Postgres => query.to_string(PostgresQueryBuilder),
+        DbBackend::MySql => query.to_string(MysqlQueryBuilder),
+        DbBackend::Sqlite => query.to_string(SqliteQueryBuilder),
+    };
+
+    sql.split_once(" WHERE ").unwrap().1.to_owned()
+}
+
+#[test]
+fn date_parts_per_backend() {
+    let input =
+        || value!({ "year": { "eq": 2024 }, "month": { "gte": 6 }, "dayOfWeek": { "eq": 0 } });
+
+    assert_eq!(
+        sql(input(), DbBackend::Postgres),
+        r#"(CAST(EXTRACT(YEAR FROM "at") AS integer)) = 2024 AND (CAST(EXTRACT(MONTH FROM "at") AS integer)) >= 6 AND (CAST(EXTRACT(DOW FROM "at") AS integer)) = 0"#
+    );
+    assert_eq!(
+        sql(input(), DbBackend::MySql),
+        "(YEAR(`at`)) = 2024 AND (MONTH(`at`)) >= 6 AND ((DAYOFWEEK(`at`) - 1)) = 0"
+    );
+    assert_eq!(
+        sql(input(), DbBackend::Sqlite),
+        r#"(CAST(strftime('%Y', "at") AS INTEGER)) = 2024 AND (CAST(strftime('%m', "at") AS INTEGER)) >= 6 AND (CAST(strftime('%w', "at") AS INTEGER)) = 0"#
+    );
+}
+
+#[test]
+fn between_on_date_time() {
+    assert_eq!(
+        sql(
+            value!({ "between": { "from": "2024-01-01T00:00:00", "to": "2024-12-31T23:59:59" } }),
+            DbBackend::Sqlite
+        ),
+        r#""at" BETWEEN '2024-01-01 00:00:00.000000' AND '2024-12-31 23:59:59.000000'"#
+    );
+}
@@ -0,0 +1,146 @@
+extern crate async_graphql_template as seaography;
+
+mod common;
+mod entities;
+
+use async_graphql::{value, Request};
+use sea_orm::{DatabaseBackend, MockDatabase, MockExecResult, Value};
+
+pub use common::OrmDataloader;
+use common::{note_schema, share_mock, Staff};
+use entities::note;
+use seaography::Tenant;
+
+fn request(query: &str) -> Request {
+    Request::new(query).data(Tenant(7)).data(Staff)
+}
+
+/// 最後一條 SQL 與其綁定的值
+fn last_statement(db: sea_orm::DatabaseConnection) -> (String, Vec<Value>) {
+    let log = db.into_transaction_log();
+    let statement = &log.last().unwrap().statements()[0];
+
+    (
+        statement.sql.clone(),
+        statement.values.as_ref().unwrap().0.clone(),
+    )
+}
+
+fn exec(rows_affected: u64) -> MockExecResult {
+    MockExecResult {
+        last_insert_id: 0,
+        rows_affected,
+    }
+}
+
+#[tokio::test]
+async fn reads_are_scoped_to_the_tenant() {
+    let db = MockDatabase::new(DatabaseBackend::Postgres)
+        .append_query_results([Vec::<note::Model>::new()])
+        .into_connection();
+
+    let response = note_schema(share_mock(&db))
+        .execute(request(
+            r#"{ note(filters: { orgId: { eq: 8 } }) { nodes { id } } }"#,
+        ))
+        .await;
+
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+    // 過濾其他租戶只會與自己的租戶條件同時成立
+    let (sql, values) = last_statement(db);
+    assert!(
+        sql.contains(r#""note"."org_id" = $1 AND ("note"."org_id" = $2"#),
+        "{}",
+        sql
+    );
+    assert_eq!(values[..2], [Value::Int(Some(8)), Value::Int(Some(7))]);
+}
+
+#[tokio::test]
+async fn lookups_are_scoped_to_the_tenant() {
+    let db = MockDatabase::new(DatabaseBackend::Postgres)
+        .append_query_results([Vec::<note::Model>::new()])
+        .into_connection();
+
+    let response = note_schema(share_mock(&db))
+        .execute(request("{ noteById(id: 1) { id } }"))
+        .await;
+
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(response.data, value!({ "noteById": null }));
+
+    let (sql, values) = last_statement(db);
+    assert!(sql.contains(r#""note"."org_id" = $2"#), "{}", sql);
+    assert_eq!(values[1], Value::Int(Some(7)));
+}
+
+#[tokio::test]
+async fn updates_and_deletes_are_scoped_to_the_tenant() {
+    let db = MockDatabase::new(DatabaseBackend::Postgres)
+        .append_exec_results([exec(1)])
+        .into_connection();
+
+    let response = note_schema(share_mock(&db))
+        .execute(request(
+            r#"mutation { noteUpdateMany(filters: { id: { eq: 1 } }, mutant: { title: "t" }, expectedVersion: 0) }"#,
+        ))
+        .await;
+
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+    let (sql, values) = last_statement(db);
+    assert!(
+        sql.starts_with(r#"UPDATE "note" SET "title" = $1"#),
+        "{}",
+        sql
+    );
+    assert!(sql.contains(r#""note"."org_id" = $"#), "{}", sql);
+    assert!(values.contains(&Value::Int(Some(7))), "{:?}", values);
+
+    let db = MockDatabase::new(DatabaseBackend::Postgres)
+        .append_exec_results([exec(1)])
+        .into_connection();
+
+    let response = note_schema(share_mock(&db))
+        .execute(request(
+            "mutation { noteDeleteMany(filters: { id: { eq: 1 } }) }",
+        ))
+        .await;
+
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+    let (sql, values) = last_statement(db);
+    assert!(
+        sql.contains(r#""note"."id" = $1 AND ("note"."org_id" = $2"#),
+        "{}",
+        sql
+    );
+    assert_eq!(values[1], Value::Int(Some(7)));
+}
+
+#[tokio::test]
+async fn missing_tenant_is_an_error() {
+    let db = MockDatabase::new(DatabaseBackend::Postgres).into_connection();
+
+    let response = note_schema(share_mock(&db))
+        .execute(Request::new("{ note { nodes { id } } }").data(Staff))
+        .await;
+
+    assert_eq!(response.errors.len(), 1, "{:?}", response.errors);
+    assert!(db.into_transaction_log().is_empty());
+}
+
+#[test]
+fn tenant_column_is_left_out_of_mutant() {
+    let sdl = note_schema(MockDatabase::new(DatabaseBackend::Postgres).into_connection()).sdl();
+    let mutant = sdl
+        .split("input NoteMutant {")
+        .nth(1)
+        .and_then(|rest| rest.split('}').next())
+        .unwrap();
+
+    // 只有標記為租戶的欄位被排除，同名慣例的 `user_id` 仍可修改
+    assert!(!mutant.contains("orgId"), "{}", mutant);
+    assert!(mutant.contains("userId: Int"), "{}", mutant);
+}
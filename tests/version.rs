@@ -0,0 +1,232 @@
+extern crate async_graphql_template as seaography;
+
+mod common;
+mod entities;
+
+use std::collections::BTreeMap;
+
+use async_graphql::dataloader::DataLoader;
+use async_graphql::{value, Context, EmptySubscription, Object, Request, Schema};
+use sea_orm::ActiveValue::{Set, Unchanged};
+use sea_orm::{DatabaseBackend, MockDatabase, MockExecResult, Value};
+
+pub use common::OrmDataloader;
+use common::{share_mock, NoteMutation, NoteRoot, Viewer};
+use entities::note;
+use seaography::Tenant;
+
+/// 以 `update_versioned` 修改單筆資料的標題
+#[derive(Default)]
+struct VersionedMutation;
+
+#[Object]
+impl VersionedMutation {
+    async fn note_update_title(
+        &self,
+        ctx: &Context<'_>,
+        id: i32,
+        title: String,
+        expected_version: i32,
+    ) -> async_graphql::Result<note::Model> {
+        note::ActiveModel {
+            id: Unchanged(id),
+            title: Set(title),
+            ..Default::default()
+        }
+        .update_versioned(ctx, expected_version)
+        .await
+    }
+}
+
+#[derive(async_graphql::MergedObject, Default)]
+struct Mutation(NoteMutation, VersionedMutation);
+
+fn schema(db: sea_orm::DatabaseConnection) -> Schema<NoteRoot, Mutation, EmptySubscription> {
+    Schema::build(NoteRoot::default(), Mutation::default(), EmptySubscription)
+        .data(DataLoader::new(OrmDataloader { db }, tokio::spawn))
+        .finish()
+}
+
+fn request(query: &str) -> Request {
+    Request::new(query).data(Tenant(7)).data(Viewer(3))
+}
+
+fn note(version: i32) -> note::Model {
+    note::Model {
+        id: 1,
+        org_id: 7,
+        user_id: 3,
+        slug: "first".into(),
+        title: "new".into(),
+        secret: "s".into(),
+        deleted_at: None,
+        created_by: Some(3),
+        version,
+    }
+}
+
+fn count(n: i64) -> BTreeMap<&'static str, Value> {
+    BTreeMap::from([("num_items", Value::BigInt(Some(n)))])
+}
+
+fn exec(rows_affected: u64) -> MockExecResult {
+    MockExecResult {
+        last_insert_id: 0,
+        rows_affected,
+    }
+}
+
+const UPDATE_MANY: &str = r#"mutation { noteUpdateMany(filters: { id: { eq: 1 } }, mutant: { title: "new" }, expectedVersion: 2) }"#;
+
+#[tokio::test]
+async fn update_many_bumps_the_expected_version() {
+    let db = MockDatabase::new(DatabaseBackend::Postgres)
+        .append_exec_results([exec(1)])
+        .into_connection();
+
+    let response = schema(share_mock(&db)).execute(request(UPDATE_MANY)).await;
+
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(response.data, value!({ "noteUpdateMany": 1 }));
+
+    let log = db.into_transaction_log();
+    let sql = &log[0].statements()[0].sql;
+
+    assert!(sql.contains(r#""version" = "version" + $"#), "{}", sql);
+    assert!(sql.contains(r#""note"."version" = $"#), "{}", sql);
+}
+
+#[tokio::test]
+async fn update_many_conflicts_only_when_rows_match_the_filters() {
+    // 符合過濾條件的資料存在但版本不符
+    let db = MockDatabase::new(DatabaseBackend::Postgres)
+        .append_exec_results([exec(0)])
+        .append_query_results([[count(1)]])
+        .into_connection();
+
+    let response = schema(share_mock(&db)).execute(request(UPDATE_MANY)).await;
+
+    assert_eq!(response.errors.len(), 1, "{:?}", response.errors);
+    assert_eq!(
+        response.errors[0].extensions.as_ref().unwrap().get("code"),
+        Some(&value!("CONFLICT"))
+    );
+
+    // 重新計算時去掉版本條件，保留租戶與守衛條件
+    let log = db.into_transaction_log();
+    let sql = &log[1].statements()[0].sql;
+
+    assert!(sql.starts_with("SELECT COUNT(*)"), "{}", sql);
+    assert!(sql.contains(r#""note"."org_id" = $"#), "{}", sql);
+    assert!(!sql.contains(r#""note"."version" = "#), "{}", sql);
+
+    // 沒有符合過濾條件的資料，或資料屬於其他租戶、使用者
+    let db = MockDatabase::new(DatabaseBackend::Postgres)
+        .append_exec_results([exec(0)])
+        .append_query_results([[count(0)]])
+        .into_connection();
+
+    let response = schema(share_mock(&db)).execute(request(UPDATE_MANY)).await;
+
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(response.data, value!({ "noteUpdateMany": 0 }));
+}
+
+const UPDATE_TITLE: &str =
+    r#"mutation { noteUpdateTitle(id: 1, title: "new", expectedVersion: 2) { version } }"#;
+
+#[tokio::test]
+async fn update_versioned_is_scoped_like_update_many() {
+    let db = MockDatabase::new(DatabaseBackend::Postgres)
+        .append_query_results([vec![note(3)]])
+        .into_connection();
+
+    let response = schema(share_mock(&db)).execute(request(UPDATE_TITLE)).await;
+
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        response.data,
+        value!({ "noteUpdateTitle": { "version": 3 } })
+    );
+
+    let log = db.into_transaction_log();
+    let statement = &log[0].statements()[0];
+    let sql = &statement.sql;
+
+    assert!(sql.starts_with(r#"UPDATE "note" SET"#), "{}", sql);
+    assert!(sql.contains(r#""note"."org_id" = $"#), "{}", sql);
+    assert!(sql.contains(r#""note"."user_id" = $"#), "{}", sql);
+    assert!(sql.contains(r#""note"."deleted_at" IS NULL"#), "{}", sql);
+    assert!(sql.contains(r#""note"."version" = $"#), "{}", sql);
+
+    let values = &statement.values.as_ref().unwrap().0;
+    assert!(values.contains(&Value::Int(Some(7))), "{:?}", values);
+}
+
+#[tokio::test]
+async fn update_versioned_tells_conflicts_from_missing_rows() {
+    let db = MockDatabase::new(DatabaseBackend::Postgres)
+        .append_query_results([Vec::<note::Model>::new()])
+        .append_query_results([[count(1)]])
+        .into_connection();
+
+    let response = schema(share_mock(&db)).execute(request(UPDATE_TITLE)).await;
+
+    assert_eq!(
+        response.errors[0].extensions.as_ref().unwrap().get("code"),
+        Some(&value!("CONFLICT"))
+    );
+
+    let log = db.into_transaction_log();
+    let sql = &log[1].statements()[0].sql;
+
+    assert!(sql.contains(r#""note"."id" = $"#), "{}", sql);
+    assert!(sql.contains(r#""note"."deleted_at" IS NULL"#), "{}", sql);
+    assert!(!sql.contains(r#""note"."version" = "#), "{}", sql);
+
+    let db = MockDatabase::new(DatabaseBackend::Postgres)
+        .append_query_results([Vec::<note::Model>::new()])
+        .append_query_results([[count(0)]])
+        .into_connection();
+
+    let response = schema(share_mock(&db)).execute(request(UPDATE_TITLE)).await;
+
+    assert_eq!(response.errors.len(), 1, "{:?}", response.errors);
+    assert!(response.errors[0].extensions.is_none());
+    assert_eq!(
+        response.errors[0].message,
+        "None of the records are updated"
+    );
+}
+
+#[tokio::test]
+async fn dynamic_update_many_conflicts_only_when_rows_match_the_filters() {
+    let schema = |db| {
+        seaography::SchemaBuilder::new()
+            .register_guarded::<note::Entity>()
+            .finish(db, tokio::spawn)
+            .unwrap()
+    };
+
+    let db = MockDatabase::new(DatabaseBackend::Postgres)
+        .append_exec_results([exec(0)])
+        .append_query_results([[count(1)]])
+        .into_connection();
+
+    let response = schema(share_mock(&db)).execute(request(UPDATE_MANY)).await;
+
+    assert_eq!(
+        response.errors[0].extensions.as_ref().unwrap().get("code"),
+        Some(&value!("CONFLICT"))
+    );
+
+    let db = MockDatabase::new(DatabaseBackend::Postgres)
+        .append_exec_results([exec(0)])
+        .append_query_results([[count(0)]])
+        .into_connection();
+
+    let response = schema(share_mock(&db)).execute(request(UPDATE_MANY)).await;
+
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(response.data, value!({ "noteUpdateMany": 0 }));
+}